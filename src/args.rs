@@ -1,5 +1,12 @@
-use clap::Parser;
+use std::path::PathBuf;
 
+use clap::{ArgGroup, Args as ClapArgs, Parser, Subcommand, ValueEnum};
+use clap_complete::ArgValueCompleter;
+
+use crate::completions::{
+    company_candidates, config_key_candidates, job_id_candidates, profile_candidates,
+    run_id_candidates,
+};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -7,4 +14,393 @@ pub struct Args {
     /// Enable Discord mode
     #[arg(long)]
     pub discord: bool,
+
+    /// Profile to use, each profile has its own companies, connections, criteria and webhook
+    #[arg(
+        long,
+        default_value = "default",
+        global = true,
+        add = ArgValueCompleter::new(profile_candidates)
+    )]
+    pub profile: String,
+
+    /// Open the full-screen terminal UI instead of the menu
+    #[arg(long, conflicts_with = "discord")]
+    pub tui: bool,
+
+    /// Fetch the descriptions of new jobs in the background after each scrape
+    #[arg(long)]
+    pub fetch_descriptions: bool,
+
+    /// Output format of commands that list or show something
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table, global = true)]
+    pub format: OutputFormat,
+
+    /// Config file to use instead of config.toml in the config dir
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+
+    /// Override a setting for this run, e.g. --set scraper.headless=false, can be repeated
+    #[arg(long = "set", value_name = "KEY=VALUE", global = true)]
+    pub set: Vec<String>,
+
+    /// Run a single command without prompts instead of the interactive menu
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human readable tables
+    Table,
+    /// One JSON document, an array for listings
+    Json,
+    /// One JSON object per line
+    Ndjson,
+    /// A header row, nested fields flattened to dotted columns
+    Csv,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Scrape companies for new jobs and write a new jobs report
+    Scan(ScanArgs),
+    /// List, inspect and update stored jobs
+    #[command(subcommand)]
+    Jobs(JobsCommand),
+    /// List and follow companies
+    #[command(subcommand)]
+    Companies(CompaniesCommand),
+    /// Manage your connections
+    #[command(subcommand)]
+    Connections(ConnectionsCommand),
+    /// List and open new jobs reports
+    #[command(subcommand)]
+    Reports(ReportsCommand),
+    /// Look back at past scans and how each company's scraper did
+    #[command(subcommand)]
+    Runs(RunsCommand),
+    /// Run Discord mode without prompts under systemd, Docker or another supervisor
+    Daemon(DaemonArgs),
+    /// Show, change and check the config file
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// Print a script that sets up tab completion, including company names and job ids
+    Completions {
+        #[arg(value_enum)]
+        shell: CompletionShell,
+    },
+    /// Print the man page, or write a page per command to a directory
+    Man {
+        /// Write jobshell.1, jobshell-scan.1 and so on here instead of printing jobshell.1
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+    /// Print the JSON Schema of the records the json, ndjson and csv formats emit
+    Schema {
+        #[arg(value_enum, default_value_t = SchemaRecord::Job)]
+        record: SchemaRecord,
+    },
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaRecord {
+    Job,
+    Connection,
+    Company,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+    Powershell,
+}
+
+#[derive(ClapArgs, Debug)]
+pub struct ScanArgs {
+    /// Company to scan, can be repeated
+    #[arg(long, conflicts_with_all = ["followed", "all"], add = ArgValueCompleter::new(company_candidates))]
+    pub company: Vec<String>,
+
+    /// Scan followed companies and companies with connections (the default)
+    #[arg(long, conflicts_with = "all")]
+    pub followed: bool,
+
+    /// Scan every company
+    #[arg(long)]
+    pub all: bool,
+
+    /// Report every job the first time a company is scanned instead of only recording a baseline
+    #[arg(long)]
+    pub notify_on_baseline: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum JobsCommand {
+    /// List jobs across companies, newest first
+    List(JobsListArgs),
+    /// Show everything stored about a job
+    Show {
+        /// Job id, or a unique prefix of it
+        #[arg(add = ArgValueCompleter::new(job_id_candidates))]
+        id: String,
+    },
+    /// Update a job's state
+    Mark(JobsMarkArgs),
+}
+
+#[derive(ClapArgs, Debug)]
+pub struct JobsListArgs {
+    /// Only jobs at this company, can be repeated
+    #[arg(long, add = ArgValueCompleter::new(company_candidates))]
+    pub company: Vec<String>,
+
+    /// Words that all have to appear in the title, location, department, tags or notes
+    #[arg(long)]
+    pub keywords: Option<String>,
+
+    /// Only remote jobs
+    #[arg(long)]
+    pub remote: bool,
+
+    /// Only bookmarked jobs
+    #[arg(long)]
+    pub bookmarked: bool,
+
+    /// Only jobs you applied to
+    #[arg(long)]
+    pub applied: bool,
+
+    /// Only jobs you have not looked at yet
+    #[arg(long)]
+    pub unseen: bool,
+
+    /// Include postings that have closed
+    #[arg(long)]
+    pub include_closed: bool,
+
+    /// Stop after this many jobs
+    #[arg(long)]
+    pub limit: Option<usize>,
+}
+
+#[derive(ClapArgs, Debug)]
+#[group(skip)]
+#[command(group(
+    ArgGroup::new("mark")
+        .required(true)
+        .multiple(true)
+        .args(["applied", "bookmark", "unbookmark", "seen"])
+))]
+pub struct JobsMarkArgs {
+    /// Job id, or a unique prefix of it
+    #[arg(add = ArgValueCompleter::new(job_id_candidates))]
+    pub id: String,
+
+    /// Mark the job as applied
+    #[arg(long)]
+    pub applied: bool,
+
+    /// Bookmark the job
+    #[arg(long, conflicts_with = "unbookmark")]
+    pub bookmark: bool,
+
+    /// Remove the job's bookmark
+    #[arg(long)]
+    pub unbookmark: bool,
+
+    /// Mark the job as seen
+    #[arg(long)]
+    pub seen: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CompaniesCommand {
+    /// List every company with its follow state, connections and open jobs
+    List,
+    /// Follow a company so scans include it
+    Follow {
+        #[arg(add = ArgValueCompleter::new(company_candidates))]
+        name: String,
+    },
+    /// Stop following a company
+    Unfollow {
+        #[arg(add = ArgValueCompleter::new(company_candidates))]
+        name: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConnectionsCommand {
+    /// List connections, optionally at one company
+    List {
+        #[arg(long, add = ArgValueCompleter::new(company_candidates))]
+        company: Option<String>,
+    },
+    /// Add a connection at a company
+    Add(ConnectionArgs),
+    /// Remove a connection and its reminders
+    Remove {
+        #[arg(long, add = ArgValueCompleter::new(company_candidates))]
+        company: String,
+        #[arg(long)]
+        first_name: String,
+        #[arg(long)]
+        last_name: String,
+    },
+}
+
+#[derive(ClapArgs, Debug)]
+pub struct ConnectionArgs {
+    #[arg(long, add = ArgValueCompleter::new(company_candidates))]
+    pub company: String,
+
+    #[arg(long)]
+    pub first_name: String,
+
+    #[arg(long)]
+    pub last_name: String,
+
+    /// Their role at the company, e.g. Software Engineer
+    #[arg(long, default_value = "")]
+    pub role: String,
+
+    #[arg(long)]
+    pub email: Option<String>,
+
+    /// LinkedIn profile, e.g. https://linkedin.com/in/jane
+    #[arg(long)]
+    pub linkedin: Option<String>,
+
+    /// They no longer work at the company
+    #[arg(long)]
+    pub former_employee: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ReportsCommand {
+    /// List new jobs reports, newest first
+    List,
+    /// Open a report in the browser
+    Open {
+        /// Report name as listed, or "latest"
+        #[arg(default_value = "latest")]
+        name: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum RunsCommand {
+    /// List past scans, newest first
+    List {
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Show how each company did in a scan
+    Show {
+        /// Run id or a unique prefix of it
+        #[arg(add = ArgValueCompleter::new(run_id_candidates))]
+        id: String,
+    },
+    /// Runs, failures and durations per company, most failures first
+    Stats,
+}
+
+#[derive(ClapArgs, Debug)]
+pub struct DaemonArgs {
+    /// Append log lines to this file as well as printing them
+    #[arg(long)]
+    pub log_file: Option<PathBuf>,
+
+    /// Where to write the daemon's pid, daemon.pid in the profile's data dir by default
+    #[arg(long)]
+    pub pid_file: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Print the resolved config, after env vars and --set, with API keys masked
+    Show,
+    /// Change a setting in the config file
+    Set {
+        /// The setting as section.key, e.g. scraper.concurrency
+        #[arg(add = ArgValueCompleter::new(config_key_candidates))]
+        key: String,
+        value: String,
+    },
+    /// Check the config file, env vars and --set flags, exits non-zero when something is wrong
+    Validate,
+}
+
+#[cfg(test)]
+mod test {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn test_args() {
+        Args::command().debug_assert();
+
+        let args = Args::parse_from([
+            "jobshell",
+            "jobs",
+            "mark",
+            "3f2a",
+            "--applied",
+            "--bookmark",
+        ]);
+        assert!(matches!(
+            args.command,
+            Some(Command::Jobs(JobsCommand::Mark(JobsMarkArgs {
+                applied: true,
+                bookmark: true,
+                unbookmark: false,
+                seen: false,
+                ..
+            })))
+        ));
+
+        // Marking needs at least one change and can't both add and remove a bookmark
+        assert!(Args::try_parse_from(["jobshell", "jobs", "mark", "3f2a"]).is_err());
+        assert!(Args::try_parse_from([
+            "jobshell",
+            "jobs",
+            "mark",
+            "3f2a",
+            "--bookmark",
+            "--unbookmark"
+        ])
+        .is_err());
+        assert!(
+            Args::try_parse_from(["jobshell", "scan", "--company", "Stripe", "--all"]).is_err()
+        );
+
+        let args = Args::parse_from([
+            "jobshell",
+            "config",
+            "show",
+            "--set",
+            "scraper.concurrency=2",
+            "--set",
+            "scraper.headless=false",
+        ]);
+        assert_eq!(args.set.len(), 2);
+
+        let args = Args::parse_from(["jobshell", "completions", "powershell"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::Completions {
+                shell: CompletionShell::Powershell
+            })
+        ));
+
+        let args = Args::parse_from(["jobshell", "runs", "list", "--limit", "5"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::Runs(RunsCommand::List { limit: 5 }))
+        ));
+    }
 }
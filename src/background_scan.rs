@@ -0,0 +1,531 @@
+use std::{
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use console::Term;
+use strum::IntoEnumIterator;
+use tokio::{
+    sync::Notify,
+    task::{JoinHandle, JoinSet},
+};
+use tracing::{Instrument, Span};
+
+use crate::{
+    company_options::{CompanyOption, ScrapeJobs},
+    config,
+    handlers::handlers::FormattedJob,
+    models::{
+        ai::{AiModel, OpenAIClient},
+        data::Data,
+        run::{CompanyRun, CompanyTimer, RunTrigger, ScanRun},
+        scraper::{Job, JobsPayload},
+    },
+    profiles,
+    reports::{create_report, ReportMode},
+};
+
+/// What a background scan has done so far, shared between its task and the UI that started it
+#[derive(Default)]
+pub struct ScanProgress {
+    /// The company being scraped, the last one started when several run at once
+    pub company: String,
+    pub scanned: usize,
+    pub total: usize,
+    /// One line per notable event, e.g. new jobs found or a company failing
+    pub log: Vec<String>,
+    pub new_jobs: Vec<FormattedJob>,
+    /// The new jobs that passed the smart criteria, when it is enabled
+    pub smart_criteria_jobs: Option<Vec<FormattedJob>>,
+    pub closed_jobs: Vec<FormattedJob>,
+    pub failed: Vec<String>,
+    /// Companies whose jobs were not saved because the profile changed during the scan
+    pub dropped: Vec<String>,
+    pub finished: bool,
+    pub cancelled: bool,
+    // INFO: Scraped jobs per company, waiting to be merged into the UI's copy of the data
+    unmerged: Vec<(String, Vec<Job>)>,
+    // INFO: Saved to the scan history once the scan finishes or is cancelled
+    run: Option<ScanRun>,
+}
+
+impl ScanProgress {
+    /// The jobs to present once the scan is done, only the smart criteria picks when it is enabled
+    pub fn results(&self) -> &[FormattedJob] {
+        self.smart_criteria_jobs
+            .as_deref()
+            .unwrap_or(&self.new_jobs)
+    }
+
+    pub fn status_line(&self) -> String {
+        let mut status = if self.cancelled {
+            format!(
+                "Scan cancelled after {}/{} companies, {} new jobs",
+                self.scanned,
+                self.total,
+                self.results().len()
+            )
+        } else if self.finished {
+            format!("Scan finished, {} new jobs", self.results().len())
+        } else {
+            format!(
+                "Scanning {} ({}/{}), {} new jobs so far",
+                self.company,
+                self.scanned + 1,
+                self.total,
+                self.new_jobs.len()
+            )
+        };
+
+        if !self.failed.is_empty() {
+            status += &format!(", failed: {}", self.failed.join(", "));
+        }
+
+        if !self.dropped.is_empty() {
+            status += &format!(", not saved: {}", self.dropped.join(", "));
+        }
+
+        status
+    }
+
+    fn save_run(&mut self) {
+        if let Some(mut run) = self.run.take() {
+            run.cancelled = self.cancelled;
+            if let Err(e) = run.finish() {
+                self.log
+                    .push(format!("❌ Could not save the scan history: {}", e));
+            }
+        }
+    }
+}
+
+/// A scan running on its own task so the interface stays usable, see `merge_into` for getting its jobs
+pub struct BackgroundScan {
+    profile: String,
+    progress: Arc<Mutex<ScanProgress>>,
+    updated: Arc<Notify>,
+    task: JoinHandle<()>,
+}
+
+impl BackgroundScan {
+    /// Followed companies and companies where the user has a connection
+    pub fn network_companies(data: &Data) -> Vec<CompanyOption> {
+        CompanyOption::iter()
+            .filter(|c| {
+                let company = &data.companies[&c.to_string()];
+                company.is_following || !company.connections.is_empty()
+            })
+            .collect()
+    }
+
+    /// Scrapes `companies` starting from `data`, which is copied once for the whole scan
+    pub fn start(companies: Vec<CompanyOption>, data: &Data, trigger: RunTrigger) -> Self {
+        let run = ScanRun::start(trigger);
+        let span = run.span();
+        let progress = Arc::new(Mutex::new(ScanProgress {
+            company: companies.first().map(|c| c.to_string()).unwrap_or_default(),
+            total: companies.len(),
+            run: Some(run),
+            ..Default::default()
+        }));
+        let updated = Arc::new(Notify::new());
+
+        // INFO: A detached copy never saves, the scraped jobs are merged into the UI's copy instead
+        let mut data = data.clone();
+        data.detached = true;
+
+        let task =
+            tokio::spawn(scan(companies, data, progress.clone(), updated.clone()).instrument(span));
+
+        BackgroundScan {
+            profile: profiles::active_profile(),
+            progress,
+            updated,
+            task,
+        }
+    }
+
+    pub fn progress(&self) -> MutexGuard<'_, ScanProgress> {
+        self.progress.lock().unwrap()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress().finished
+    }
+
+    /// Stops the scan right away, the jobs of companies it already scraped are kept
+    pub fn cancel(&self) {
+        self.task.abort();
+
+        let mut progress = self.progress();
+        if !progress.finished {
+            progress.finished = true;
+            progress.cancelled = true;
+            progress.save_run();
+        }
+        drop(progress);
+
+        self.updated.notify_one();
+    }
+
+    /// Resolves when the scan makes progress, owns what it needs so it can be awaited next to `&mut self` work
+    pub fn updated(&self) -> impl Future<Output = ()> + 'static {
+        let updated = self.updated.clone();
+        async move { updated.notified().await }
+    }
+
+    /// Moves the jobs scraped so far into `data` and saves it, returns whether there was anything to move
+    ///
+    /// The scan never writes the data file itself, so whatever the user changed while it ran is kept.
+    /// Jobs scanned for another profile than the active one are dropped, and noted in the progress.
+    pub fn merge_into(&self, data: &mut Data) -> bool {
+        let unmerged = std::mem::take(&mut self.progress().unmerged);
+        if unmerged.is_empty() {
+            return false;
+        }
+
+        if profiles::active_profile() != self.profile {
+            let mut progress = self.progress();
+            for (company_key, _) in unmerged {
+                progress.log.push(format!(
+                    "⚠️ Did not save the jobs scanned for {}, the scan started in profile {}",
+                    company_key, self.profile
+                ));
+                progress.dropped.push(company_key);
+            }
+            return false;
+        }
+
+        for (company_key, jobs) in unmerged {
+            data.merge_scanned_jobs(&company_key, jobs);
+        }
+        data.save();
+
+        true
+    }
+
+    /// Keeps the terminal title on the scan's progress and rings the bell when it is done
+    ///
+    /// Escape sequences for the title don't disturb a prompt, unlike printing would.
+    pub fn show_progress_in_terminal_title(&self) {
+        let progress = self.progress.clone();
+        let updated = self.updated.clone();
+
+        tokio::spawn(async move {
+            let term = Term::stderr();
+
+            loop {
+                let (status, finished) = {
+                    let progress = progress.lock().unwrap();
+                    (progress.status_line(), progress.finished)
+                };
+                term.set_title(format!("JobShell · {}", status));
+
+                if finished {
+                    let _ = term.write_str("\x07");
+                    break;
+                }
+
+                updated.notified().await;
+            }
+        });
+    }
+}
+
+fn update(progress: &Mutex<ScanProgress>, updated: &Notify, f: impl FnOnce(&mut ScanProgress)) {
+    f(&mut progress.lock().unwrap());
+    updated.notify_one();
+}
+
+fn format_jobs(jobs: Vec<Job>, company_key: &str) -> Vec<FormattedJob> {
+    jobs.into_iter()
+        .map(|j| FormattedJob {
+            display_name: format!("{} | {} | ({})", j.title, j.location, company_key),
+            job: j,
+            company: company_key.to_string(),
+        })
+        .collect()
+}
+
+/// What scraping one company came up with, reported back to `scan` once its task is done
+struct CompanyScan {
+    company_key: String,
+    company_run: CompanyRun,
+    smart_criteria_enabled: bool,
+    result: Result<ScrapedCompany, String>,
+}
+
+struct ScrapedCompany {
+    jobs_payload: JobsPayload,
+    new_jobs: Vec<Job>,
+    smart_criteria_jobs: Result<Vec<Job>, (String, Vec<Job>)>,
+}
+
+async fn scan_company(company_option: CompanyOption, mut data: Data) -> CompanyScan {
+    let company_key = company_option.to_string();
+
+    let timer = CompanyTimer::start(&company_key);
+    let jobs_payload = match company_option.scrape_jobs(&mut data).await {
+        Ok(jobs_payload) => jobs_payload,
+        Err(e) => {
+            return CompanyScan {
+                company_run: timer.finish(Err(e.to_string())),
+                company_key,
+                smart_criteria_enabled: data.smart_criteria_enabled,
+                result: Err(e.to_string()),
+            }
+        }
+    };
+    let company_run = timer.finish(Ok(&jobs_payload));
+
+    // The first scrape of a company only establishes a baseline, it is not news
+    let new_jobs = jobs_payload.jobs_to_notify(false);
+
+    let smart_criteria_jobs = if data.smart_criteria_enabled && !new_jobs.is_empty() {
+        match OpenAIClient::new()
+            .filter_jobs_based_on_smart_criteria(&new_jobs)
+            .await
+        {
+            Ok(jobs) => Ok(jobs),
+            // INFO: Better to show every new job than to hide them all when the AI is unavailable
+            Err(e) => Err((e.to_string(), new_jobs.clone())),
+        }
+    } else {
+        Ok(Vec::new())
+    };
+
+    CompanyScan {
+        company_key,
+        company_run,
+        smart_criteria_enabled: data.smart_criteria_enabled,
+        result: Ok(ScrapedCompany {
+            jobs_payload,
+            new_jobs,
+            smart_criteria_jobs,
+        }),
+    }
+}
+
+fn record(p: &mut ScanProgress, company_scan: CompanyScan) {
+    let CompanyScan {
+        company_key,
+        company_run,
+        smart_criteria_enabled,
+        result,
+    } = company_scan;
+
+    if let Some(run) = &mut p.run {
+        run.record(company_run);
+    }
+
+    let ScrapedCompany {
+        jobs_payload,
+        new_jobs,
+        smart_criteria_jobs,
+    } = match result {
+        Ok(scraped) => scraped,
+        Err(e) => {
+            p.log
+                .push(format!("❌ Could not scan {}: {}", company_key, e));
+            p.failed.push(company_key);
+            return;
+        }
+    };
+
+    let closed_jobs = jobs_payload.closed_jobs();
+    if !closed_jobs.is_empty() {
+        p.log.push(format!(
+            "🔒 {} job(s) you were tracking at {} have closed",
+            closed_jobs.len(),
+            company_key
+        ));
+        p.closed_jobs.extend(format_jobs(closed_jobs, &company_key));
+    }
+
+    if jobs_payload.diff.is_baseline {
+        p.log.push(format!(
+            "📋 Recorded {} jobs as the baseline for {}",
+            jobs_payload.all_jobs.len(),
+            company_key
+        ));
+    }
+
+    if !new_jobs.is_empty() {
+        p.log.push(format!(
+            "✨ Found {} new jobs for {}!",
+            new_jobs.len(),
+            company_key
+        ));
+    }
+
+    if smart_criteria_enabled {
+        let jobs = match smart_criteria_jobs {
+            Ok(jobs) => jobs,
+            Err((e, jobs)) => {
+                p.log.push(format!(
+                    "🧠 Smart criteria failed for {}, keeping every new job: {}",
+                    company_key, e
+                ));
+                jobs
+            }
+        };
+        p.smart_criteria_jobs
+            .get_or_insert_with(Vec::new)
+            .extend(format_jobs(jobs, &company_key));
+    }
+
+    p.new_jobs.extend(format_jobs(new_jobs, &company_key));
+    p.unmerged.push((company_key, jobs_payload.all_jobs));
+}
+
+async fn scan(
+    companies: Vec<CompanyOption>,
+    data: Data,
+    progress: Arc<Mutex<ScanProgress>>,
+    updated: Arc<Notify>,
+) {
+    // INFO: Each company scrapes into its own detached copy of the data, so up to
+    // `scraper.concurrency` of them can run at once. Aborting this task aborts them too.
+    let concurrency = config::get().scraper.concurrency.max(1);
+    let mut pending = companies.into_iter();
+    let mut running = JoinSet::new();
+
+    loop {
+        while running.len() < concurrency {
+            let Some(company_option) = pending.next() else {
+                break;
+            };
+            update(&progress, &updated, |p| {
+                p.company = company_option.to_string()
+            });
+            running.spawn(scan_company(company_option, data.clone()).instrument(Span::current()));
+        }
+
+        let Some(joined) = running.join_next().await else {
+            break;
+        };
+
+        update(&progress, &updated, |p| {
+            match joined {
+                Ok(company_scan) => record(p, company_scan),
+                Err(e) => p.log.push(format!("❌ A scraper crashed: {}", e)),
+            }
+            p.scanned += 1;
+        });
+    }
+
+    update(&progress, &updated, |p| {
+        // Closed postings you were tracking go in a report too, marked with their status
+        for jobs in [&p.closed_jobs, &p.new_jobs] {
+            if !jobs.is_empty() {
+                if let Err(e) = create_report(jobs, ReportMode::HTML) {
+                    p.log.push(format!("❌ Could not create the report: {}", e));
+                }
+            }
+        }
+
+        p.scanned = p.total;
+        p.finished = true;
+        p.save_run();
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn test_merge_keeps_user_changes() {
+        let mut data = Data::default();
+        data.detached = true;
+
+        let job = Job {
+            id: Uuid::new_v4(),
+            title: "Rust Engineer".to_string(),
+            ..Default::default()
+        };
+        data.companies.get_mut("Stripe").unwrap().jobs = vec![job.clone()];
+
+        // The scan read the job before the user bookmarked it
+        let scanned_jobs = vec![
+            job.clone(),
+            Job {
+                id: Uuid::new_v4(),
+                title: "Staff Rust Engineer".to_string(),
+                ..Default::default()
+            },
+        ];
+        data.set_job_bookmarked(&job.id, true);
+        data.merge_scanned_jobs("Stripe", scanned_jobs);
+
+        let jobs = &data.companies["Stripe"].jobs;
+        assert_eq!(jobs.len(), 2);
+        assert!(jobs[0].is_bookmarked);
+        assert!(!jobs[1].is_bookmarked);
+    }
+
+    #[test]
+    fn test_merge_keeps_jobs_tracked_during_the_scan() {
+        let mut data = Data::default();
+        data.detached = true;
+
+        let mut annotated = Job {
+            id: Uuid::new_v4(),
+            title: "Rust Engineer".to_string(),
+            notes: "Ask Sam for a referral".to_string(),
+            ..Default::default()
+        };
+        let untouched = Job {
+            id: Uuid::new_v4(),
+            title: "Go Engineer".to_string(),
+            ..Default::default()
+        };
+        data.companies.get_mut("Stripe").unwrap().jobs = vec![annotated.clone(), untouched.clone()];
+
+        // The scan read both before the user touched them, and dropped the Go posting as closed
+        data.set_job_bookmarked(&untouched.id, true);
+        data.set_job_notes(&annotated.id, "Referral sent".to_string());
+        annotated.title = "Senior Rust Engineer".to_string();
+        data.merge_scanned_jobs("Stripe", vec![annotated.clone()]);
+
+        let jobs = &data.companies["Stripe"].jobs;
+        assert_eq!(jobs.len(), 2);
+        assert_eq!(jobs[0].title, "Senior Rust Engineer");
+        assert_eq!(jobs[0].notes, "Referral sent");
+        assert_eq!(jobs[1].id, untouched.id);
+        assert!(jobs[1].is_bookmarked);
+        assert!(jobs[1].is_closed());
+    }
+
+    #[tokio::test]
+    async fn test_merge_into_another_profile_is_reported() {
+        let scan = BackgroundScan {
+            profile: "jobshell-test-other-profile".to_string(),
+            progress: Arc::new(Mutex::new(ScanProgress::default())),
+            updated: Arc::new(Notify::new()),
+            task: tokio::spawn(async {}),
+        };
+        let job = Job {
+            id: Uuid::new_v4(),
+            title: "Rust Engineer".to_string(),
+            ..Default::default()
+        };
+        scan.progress()
+            .unmerged
+            .push(("Stripe".to_string(), vec![job]));
+
+        let mut data = Data::default();
+        data.detached = true;
+
+        assert!(!scan.merge_into(&mut data));
+        assert!(data.companies["Stripe"].jobs.is_empty());
+
+        let progress = scan.progress();
+        assert!(progress.unmerged.is_empty());
+        assert_eq!(progress.dropped, vec!["Stripe".to_string()]);
+        assert_eq!(progress.log.len(), 1);
+        assert!(progress.status_line().ends_with(", not saved: Stripe"));
+    }
+}
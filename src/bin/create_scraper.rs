@@ -94,18 +94,20 @@ use std::error::Error;
 
 use headless_chrome::{{Browser, LaunchOptions}};
 
-use crate::models::{{
-    data::Data,
-    scraper::{{JobsPayload, ScrapedJob}},
+use crate::{{
+    config::launch_options,
+    models::{{
+        data::Data,
+        scraper::{{JobsPayload, ScrapedJob}},
+    }},
 }};
 
 pub async fn scrape_{}(data: &mut Data) -> Result<JobsPayload, Box<dyn Error>> {{
     let launch_options = LaunchOptions {{
-        headless: false,
         window_size: Some((1920, 1080)),
         enable_logging: true,
 
-        ..LaunchOptions::default()
+        ..launch_options(false)
     }};
     let browser = Browser::new(launch_options)?;
 
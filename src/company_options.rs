@@ -1,5 +1,8 @@
+use std::time::Instant;
+
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter};
+use tracing::{debug, info, info_span, warn, Instrument};
 
 use crate::{
     error::AppResult,
@@ -16,6 +19,7 @@ use crate::{
         coinbase::scraper::scrape_coinbase, costar_group::scraper::scrape_costar_group,
         default::default_scrape_jobs_handler, disney::scraper::scrape_disney,
         doordash::scraper::scrape_doordash, experian::scraper::scrape_experian,
+        job_details::queue_background_fetch,
         gen::scraper::scrape_gen, ibm::scraper::scrape_ibm, meta::scraper::scrape_meta,
         netflix::scraper::scrape_netflix, nike::scraper::scrape_nike,
         panasonic::scraper::scrape_panasonic, paypal::scraper::scrape_paypal,
@@ -139,12 +143,70 @@ impl CompanyOption {
 
         company_keys
     }
+
+    /// Looks a company up by its display name, ignoring case, spaces and punctuation
+    ///
+    /// "TheBrowserCompany" and "the-browser-company" find The Browser Company, so names can be
+    /// typed and completed without quoting.
+    pub fn from_name(name: &str) -> AppResult<CompanyOption> {
+        let normalize = |name: &str| -> String {
+            name.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(|c| c.to_lowercase())
+                .collect()
+        };
+        let wanted = normalize(name);
+
+        CompanyOption::iter()
+            .find(|c| normalize(&c.to_string()) == wanted)
+            .ok_or_else(|| {
+                format!(
+                    "Unknown company \"{}\", expected one of: {}",
+                    name,
+                    CompanyOption::keys().join(", ")
+                )
+                .into()
+            })
+    }
 }
 pub trait ScrapeJobs {
     async fn scrape_jobs(&self, data: &mut Data) -> AppResult<JobsPayload>;
 }
 impl ScrapeJobs for CompanyOption {
     async fn scrape_jobs(&self, data: &mut Data) -> AppResult<JobsPayload> {
+        let span = info_span!("company", company = %self);
+
+        async move {
+            debug!("Scraping jobs");
+            let started = Instant::now();
+
+            let payload = self.scrape_jobs_from_board(data).await;
+            let elapsed_ms = started.elapsed().as_millis() as u64;
+
+            match &payload {
+                Ok(payload) => info!(
+                    jobs = payload.all_jobs.len(),
+                    new = payload.diff.new.len(),
+                    removed = payload.diff.removed.len(),
+                    baseline = payload.diff.is_baseline,
+                    elapsed_ms,
+                    "Scraped jobs"
+                ),
+                Err(e) => warn!(error = %e, elapsed_ms, "Scrape failed"),
+            }
+
+            let payload = payload?;
+            queue_background_fetch(&self.to_string(), &payload.new_jobs);
+
+            Ok(payload)
+        }
+        .instrument(span)
+        .await
+    }
+}
+
+impl CompanyOption {
+    async fn scrape_jobs_from_board(&self, data: &mut Data) -> AppResult<JobsPayload> {
         match self {
             Self::AirBnb => scrape_airbnb(data).await,
             Self::Atlassian => scrape_atlassian(data).await,
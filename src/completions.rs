@@ -0,0 +1,230 @@
+use std::{
+    env,
+    ffi::OsStr,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::CommandFactory;
+use clap_complete::{
+    env::{Bash, EnvCompleter, Fish, Powershell, Zsh},
+    CompleteEnv, CompletionCandidate,
+};
+
+use crate::{
+    args::{Args, CompletionShell},
+    company_options::CompanyOption,
+    config::Config,
+    error::AppResult,
+    models::{data::Data, run::ScanRun},
+    profiles,
+};
+
+// INFO: The completion scripts call JobShell back with this env var set to the shell's name
+const COMPLETE_VAR: &str = "COMPLETE";
+
+/// Answers the shell when it calls back for completions and exits, otherwise returns right away
+///
+/// Has to run before anything is printed. Job and run ids come from the profile given on the
+/// command line being completed, the default one otherwise.
+pub fn complete() {
+    if env::var_os(COMPLETE_VAR).is_none() {
+        return;
+    }
+
+    let args: Vec<String> = env::args().collect();
+    let profile = args
+        .iter()
+        .enumerate()
+        .find_map(|(i, arg)| match arg.as_str() {
+            "--profile" => args.get(i + 1).cloned(),
+            _ => arg.strip_prefix("--profile=").map(String::from),
+        });
+    if let Some(profile) = profile {
+        let _ = profiles::set_active_profile(&profile);
+    }
+
+    CompleteEnv::with_factory(Args::command)
+        .var(COMPLETE_VAR)
+        .complete();
+}
+
+/// Prints the script that registers completions for `shell`
+///
+/// The script asks JobShell for candidates on every tab, so they always match the installed
+/// version and the data store.
+pub fn print_registration(shell: CompletionShell) -> AppResult<()> {
+    let completer: &dyn EnvCompleter = match shell {
+        CompletionShell::Bash => &Bash,
+        CompletionShell::Zsh => &Zsh,
+        CompletionShell::Fish => &Fish,
+        CompletionShell::Powershell => &Powershell,
+    };
+
+    // INFO: A bare name is found on the PATH, anything else is pinned to this binary
+    let bin = match env::args().next() {
+        Some(bin) if !bin.contains(std::path::is_separator) => bin,
+        _ => env::current_exe()?.display().to_string(),
+    };
+
+    completer.write_registration(
+        COMPLETE_VAR,
+        "jobshell",
+        "jobshell",
+        &bin,
+        &mut io::stdout(),
+    )?;
+
+    Ok(())
+}
+
+/// Prints jobshell.1, or writes it and a page per subcommand to `out_dir`
+pub fn write_man_pages(out_dir: Option<PathBuf>) -> AppResult<()> {
+    let command = Args::command();
+
+    match out_dir {
+        Some(out_dir) => {
+            fs::create_dir_all(&out_dir)?;
+            clap_mangen::generate_to(command, &out_dir)?;
+            eprintln!("Wrote man pages to {}", out_dir.display());
+        }
+        None => clap_mangen::Man::new(command).render(&mut io::stdout())?,
+    }
+
+    Ok(())
+}
+
+/// Candidates whose value starts with what was typed, ignoring case
+fn matching(
+    current: &OsStr,
+    candidates: impl Iterator<Item = (String, Option<String>)>,
+) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy().to_lowercase();
+
+    candidates
+        .filter(|(value, _)| value.to_lowercase().starts_with(&current))
+        .map(|(value, help)| CompletionCandidate::new(value).help(help.map(Into::into)))
+        .collect()
+}
+
+/// Company names without spaces, which `CompanyOption::from_name` accepts, so no quoting is needed
+pub fn company_candidates(current: &OsStr) -> Vec<CompletionCandidate> {
+    matching(
+        current,
+        CompanyOption::keys().into_iter().map(|name| {
+            let value: String = name.split_whitespace().collect();
+            let help = (value != name).then_some(name);
+            (value, help)
+        }),
+    )
+}
+
+pub fn job_id_candidates(current: &OsStr) -> Vec<CompletionCandidate> {
+    job_ids_in(&Data::get_data_dir().join("data.json"), current)
+}
+
+// INFO: Only reads the file, `Data::get_data` would create, save or back up data.json on a tab press
+fn job_ids_in(data_file: &Path, current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(data) = fs::read_to_string(data_file)
+        .ok()
+        .and_then(|content| serde_json::from_str::<Data>(&content).ok())
+    else {
+        return Vec::new();
+    };
+
+    matching(
+        current,
+        data.companies.iter().flat_map(|(company, c)| {
+            c.jobs
+                .iter()
+                .map(move |j| (j.id.to_string(), Some(format!("{} @ {}", j.title, company))))
+        }),
+    )
+}
+
+pub fn run_id_candidates(current: &OsStr) -> Vec<CompletionCandidate> {
+    matching(
+        current,
+        ScanRun::load_all().into_iter().map(|r| {
+            let help = format!(
+                "{} {}, {} companies",
+                r.started_at.format("%Y-%m-%d %H:%M"),
+                r.trigger,
+                r.companies.len()
+            );
+            (r.id.to_string(), Some(help))
+        }),
+    )
+}
+
+pub fn profile_candidates(current: &OsStr) -> Vec<CompletionCandidate> {
+    matching(
+        current,
+        profiles::list_profiles().into_iter().map(|p| (p, None)),
+    )
+}
+
+pub fn config_key_candidates(current: &OsStr) -> Vec<CompletionCandidate> {
+    matching(current, Config::keys().into_iter().map(|k| (k, None)))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::models::scraper::{Job, ScrapedJob};
+
+    #[test]
+    fn test_company_candidates() {
+        let values = |current: &str| -> Vec<String> {
+            company_candidates(OsStr::new(current))
+                .iter()
+                .map(|c| c.get_value().to_string_lossy().into_owned())
+                .collect()
+        };
+
+        assert_eq!(values("thebrow"), vec!["TheBrowserCompany"]);
+        assert_eq!(values("COSTAR"), vec!["CostarGroup"]);
+        assert!(values("").len() > 30);
+
+        for value in values("") {
+            assert!(CompanyOption::from_name(&value).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_job_ids_are_read_only() {
+        let data_file = env::temp_dir().join(format!("jobshell-{}.json", uuid::Uuid::new_v4()));
+        let job_id = uuid::Uuid::new_v4();
+
+        assert!(job_ids_in(&data_file, OsStr::new("")).is_empty());
+        assert!(!data_file.exists());
+
+        fs::write(&data_file, "{not json").unwrap();
+        assert!(job_ids_in(&data_file, OsStr::new("")).is_empty());
+        assert_eq!(fs::read_to_string(&data_file).unwrap(), "{not json");
+
+        let mut data = Data::default();
+        let mut job = Job::from_scraped(
+            ScrapedJob {
+                title: "Rust Engineer".to_string(),
+                link: "https://example.com/rust".to_string(),
+                ..Default::default()
+            },
+            Utc::now(),
+        );
+        job.id = job_id;
+        data.companies.get_mut("Stripe").unwrap().jobs.push(job);
+        let content = serde_json::to_string(&data).unwrap();
+        fs::write(&data_file, &content).unwrap();
+
+        let candidates = job_ids_in(&data_file, OsStr::new(&job_id.to_string()[..8]));
+        let written = fs::read_to_string(&data_file).unwrap();
+        fs::remove_file(&data_file).unwrap();
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].get_value(), job_id.to_string().as_str());
+        assert_eq!(written, content);
+    }
+}
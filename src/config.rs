@@ -0,0 +1,696 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::Duration,
+};
+
+use directories::ProjectDirs;
+use headless_chrome::LaunchOptions;
+use reqwest::{Client, Proxy};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use toml_edit::DocumentMut;
+
+use crate::{company_options::CompanyOption, error::AppResult};
+
+// INFO: Resolved once at startup from defaults, the config file, env vars and --set flags, in that order
+static CONFIG: OnceLock<Config> = OnceLock::new();
+static SOURCE: OnceLock<(PathBuf, Vec<String>)> = OnceLock::new();
+
+/// Env vars that predate the config file, still honored so existing `.env` files keep working
+const LEGACY_ENV_VARS: [(&str, &str); 3] = [
+    ("OPENAI_KEY", "ai.openai_key"),
+    ("GEMINI_KEY", "ai.gemini_key"),
+    ("GEMINI_MODEL", "ai.gemini_model"),
+];
+
+/// Settings that used to be hard-coded, prompted for or read from `.env`
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub notifications: NotificationsConfig,
+    pub schedule: ScheduleConfig,
+    pub ai: AiConfig,
+    pub scraper: ScraperConfig,
+    pub ui: UiConfig,
+    pub logging: LoggingConfig,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct NotificationsConfig {
+    /// Where Discord mode posts new jobs, prompted for when unset
+    pub discord_webhook_url: Option<String>,
+}
+
+/// Discord mode's scans, every setting left unset is prompted for
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScheduleConfig {
+    pub interval_hours: Option<u64>,
+    /// A cron expression with seconds, e.g. "0 0 9-17 * * Mon-Fri", used instead of interval_hours
+    pub cron: Option<String>,
+    /// Each scan waits a random delay up to this long, so runs don't hit the sites at the same second
+    pub jitter_secs: u64,
+    /// Otherwise only followed companies and companies with connections
+    pub scan_all_companies: Option<bool>,
+    pub notify_on_baseline: Option<bool>,
+    /// Companies scanned on their own schedule instead of the one above
+    pub groups: BTreeMap<String, ScheduleGroup>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScheduleGroup {
+    /// A cron expression with seconds, or English like "every 30 minutes"
+    pub cron: String,
+    pub companies: Vec<String>,
+    /// Falls back to schedule.jitter_secs
+    pub jitter_secs: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct AiConfig {
+    pub openai_key: Option<String>,
+    pub openai_model: String,
+    pub gemini_key: Option<String>,
+    /// The Gemini 1.5 variant, e.g. flash or pro
+    pub gemini_model: String,
+}
+
+impl Default for AiConfig {
+    fn default() -> Self {
+        AiConfig {
+            openai_key: None,
+            openai_model: "gpt-4o".to_string(),
+            gemini_key: None,
+            gemini_model: "flash".to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScraperConfig {
+    /// Overrides every scraper's own choice, some sites only serve a visible browser
+    pub headless: Option<bool>,
+    /// Companies scraped at once by background scans
+    pub concurrency: usize,
+    /// Applies to each HTTP request and to a browser left waiting on a page
+    pub timeout_secs: u64,
+    /// e.g. http://localhost:8080 or socks5://localhost:1080
+    pub proxy: Option<String>,
+}
+
+impl Default for ScraperConfig {
+    fn default() -> Self {
+        ScraperConfig {
+            headless: None,
+            concurrency: 1,
+            timeout_secs: 30,
+            proxy: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct UiConfig {
+    /// Rows shown in the job tables of the menu
+    pub page_size: usize,
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        UiConfig { page_size: 50 }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct LoggingConfig {
+    /// A level like "debug", or per module directives like "info,jobshell::scrapers=trace"
+    pub level: String,
+    /// Write JSON log lines to the logs dir in the profile's data dir
+    pub file: bool,
+    /// Daily log files kept before the oldest is deleted, 0 keeps them all
+    pub keep_days: usize,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        LoggingConfig {
+            level: "info".to_string(),
+            file: true,
+            keep_days: 14,
+        }
+    }
+}
+
+impl Config {
+    /// Every setting as `section.key`, the names `config set`, `--set` and env vars accept
+    ///
+    /// Tables like `schedule.groups` aren't listed, their entries are set as `schedule.groups.<name>.<key>`.
+    pub fn keys() -> Vec<String> {
+        let defaults = serde_json::to_value(Config::default()).unwrap();
+        let mut keys = Vec::new();
+
+        for (section, values) in defaults.as_object().unwrap() {
+            for (key, value) in values.as_object().unwrap() {
+                if !value.is_object() {
+                    keys.push(format!("{}.{}", section, key));
+                }
+            }
+        }
+
+        keys
+    }
+
+    fn is_table_key(section: &str, key: &str) -> bool {
+        serde_json::to_value(Config::default()).unwrap()[section][key].is_object()
+    }
+
+    /// A copy safe to print, API keys are masked
+    pub fn redacted(&self) -> Config {
+        let mask = |key: &Option<String>| key.as_ref().map(|_| "********".to_string());
+        let mut config = self.clone();
+        config.ai.openai_key = mask(&self.ai.openai_key);
+        config.ai.gemini_key = mask(&self.ai.gemini_key);
+
+        config
+    }
+
+    /// Catches what deserializing can't, like a webhook URL that isn't a Discord one
+    pub fn validate(&self) -> Result<(), String> {
+        let mut problems = Vec::new();
+
+        if let Some(url) = &self.notifications.discord_webhook_url {
+            if !url.starts_with("https://discord.com/api/webhooks/") {
+                problems.push(
+                    "notifications.discord_webhook_url must be a valid Discord webhook URL"
+                        .to_string(),
+                );
+            }
+        }
+
+        if let Some(interval) = self.schedule.interval_hours {
+            if !(1..=12).contains(&interval) {
+                problems.push("schedule.interval_hours must be between 1 and 12".to_string());
+            }
+        }
+
+        if let Some(cron) = &self.schedule.cron {
+            if let Err(e) = check_cron(cron) {
+                problems.push(format!("schedule.cron {}", e));
+            }
+        }
+
+        let mut grouped: Vec<String> = Vec::new();
+        for (name, group) in &self.schedule.groups {
+            if let Err(e) = check_cron(&group.cron) {
+                problems.push(format!("schedule.groups.{}.cron {}", name, e));
+            }
+
+            if group.companies.is_empty() {
+                problems.push(format!("schedule.groups.{}.companies is empty", name));
+            }
+
+            for company in &group.companies {
+                match CompanyOption::from_name(company).map(|c| c.to_string()) {
+                    Ok(company) if grouped.contains(&company) => problems.push(format!(
+                        "schedule.groups.{}: {} is already in another group",
+                        name, company
+                    )),
+                    Ok(company) => grouped.push(company),
+                    Err(e) => problems.push(format!("schedule.groups.{}: {}", name, e)),
+                }
+            }
+        }
+
+        if self.scraper.concurrency == 0 {
+            problems.push("scraper.concurrency must be at least 1".to_string());
+        }
+
+        if self.scraper.timeout_secs == 0 {
+            problems.push("scraper.timeout_secs must be at least 1".to_string());
+        }
+
+        if let Some(proxy) = &self.scraper.proxy {
+            if let Err(e) = Proxy::all(proxy) {
+                problems.push(format!("scraper.proxy is not a valid proxy URL: {}", e));
+            }
+        }
+
+        if self.ui.page_size == 0 {
+            problems.push("ui.page_size must be at least 1".to_string());
+        }
+
+        if let Err(e) = tracing_subscriber::EnvFilter::try_new(&self.logging.level) {
+            problems.push(format!(
+                "logging.level is not a valid level or directive: {}",
+                e
+            ));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems.join("\n"))
+        }
+    }
+}
+
+fn check_cron(cron: &str) -> Result<(), String> {
+    tokio_cron_scheduler::Job::new_async(cron, |_, _| Box::pin(async {}))
+        .map(|_| ())
+        .map_err(|_| {
+            format!(
+                "'{}' is not a cron expression like \"0 0 9-17 * * Mon-Fri\" or \"every 30 minutes\"",
+                cron
+            )
+        })
+}
+
+pub fn default_config_path() -> PathBuf {
+    let project_dir = ProjectDirs::from("org", "jobshell", "jobshell")
+        .expect("Problem configuring the project directory");
+
+    project_dir.config_dir().join("config.toml")
+}
+
+fn parse(content: &str) -> Result<Map<String, Value>, toml::de::Error> {
+    toml::from_str(content)
+}
+
+/// Reads `value` the way the file would after `key = `, None when it isn't valid TOML
+fn parse_value(value: &str) -> Option<Value> {
+    parse(&format!("value = {}", value)).ok()?.remove("value")
+}
+
+/// The config file as a TOML table, empty when there is no file yet
+pub fn read_file(path: &Path) -> AppResult<Map<String, Value>> {
+    if !path.exists() {
+        return Ok(Map::new());
+    }
+
+    let content = fs::read_to_string(path)?;
+    parse(&content).map_err(|e| format!("{}: {}", path.display(), e).into())
+}
+
+/// Writes `key` as it is in `table` to the file, the rest of the file and its comments are left alone
+pub fn write_setting(path: &Path, key: &str, table: &Map<String, Value>) -> AppResult<()> {
+    let content = if path.exists() {
+        fs::read_to_string(path)?
+    } else {
+        String::new()
+    };
+    let mut document: DocumentMut = content
+        .parse()
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let path_parts: Vec<&str> = key.split('.').collect();
+    let (first, rest) = path_parts.split_first().unwrap();
+    let json = rest
+        .iter()
+        .try_fold(table.get(*first), |v, part| Some(v?.get(*part)))
+        .flatten()
+        .ok_or_else(|| format!("{} is not set", key))?;
+    let mut value: toml_edit::Value = toml::Value::try_from(json)?.to_string().parse()?;
+
+    let (name, tables) = path_parts.split_last().unwrap();
+    let mut item = document.as_item_mut();
+    for part in tables {
+        item = &mut item[*part];
+        if item.is_none() {
+            // INFO: A table only holding other tables doesn't need its own [header]
+            let mut table = toml_edit::Table::new();
+            table.set_implicit(true);
+            *item = toml_edit::Item::Table(table);
+        }
+    }
+
+    // Keep a trailing comment on the line being changed
+    if let Some(old) = item.get(name).and_then(|old| old.as_value()) {
+        *value.decor_mut() = old.decor().clone();
+    }
+    item[*name] = toml_edit::Item::Value(value);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, document.to_string())?;
+
+    Ok(())
+}
+
+/// Sets `key` in `table`, the value is read as TOML unless only a plain string fits the setting
+pub fn set_value(table: &mut Map<String, Value>, key: &str, value: &str) -> AppResult<()> {
+    let path: Vec<&str> = key.split('.').collect();
+    let known = match path.as_slice() {
+        [_, _] => Config::keys().iter().any(|k| k == key),
+        [section, name, _, ..] => Config::is_table_key(section, name),
+        _ => false,
+    };
+
+    if !known || path.iter().any(|part| part.is_empty()) {
+        return Err(format!(
+            "Unknown setting '{}', expected one of: {}, schedule.groups.<name>.cron|companies|jitter_secs",
+            key,
+            Config::keys().join(", ")
+        )
+        .into());
+    }
+
+    // INFO: So `ai.openai_key = 123` stays a string while `scraper.concurrency = 4` becomes a number
+    let typed = parse_value(value);
+    let (name, tables) = path.split_last().unwrap();
+
+    for candidate in typed.into_iter().chain([Value::String(value.to_string())]) {
+        let mut candidate_table = table.clone();
+        let mut target = &mut candidate_table;
+
+        for part in tables {
+            target = target
+                .entry(part.to_string())
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .ok_or_else(|| format!("'{}' is not a table", part))?;
+        }
+        target.insert(name.to_string(), candidate);
+
+        if Config::from_table(&candidate_table).is_ok() {
+            *table = candidate_table;
+            return Ok(());
+        }
+    }
+
+    Err(format!("'{}' is not a valid value for {}", value, key).into())
+}
+
+impl Config {
+    pub fn from_table(table: &Map<String, Value>) -> AppResult<Config> {
+        Ok(serde_json::from_value(Value::Object(table.clone()))?)
+    }
+
+    /// Defaults, then the config file, then env vars, then `overrides` as `section.key=value`
+    pub fn load(path: &Path, overrides: &[String]) -> AppResult<Config> {
+        let mut table = read_file(path)?;
+
+        // INFO: JOBSHELL_SCRAPER_PROXY sets scraper.proxy and so on for every key
+        for key in Config::keys() {
+            let var = format!("JOBSHELL_{}", key.replace('.', "_").to_uppercase());
+            if let Ok(value) = env::var(&var) {
+                set_value(&mut table, &key, &value).map_err(|e| format!("{}: {}", var, e))?;
+            }
+        }
+
+        for (var, key) in LEGACY_ENV_VARS {
+            let (section, name) = key.split_once('.').unwrap();
+            let already_set = table
+                .get(section)
+                .and_then(|section| section.get(name))
+                .is_some();
+
+            if let (false, Ok(value)) = (already_set, env::var(var)) {
+                set_value(&mut table, key, &value)?;
+            }
+        }
+
+        for arg in overrides {
+            let (key, value) = arg
+                .split_once('=')
+                .ok_or_else(|| format!("--set expects key=value, got '{}'", arg))?;
+            set_value(&mut table, key.trim(), value.trim())
+                .map_err(|e| format!("--set {}: {}", arg, e))?;
+        }
+
+        let config =
+            Config::from_table(&table).map_err(|e| format!("{}: {}", path.display(), e))?;
+        config.validate()?;
+
+        Ok(config)
+    }
+}
+
+/// Resolves the config for this run, `path` defaults to config.toml in the config dir
+pub fn init(path: Option<PathBuf>, overrides: Vec<String>) -> AppResult<()> {
+    let _ = SOURCE.set((path.unwrap_or_else(default_config_path), overrides));
+    let _ = CONFIG.set(resolve()?);
+
+    Ok(())
+}
+
+/// The config file this run reads and `config set` writes
+pub fn path() -> PathBuf {
+    SOURCE
+        .get()
+        .map(|(path, _)| path.clone())
+        .unwrap_or_else(default_config_path)
+}
+
+/// Loads the config again from the same sources as `init`, with any error instead of the defaults
+pub fn resolve() -> AppResult<Config> {
+    let overrides = SOURCE
+        .get()
+        .map(|(_, overrides)| overrides.as_slice())
+        .unwrap_or_default();
+
+    Config::load(&path(), overrides)
+}
+
+/// The resolved config, the defaults when `init` never ran (e.g. in tests)
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+/// Browser options for a scraper, `headless` is its own choice unless `scraper.headless` overrides it
+pub fn launch_options(headless: bool) -> LaunchOptions<'static> {
+    let scraper = &get().scraper;
+
+    LaunchOptions {
+        headless: scraper.headless.unwrap_or(headless),
+        idle_browser_timeout: Duration::from_secs(scraper.timeout_secs),
+        proxy_server: scraper.proxy.as_deref(),
+        ..LaunchOptions::default()
+    }
+}
+
+/// An HTTP client with the configured timeout and proxy
+pub fn http_client() -> Client {
+    let scraper = &get().scraper;
+    let mut builder = Client::builder().timeout(Duration::from_secs(scraper.timeout_secs));
+
+    // INFO: The proxy URL was checked by validate when the config loaded
+    if let Some(proxy) = &scraper.proxy {
+        builder = builder.proxy(Proxy::all(proxy).expect("Invalid scraper.proxy"));
+    }
+
+    builder.build().expect("Problem building the HTTP client")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn config_file(content: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("jobshell-{}.toml", uuid::Uuid::new_v4()));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_file_layer() {
+        let path = config_file(
+            r#"
+# Posted to by Discord mode
+[notifications]
+discord_webhook_url = "https://discord.com/api/webhooks/1/abc" # trailing comment
+
+[scraper]
+concurrency = 4
+proxy = 'http://localhost:8080'
+
+[schedule.groups.startups]
+cron = "every 30 minutes"
+companies = [
+    "Stripe",
+    "Coinbase",
+]
+"#,
+        );
+        let config = Config::load(&path, &[]);
+        fs::remove_file(&path).unwrap();
+
+        let config = config.unwrap();
+        assert_eq!(config.scraper.concurrency, 4);
+        assert_eq!(
+            config.scraper.proxy.as_deref(),
+            Some("http://localhost:8080")
+        );
+        assert_eq!(config.scraper.timeout_secs, 30);
+        assert_eq!(config.schedule.groups["startups"].companies.len(), 2);
+
+        let missing = env::temp_dir().join(format!("jobshell-{}.toml", uuid::Uuid::new_v4()));
+        assert_eq!(Config::load(&missing, &[]).unwrap().scraper.concurrency, 1);
+
+        let table = parse("scraper = { concurrency = 2, headless = true }").unwrap();
+        assert_eq!(table["scraper"]["concurrency"], 2);
+        assert!(parse("[scraper]\nconcurrency = 1\nconcurrency = 2").is_err());
+    }
+
+    #[test]
+    fn test_env_layer() {
+        let path = config_file("[scraper]\ntimeout_secs = 10\n");
+
+        env::set_var("JOBSHELL_SCRAPER_TIMEOUT_SECS", "45");
+        let config = Config::load(&path, &[]);
+        env::set_var("JOBSHELL_SCRAPER_TIMEOUT_SECS", "soon");
+        let invalid = Config::load(&path, &[]);
+        env::remove_var("JOBSHELL_SCRAPER_TIMEOUT_SECS");
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(config.unwrap().scraper.timeout_secs, 45);
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_legacy_env_layer() {
+        let unset = config_file("");
+        let set = config_file("[ai]\ngemini_model = \"from-the-file\"\n");
+
+        env::set_var("GEMINI_MODEL", "from-the-env");
+        let from_env = Config::load(&unset, &[]);
+        let from_file = Config::load(&set, &[]);
+        env::remove_var("GEMINI_MODEL");
+        fs::remove_file(&unset).unwrap();
+        fs::remove_file(&set).unwrap();
+
+        // The old variables only fill in what the file leaves unset
+        assert_eq!(from_env.unwrap().ai.gemini_model, "from-the-env");
+        assert_eq!(from_file.unwrap().ai.gemini_model, "from-the-file");
+    }
+
+    #[test]
+    fn test_set_layer() {
+        let path = config_file("[scraper]\nconcurrency = 4\n");
+
+        let config = Config::load(
+            &path,
+            &[
+                "scraper.concurrency=8".to_string(),
+                "ai.openai_key = 123".to_string(),
+            ],
+        );
+        let missing_value = Config::load(&path, &["scraper.concurrency".to_string()]);
+        let unknown_key = Config::load(&path, &["scraper.threads=4".to_string()]);
+        fs::remove_file(&path).unwrap();
+
+        let config = config.unwrap();
+        assert_eq!(config.scraper.concurrency, 8);
+        assert_eq!(config.ai.openai_key.as_deref(), Some("123"));
+        assert!(missing_value.is_err());
+        assert!(unknown_key.is_err());
+
+        let mut table = Map::new();
+        set_value(&mut table, "scraper.headless", "false").unwrap();
+        assert_eq!(
+            Config::from_table(&table).unwrap().scraper.headless,
+            Some(false)
+        );
+        assert!(set_value(&mut table, "scraper.concurrency", "many").is_err());
+
+        set_value(
+            &mut table,
+            "schedule.groups.startups.companies",
+            r#"["Stripe", "Coinbase"]"#,
+        )
+        .unwrap();
+        assert!(set_value(&mut table, "schedule.groups.startups.every", "1").is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut table = Map::new();
+        set_value(
+            &mut table,
+            "schedule.groups.startups.cron",
+            "every 30 minutes",
+        )
+        .unwrap();
+        set_value(
+            &mut table,
+            "schedule.groups.startups.companies",
+            r#"["Stripe", "Coinbase"]"#,
+        )
+        .unwrap();
+        assert!(Config::from_table(&table).unwrap().validate().is_ok());
+
+        set_value(&mut table, "schedule.groups.corps.cron", "0 0 9 * * *").unwrap();
+        set_value(
+            &mut table,
+            "schedule.groups.corps.companies",
+            r#"["stripe"]"#,
+        )
+        .unwrap();
+        assert!(Config::from_table(&table).unwrap().validate().is_err());
+        table["schedule"]["groups"]
+            .as_object_mut()
+            .unwrap()
+            .remove("corps");
+
+        set_value(&mut table, "schedule.cron", "whenever").unwrap();
+        assert!(Config::from_table(&table).unwrap().validate().is_err());
+        set_value(&mut table, "schedule.cron", "0 0 9-17 * * Mon-Fri").unwrap();
+        assert!(Config::from_table(&table).unwrap().validate().is_ok());
+
+        set_value(&mut table, "logging.level", "info,jobshell::scrapers=debug").unwrap();
+        assert!(Config::from_table(&table).unwrap().validate().is_ok());
+        set_value(&mut table, "logging.level", "jobshell=loud").unwrap();
+        assert!(Config::from_table(&table).unwrap().validate().is_err());
+        set_value(&mut table, "logging.level", "warn").unwrap();
+
+        set_value(&mut table, "schedule.interval_hours", "24").unwrap();
+        assert!(Config::from_table(&table).unwrap().validate().is_err());
+        set_value(&mut table, "schedule.interval_hours", "6").unwrap();
+
+        set_value(
+            &mut table,
+            "notifications.discord_webhook_url",
+            "https://example.com",
+        )
+        .unwrap();
+        assert!(Config::from_table(&table).unwrap().validate().is_err());
+    }
+
+    #[test]
+    fn test_write_setting_keeps_comments() {
+        let path = env::temp_dir().join(format!("jobshell-{}.toml", uuid::Uuid::new_v4()));
+        fs::write(
+            &path,
+            "# My settings\n[scraper]\nconcurrency = 2 # two is plenty\n",
+        )
+        .unwrap();
+
+        let mut table = read_file(&path).unwrap();
+        set_value(&mut table, "scraper.concurrency", "4").unwrap();
+        write_setting(&path, "scraper.concurrency", &table).unwrap();
+        set_value(
+            &mut table,
+            "schedule.groups.startups.companies",
+            r#"["Stripe"]"#,
+        )
+        .unwrap();
+        write_setting(&path, "schedule.groups.startups.companies", &table).unwrap();
+
+        let written = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(written.starts_with("# My settings\n"));
+        assert!(written.contains("concurrency = 4 # two is plenty"));
+        assert!(written.contains("[schedule.groups.startups]"));
+        assert!(!written.contains("[schedule]"));
+        assert_eq!(parse(&written).unwrap(), table);
+    }
+}
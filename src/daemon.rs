@@ -0,0 +1,365 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info};
+
+use crate::{
+    args::DaemonArgs,
+    config,
+    discord::{self, start_discord_scheduler, ScanSchedule},
+    error::AppResult,
+    models::data::Data,
+    profiles,
+};
+
+// INFO: Only set while running as a daemon, the state file is only written then
+static DAEMON: OnceLock<Daemon> = OnceLock::new();
+
+// INFO: Scheduled scans each load, change and save the data, so they take turns, and so does
+// anything else that saves from the scheduler. Shutting down takes it too, to wait for a running
+// scan instead of cutting a save short.
+static SCAN_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DaemonStatus {
+    Idle,
+    Scanning,
+    Stopping,
+    Stopped,
+}
+
+/// Written to `daemon.json` in the profile's data dir whenever the daemon changes what it is doing
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DaemonState {
+    pub pid: u32,
+    pub profile: String,
+    pub status: DaemonStatus,
+    pub started_at: DateTime<Utc>,
+    /// The schedule the last scan ran on, "default" or a group's name
+    pub last_scan_schedule: Option<String>,
+    pub last_scan_started_at: Option<DateTime<Utc>>,
+    pub last_scan_finished_at: Option<DateTime<Utc>>,
+    pub last_scan_new_jobs: Option<usize>,
+    /// When each schedule runs next
+    pub next_scans: BTreeMap<String, DateTime<Utc>>,
+}
+
+struct Daemon {
+    state_path: PathBuf,
+    state: Mutex<DaemonState>,
+}
+
+impl Daemon {
+    fn update(&self, f: impl FnOnce(&mut DaemonState)) {
+        let mut state = self.state.lock().unwrap();
+        f(&mut state);
+
+        if let Err(e) = fs::write(
+            &self.state_path,
+            serde_json::to_string_pretty(&*state).unwrap(),
+        ) {
+            error!("Could not write {}: {}", self.state_path.display(), e);
+        }
+    }
+}
+
+/// Keeps other scans waiting until dropped, and the daemon's state on this scan
+pub struct ScanGuard {
+    schedule: String,
+    pub new_jobs: usize,
+    pub next_scan_at: Option<DateTime<Utc>>,
+    _lock: tokio::sync::MutexGuard<'static, ()>,
+}
+
+/// Waits for any running scan to finish, `schedule` names the schedule this one runs on
+pub async fn begin_scan(schedule: &str) -> ScanGuard {
+    let lock = SCAN_LOCK.lock().await;
+
+    if let Some(daemon) = DAEMON.get() {
+        daemon.update(|state| {
+            state.status = DaemonStatus::Scanning;
+            state.last_scan_schedule = Some(schedule.to_string());
+            state.last_scan_started_at = Some(Utc::now());
+        });
+    }
+
+    ScanGuard {
+        schedule: schedule.to_string(),
+        new_jobs: 0,
+        next_scan_at: None,
+        _lock: lock,
+    }
+}
+
+/// Waits for any running scan, for scheduled jobs other than scans that load and save the data
+///
+/// A scan saves its own copy of the data after every company, anything saved meanwhile is lost.
+pub async fn lock_data() -> tokio::sync::MutexGuard<'static, ()> {
+    SCAN_LOCK.lock().await
+}
+
+impl Drop for ScanGuard {
+    fn drop(&mut self) {
+        if let Some(daemon) = DAEMON.get() {
+            daemon.update(|state| {
+                if state.status == DaemonStatus::Scanning {
+                    state.status = DaemonStatus::Idle;
+                }
+                state.last_scan_finished_at = Some(Utc::now());
+                state.last_scan_new_jobs = Some(self.new_jobs);
+                if let Some(next_scan_at) = self.next_scan_at {
+                    state.next_scans.insert(self.schedule.clone(), next_scan_at);
+                }
+            });
+        }
+    }
+}
+
+/// Discord mode without prompts, for systemd, Docker and other supervisors
+///
+/// Everything comes from the config, env vars and `--set`, a missing setting is an error rather than
+/// a prompt. The webhook can also be the one saved with the profile, see `discord::webhook_url`.
+/// SIGTERM and SIGINT stop the scheduler and wait for a running scan, a second one exits right away.
+pub async fn run(args: DaemonArgs) -> AppResult<()> {
+    let config = config::get();
+
+    let webhook_url = discord::webhook_url(&config.notifications, &Data::get_data()).ok_or(
+        "notifications.discord_webhook_url must be set, or a webhook saved with the profile by Discord mode, to run as a daemon",
+    )?;
+    let cron = match (&config.schedule.cron, config.schedule.interval_hours) {
+        (Some(cron), _) => cron.clone(),
+        (None, Some(interval)) => format!("every {} hours", interval),
+        (None, None) => {
+            return Err(
+                "schedule.cron or schedule.interval_hours must be set to run as a daemon".into(),
+            )
+        }
+    };
+
+    // INFO: `--log-file` is set up with the rest of logging, see `logging::init`
+    let mut signals = ShutdownSignals::new()?;
+
+    let data_dir = Data::get_data_dir();
+    fs::create_dir_all(&data_dir)?;
+
+    let pid_file = args
+        .pid_file
+        .clone()
+        .unwrap_or_else(|| data_dir.join("daemon.pid"));
+    let _pid_file = PidFile::create(pid_file)?;
+
+    let daemon = DAEMON.get_or_init(|| Daemon {
+        state_path: data_dir.join("daemon.json"),
+        state: Mutex::new(DaemonState {
+            pid: std::process::id(),
+            profile: profiles::active_profile(),
+            status: DaemonStatus::Idle,
+            started_at: Utc::now(),
+            last_scan_schedule: None,
+            last_scan_started_at: None,
+            last_scan_finished_at: None,
+            last_scan_new_jobs: None,
+            next_scans: BTreeMap::new(),
+        }),
+    });
+    daemon.update(|_| {});
+
+    let schedules = ScanSchedule::from_config(
+        &config.schedule,
+        cron,
+        config.schedule.scan_all_companies.unwrap_or(false),
+    );
+    let (mut scheduler, scan_jobs) = start_discord_scheduler(
+        webhook_url,
+        schedules,
+        config.schedule.notify_on_baseline.unwrap_or(false),
+    )
+    .await?;
+
+    for (schedule, job) in scan_jobs {
+        if let Ok(Some(next_scan_at)) = scheduler.next_tick_for_job(job).await {
+            daemon.update(|state| {
+                state.next_scans.insert(schedule, next_scan_at);
+            });
+        }
+    }
+
+    info!(
+        "Daemon started for profile {} with pid {}",
+        profiles::active_profile(),
+        std::process::id()
+    );
+
+    let received = signals.recv().await;
+    info!("Received {}, shutting down", received);
+
+    daemon.update(|state| state.status = DaemonStatus::Stopping);
+    scheduler.shutdown().await?;
+
+    if SCAN_LOCK.try_lock().is_err() {
+        info!("Waiting for the running scan to finish, signal again to stop right away");
+        tokio::select! {
+            _ = SCAN_LOCK.lock() => {}
+            _ = signals.recv() => info!("Stopping without waiting for the scan"),
+        }
+    }
+
+    daemon.update(|state| {
+        state.status = DaemonStatus::Stopped;
+        state.next_scans.clear();
+    });
+    info!("Daemon stopped");
+
+    Ok(())
+}
+
+/// The daemon's pid file, removed however `run` returns
+struct PidFile(PathBuf);
+
+impl PidFile {
+    /// Refuses to start while the pid in an existing file is still running, a file left behind by
+    /// a daemon that was killed is taken over
+    fn create(path: PathBuf) -> AppResult<Self> {
+        // INFO: Only one of two daemons starting at once can create the file, the other sees it
+        let write_new = |path: &Path| -> io::Result<()> {
+            OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(path)?
+                .write_all(format!("{}\n", std::process::id()).as_bytes())
+        };
+
+        let written = match write_new(&path) {
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                let running_pid = fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|pid| pid.trim().parse::<u32>().ok())
+                    .filter(|pid| *pid != std::process::id() && process_is_running(*pid));
+                if let Some(pid) = running_pid {
+                    return Err(format!(
+                        "A daemon is already running with pid {}, see {}",
+                        pid,
+                        path.display()
+                    )
+                    .into());
+                }
+
+                let _ = fs::remove_file(&path);
+                write_new(&path)
+            }
+            written => written,
+        };
+        written.map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+
+        Ok(Self(path))
+    }
+}
+
+impl Drop for PidFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn process_is_running(pid: u32) -> bool {
+    std::path::Path::new("/proc").join(pid.to_string()).exists()
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+fn process_is_running(pid: u32) -> bool {
+    std::process::Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .stderr(std::process::Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+#[cfg(not(unix))]
+fn process_is_running(pid: u32) -> bool {
+    std::process::Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid), "/NH"])
+        .output()
+        .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).contains(&pid.to_string()))
+}
+
+/// SIGTERM and SIGINT, or Ctrl+C where there are no Unix signals
+///
+/// Listening starts when it is created, so a signal that arrives during startup isn't missed.
+struct ShutdownSignals {
+    #[cfg(unix)]
+    sigterm: tokio::signal::unix::Signal,
+    #[cfg(unix)]
+    sigint: tokio::signal::unix::Signal,
+}
+
+impl ShutdownSignals {
+    fn new() -> AppResult<Self> {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{signal, SignalKind};
+
+            Ok(ShutdownSignals {
+                sigterm: signal(SignalKind::terminate())?,
+                sigint: signal(SignalKind::interrupt())?,
+            })
+        }
+
+        #[cfg(not(unix))]
+        Ok(ShutdownSignals {})
+    }
+
+    async fn recv(&mut self) -> &'static str {
+        #[cfg(unix)]
+        {
+            tokio::select! {
+                _ = self.sigterm.recv() => "SIGTERM",
+                _ = self.sigint.recv() => "SIGINT",
+            }
+        }
+
+        #[cfg(not(unix))]
+        {
+            let _ = tokio::signal::ctrl_c().await;
+            "Ctrl+C"
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_pid_file() {
+        let path = std::env::temp_dir().join(format!("jobshell-{}.pid", uuid::Uuid::new_v4()));
+
+        drop(PidFile::create(path.clone()).unwrap());
+        assert!(!path.exists());
+
+        // INFO: No pid gets this high, so this is a daemon that was killed
+        fs::write(&path, "4294967295\n").unwrap();
+        let pid_file = PidFile::create(path.clone()).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            format!("{}\n", std::process::id())
+        );
+        drop(pid_file);
+        assert!(!path.exists());
+
+        // The test runner's parent is still running
+        fs::write(&path, format!("{}\n", std::os::unix::process::parent_id())).unwrap();
+        assert!(PidFile::create(path.clone()).is_err());
+        assert!(path.exists());
+        fs::remove_file(&path).unwrap();
+    }
+}
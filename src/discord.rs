@@ -1,14 +1,23 @@
-use reqwest::Client;
+use std::time::Duration;
+
+use chrono::Utc;
 use serde::Serialize;
 use strum::IntoEnumIterator;
 use tokio_cron_scheduler::{Job as CronJob, JobScheduler};
+use tracing::{error, info, info_span, Instrument};
+use uuid::Uuid;
 
 use crate::{
     company_options::{CompanyOption, ScrapeJobs},
+    config::{http_client, NotificationsConfig, ScheduleConfig},
+    daemon::{self, ScanGuard},
     error::AppResult,
     models::{
         ai::{AiModel, OpenAIClient},
         data::Data,
+        reminder::Reminder,
+        run::{CompanyTimer, RunTrigger, ScanRun},
+        saved_search::match_saved_searches,
         scraper::Job,
     },
     reports::create_report,
@@ -43,113 +52,358 @@ struct Field {
     inline: bool,
 }
 
+/// Which companies one of Discord mode's scheduled scans covers
+#[derive(Clone, Debug)]
+pub enum ScanScope {
+    /// Followed companies and companies with connections, or every company, minus the grouped ones
+    Default {
+        scan_all_companies: bool,
+        except: Vec<String>,
+    },
+    /// The companies of a `schedule.groups` entry, whether followed or not
+    Group(Vec<String>),
+}
+
+/// A scan Discord mode runs on its own cron schedule
+#[derive(Clone, Debug)]
+pub struct ScanSchedule {
+    /// "default", or the group's name
+    pub name: String,
+    pub cron: String,
+    pub jitter_secs: u64,
+    pub scope: ScanScope,
+}
+
+impl ScanSchedule {
+    /// The default schedule on `cron`, plus one per group in the config
+    pub fn from_config(
+        schedule: &ScheduleConfig,
+        cron: String,
+        scan_all_companies: bool,
+    ) -> Vec<ScanSchedule> {
+        // INFO: The config was validated, names only need normalizing to the company keys
+        let company_keys = |companies: &Vec<String>| -> Vec<String> {
+            companies
+                .iter()
+                .filter_map(|c| CompanyOption::from_name(c).ok())
+                .map(|c| c.to_string())
+                .collect()
+        };
+
+        let mut schedules = vec![ScanSchedule {
+            name: "default".to_string(),
+            cron,
+            jitter_secs: schedule.jitter_secs,
+            scope: ScanScope::Default {
+                scan_all_companies,
+                except: schedule
+                    .groups
+                    .values()
+                    .flat_map(|g| company_keys(&g.companies))
+                    .collect(),
+            },
+        }];
+
+        schedules.extend(schedule.groups.iter().map(|(name, group)| ScanSchedule {
+            name: name.clone(),
+            cron: group.cron.clone(),
+            jitter_secs: group.jitter_secs.unwrap_or(schedule.jitter_secs),
+            scope: ScanScope::Group(company_keys(&group.companies)),
+        }));
+
+        schedules
+    }
+}
+
+/// The webhook Discord mode posts to without asking
+///
+/// `notifications.discord_webhook_url` wins when it is set, from the config file, a `JOBSHELL_*` env
+/// var or `--set`, otherwise the webhook saved with the profile is used.
+pub fn webhook_url(notifications: &NotificationsConfig, data: &Data) -> Option<String> {
+    notifications
+        .discord_webhook_url
+        .clone()
+        .or_else(|| data.discord_webhook_url.clone())
+}
+
 pub async fn initialize_discord_mode(
     webhook_url: String,
-    cron_interval: u64,
-    scan_all_companies: bool,
+    schedules: Vec<ScanSchedule>,
+    notify_on_baseline: bool,
 ) -> AppResult<()> {
+    start_discord_scheduler(webhook_url, schedules, notify_on_baseline).await?;
+    info!("Job scheduler started! Press Ctrl+C to exit.");
+
+    // Wait for shutdown signal
+    tokio::signal::ctrl_c().await?;
+    info!("Shutting down scheduler...");
+
+    Ok(())
+}
+
+/// Starts scanning and sending reminders, returns the scheduler and the job id of each schedule
+pub async fn start_discord_scheduler(
+    webhook_url: String,
+    schedules: Vec<ScanSchedule>,
+    notify_on_baseline: bool,
+) -> AppResult<(JobScheduler, Vec<(String, Uuid)>)> {
     let scheduler = JobScheduler::new().await?;
 
-    let cron = format!("every {} hours", cron_interval);
-    println!("Using cron expression: {}", cron);
+    // INFO: Reminders are checked far more often than jobs are scanned so they arrive close to due time
+    let reminder_webhook_url = webhook_url.clone();
+    let reminder_job = CronJob::new_async("0 */15 * * * *", move |_uuid, _lock| {
+        let webhook_url = reminder_webhook_url.clone();
+        Box::pin(async move {
+            deliver_due_reminders(&webhook_url).await;
+        })
+    })?;
+
+    let mut scan_jobs = Vec::new();
+    for schedule in schedules {
+        info!(
+            "Scanning {} on \"{}\"{}",
+            match &schedule.scope {
+                ScanScope::Default { .. } => "companies outside a group".to_string(),
+                ScanScope::Group(companies) => companies.join(", "),
+            },
+            schedule.cron,
+            if schedule.jitter_secs > 0 {
+                format!(" with up to {}s of jitter", schedule.jitter_secs)
+            } else {
+                String::new()
+            }
+        );
 
-    let job = CronJob::new_async(cron, move |uuid, mut lock| {
+        let name = schedule.name.clone();
         let webhook_url = webhook_url.clone();
-        Box::pin(async move {
-            println!("Discord cron starting!");
-            let data = Data::get_data();
+        let job = CronJob::new_async(schedule.cron.clone(), move |uuid, mut lock| {
+            let schedule = schedule.clone();
+            let webhook_url = webhook_url.clone();
+            let span = info_span!("schedule", name = %schedule.name);
+            Box::pin(
+                async move {
+                    if schedule.jitter_secs > 0 {
+                        let delay = fastrand::u64(0..=schedule.jitter_secs);
+                        tokio::time::sleep(Duration::from_secs(delay)).await;
+                    }
 
-            let (new_jobs_based_on_smart_criteria, total_new_jobs) =
-                scan_for_new_jobs(scan_all_companies).await;
+                    let mut scan = daemon::begin_scan(&schedule.name).await;
+                    scan.next_scan_at = lock.next_tick_for_job(uuid).await.ok().flatten();
 
-            if total_new_jobs.is_empty() {
-                println!("No new jobs detected");
-                return; // Return Ok for successful empty check
-            }
+                    run_scheduled_scan(&schedule, webhook_url, notify_on_baseline, &mut scan).await;
 
-            // converting discord format into report format
-            let formatted_jobs_for_reports: Vec<crate::handlers::handlers::FormattedJob> =
-                total_new_jobs
-                    .iter()
-                    .map(|j| crate::handlers::handlers::FormattedJob {
-                        display_name: format!("{} @ {}", j.title, j.company),
-                        company: j.company.clone(),
-                        job: j.job.clone(),
-                    })
-                    .collect();
+                    match scan.next_scan_at {
+                        Some(ts) => info!("Next {} run scheduled for: {:?}", schedule.name, ts),
+                        None => info!("Could not determine next run time"),
+                    }
+                }
+                .instrument(span),
+            )
+        })?;
 
-            if let Err(e) = create_report(
-                &formatted_jobs_for_reports,
-                crate::reports::ReportMode::HTML,
-            ) {
-                eprintln!("Error creating report: {e}");
-            }
+        scan_jobs.push((name, scheduler.add(job).await?));
+    }
 
-            println!("Finished Scraping");
-            println!("Building messages and sending to Discord");
+    scheduler.add(reminder_job).await?;
 
-            let jobs_to_deploy = if data.smart_criteria_enabled {
-                new_jobs_based_on_smart_criteria
-            } else {
-                total_new_jobs
-            };
+    // Start the scheduler
+    scheduler.start().await?;
+
+    Ok((scheduler, scan_jobs))
+}
 
-            deploy_messages_to_discord(jobs_to_deploy, webhook_url, 2).await;
+async fn run_scheduled_scan(
+    schedule: &ScanSchedule,
+    webhook_url: String,
+    notify_on_baseline: bool,
+    scan: &mut ScanGuard,
+) {
+    info!("Discord cron starting! ({})", schedule.name);
 
-            println!("Process finished!");
+    let (new_jobs_based_on_smart_criteria, total_new_jobs) =
+        scan_for_new_jobs(schedule, notify_on_baseline).await;
+    let data = Data::get_data();
 
-            if let Ok(Some(ts)) = lock.next_tick_for_job(uuid).await {
-                println!("Next run scheduled for: {:?}", ts);
-            } else {
-                println!("Could not determine next run time");
-            }
+    scan.new_jobs = total_new_jobs.len();
+
+    if total_new_jobs.is_empty() {
+        info!("No new jobs detected");
+        return;
+    }
+
+    // converting discord format into report format
+    let formatted_jobs_for_reports: Vec<crate::handlers::handlers::FormattedJob> = total_new_jobs
+        .iter()
+        .map(|j| crate::handlers::handlers::FormattedJob {
+            display_name: format!("{} @ {}", j.title, j.company),
+            company: j.company.clone(),
+            job: j.job.clone(),
         })
-    })?;
+        .collect();
 
-    // Add job to the scheduler
-    scheduler.add(job).await?;
+    if let Err(e) = create_report(
+        &formatted_jobs_for_reports,
+        crate::reports::ReportMode::HTML,
+    ) {
+        error!("Error creating report: {e}");
+    }
 
-    // Start the scheduler
-    scheduler.start().await?;
-    println!("Job scheduler started! Press Ctrl+C to exit.");
+    info!("Finished Scraping");
+    info!("Building messages and sending to Discord");
 
-    // Wait for shutdown signal
-    tokio::signal::ctrl_c().await?;
-    println!("Shutting down scheduler...");
+    // INFO: Saved searches see every new job, smart criteria only narrows the main channel
+    let scanned_jobs: Vec<(String, Job)> = total_new_jobs
+        .iter()
+        .map(|j| (j.company.clone(), j.job.clone()))
+        .collect();
 
-    Ok(())
+    for matches in match_saved_searches(&data.saved_searches, &scanned_jobs, Utc::now()) {
+        let Some(search_webhook_url) = matches.search.discord_webhook_url.clone() else {
+            continue;
+        };
+
+        info!(
+            "Sending {} match(es) for saved search {}",
+            matches.jobs.len(),
+            matches.search.name
+        );
+
+        let search_jobs = matches
+            .jobs
+            .into_iter()
+            .map(|(company, job)| DiscordModeFormattedJob {
+                title: job.title.clone(),
+                location: job.location.clone(),
+                link: job.link.clone(),
+                company,
+                job,
+            })
+            .collect();
+
+        deploy_messages_to_discord(
+            search_jobs,
+            search_webhook_url,
+            2,
+            &format!("New Jobs · {}", matches.search.name),
+        )
+        .await;
+    }
+
+    let jobs_to_deploy = if data.smart_criteria_enabled {
+        new_jobs_based_on_smart_criteria
+    } else {
+        total_new_jobs
+    };
+
+    deploy_messages_to_discord(jobs_to_deploy, webhook_url, 2, "New Jobs").await;
+
+    info!("Process finished!");
+}
+
+async fn deliver_due_reminders(webhook_url: &str) {
+    // INFO: Held until the reminders are marked, a scan saving an older copy would unmark them
+    let _lock = daemon::lock_data().await;
+    let mut data = Data::get_data();
+    let now = Utc::now();
+
+    let due_reminders: Vec<Reminder> = data
+        .reminders
+        .iter()
+        .filter(|r| r.is_due(now) && r.notified_at.is_none())
+        .cloned()
+        .collect();
+
+    if due_reminders.is_empty() {
+        return;
+    }
+
+    info!("Sending {} due reminder(s) to Discord", due_reminders.len());
+
+    let mut delivered = Vec::new();
+
+    // INFO: Discord caps an embed at 25 fields
+    for chunk in due_reminders.chunks(25) {
+        let message = Message {
+            username: "Jobshell".to_string(),
+            avatar_url: "https://cdn.discordapp.com/attachments/917180495849197568/1305854030899314688/jobshell_icon.png?ex=67538616&is=67523496&hm=a7dfa93aaf187bc3c791ed5a8622fa4769b5cfef186524f174cc0cf6e8b3498c&".to_string(),
+            embeds: vec![Embed {
+                title: "Follow-up Reminders".to_string(),
+                fields: chunk
+                    .iter()
+                    .map(|r| Field {
+                        name: r.subject.clone(),
+                        value: format!("{}\nDue {}", r.message, r.due_at.format("%Y-%m-%d %H:%M UTC")),
+                        inline: false,
+                    })
+                    .collect(),
+            }],
+        };
+
+        match http_client().post(webhook_url).json(&message).send().await {
+            Ok(res) if res.status().is_success() => {
+                delivered.extend(chunk.iter().map(|r| r.id));
+            }
+            Ok(res) => error!("Discord rejected reminders: {}", res.status()),
+            Err(e) => error!("Error sending reminders to Discord: {e}"),
+        }
+    }
+
+    data.mark_reminders_notified(&delivered);
 }
 
 async fn scan_for_new_jobs(
-    scan_all_companies: bool,
+    schedule: &ScanSchedule,
+    notify_on_baseline: bool,
 ) -> (Vec<DiscordModeFormattedJob>, Vec<DiscordModeFormattedJob>) {
     let mut data = Data::get_data();
 
     let mut company_options = CompanyOption::iter().collect::<Vec<CompanyOption>>();
 
-    if !scan_all_companies {
-        company_options.retain(|k| {
-            let company_key = k.to_string();
-            data.companies[company_key.as_str()].is_following
-                || !data.companies[company_key.as_str()].connections.is_empty()
-        });
+    match &schedule.scope {
+        ScanScope::Default {
+            scan_all_companies,
+            except,
+        } => {
+            company_options.retain(|k| {
+                let company_key = k.to_string();
+                !except.contains(&company_key)
+                    && (*scan_all_companies
+                        || data.companies[company_key.as_str()].is_following
+                        || !data.companies[company_key.as_str()].connections.is_empty())
+            });
+        }
+        ScanScope::Group(companies) => {
+            company_options.retain(|k| companies.contains(&k.to_string()))
+        }
     }
 
     let mut new_jobs_based_on_smart_criteria: Vec<DiscordModeFormattedJob> = Vec::new();
     let mut all_new_jobs: Vec<DiscordModeFormattedJob> = Vec::new();
+    let mut run = ScanRun {
+        schedule: Some(schedule.name.clone()),
+        ..ScanRun::start(RunTrigger::Cron)
+    };
+    let span = run.span();
     for company_option in company_options.iter() {
-        println!("Scanning new jobs @ {company_option}");
+        info!("Scanning new jobs @ {company_option}");
 
-        let jobs_payload_result = company_option.scrape_jobs(&mut data).await;
+        let timer = CompanyTimer::start(&company_option.to_string());
+        let jobs_payload_result = company_option
+            .scrape_jobs(&mut data)
+            .instrument(span.clone())
+            .await;
 
         match jobs_payload_result {
             Ok(jobs_payload) => {
-                if jobs_payload.are_new_jobs {
+                run.record(timer.finish(Ok(&jobs_payload)));
+                let new_jobs = jobs_payload.jobs_to_notify(notify_on_baseline);
+
+                if !new_jobs.is_empty() {
                     if data.smart_criteria_enabled {
-                        println!("Filtering jobs based on smart criteria");
+                        info!("Filtering jobs based on smart criteria");
                         let openai_client = OpenAIClient::new();
                         let result = openai_client
-                            .filter_jobs_based_on_smart_criteria(&jobs_payload.new_jobs)
+                            .filter_jobs_based_on_smart_criteria(&new_jobs)
                             .await;
 
                         match result {
@@ -167,13 +421,12 @@ async fn scan_for_new_jobs(
                                 new_jobs_based_on_smart_criteria.extend(formatted_jobs);
                             }
                             Err(e) => {
-                                eprintln!("Error filtering jobs for {company_option}\nError: {e}");
+                                error!("Error filtering jobs for {company_option}\nError: {e}");
                             }
                         }
                     }
 
-                    let formatted_jobs = jobs_payload
-                        .new_jobs
+                    let formatted_jobs = new_jobs
                         .iter()
                         .map(|j| DiscordModeFormattedJob {
                             title: j.title.clone(),
@@ -188,11 +441,16 @@ async fn scan_for_new_jobs(
                 }
             }
             Err(e) => {
-                eprintln!("Error scanning new jobs for {company_option}\nError: {e}");
+                run.record(timer.finish(Err(e.to_string())));
+                error!("Error scanning new jobs for {company_option}\nError: {e}");
             }
         }
     }
 
+    if let Err(e) = run.finish() {
+        error!("Could not save the scan history: {e}");
+    }
+
     (new_jobs_based_on_smart_criteria, all_new_jobs)
 }
 
@@ -200,12 +458,13 @@ async fn deploy_messages_to_discord(
     total_new_jobs: Vec<DiscordModeFormattedJob>,
     webhook_url: String,
     embeds_per_message: usize,
+    embed_title: &str,
 ) {
     let embeds: Vec<&[DiscordModeFormattedJob]> = total_new_jobs.chunks(15).collect();
 
     let messages = embeds.chunks(embeds_per_message);
 
-    println!("Number of messages: {}", messages.len());
+    info!("Number of messages: {}", messages.len());
     for message_set in messages {
         let mut new_message = Message {
             username: "Jobshell".to_string(),
@@ -215,7 +474,7 @@ async fn deploy_messages_to_discord(
 
         for embed in message_set.iter() {
             let mut new_embed = Embed {
-                title: "New Jobs".to_string(),
+                title: embed_title.to_string(),
                 fields: Vec::new(),
             };
             for job in embed.iter() {
@@ -231,15 +490,87 @@ async fn deploy_messages_to_discord(
             new_message.embeds.push(new_embed);
         }
 
-        let res = Client::new()
+        match http_client()
             .post(&webhook_url)
             .json(&new_message)
             .send()
             .await
-            .unwrap();
+        {
+            Ok(res) if res.status().is_success() => info!("Message sent!"),
+            Ok(res) => error!("Discord rejected the message: {}", res.status()),
+            Err(e) => error!("Error sending the message to Discord: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::ScheduleGroup;
+
+    #[test]
+    fn test_webhook_url_precedence() {
+        let mut notifications = NotificationsConfig::default();
+        let mut data = Data {
+            detached: true,
+            ..Data::default()
+        };
+        assert_eq!(webhook_url(&notifications, &data), None);
+
+        data.discord_webhook_url = Some("https://discord.com/api/webhooks/profile".to_string());
+        assert_eq!(
+            webhook_url(&notifications, &data).as_deref(),
+            Some("https://discord.com/api/webhooks/profile")
+        );
+
+        notifications.discord_webhook_url =
+            Some("https://discord.com/api/webhooks/config".to_string());
+        assert_eq!(
+            webhook_url(&notifications, &data).as_deref(),
+            Some("https://discord.com/api/webhooks/config")
+        );
+    }
+
+    #[test]
+    fn test_schedules_from_config() {
+        let mut config = ScheduleConfig {
+            jitter_secs: 60,
+            ..Default::default()
+        };
+        config.groups.insert(
+            "startups".to_string(),
+            ScheduleGroup {
+                cron: "every 30 minutes".to_string(),
+                companies: vec!["stripe".to_string(), "Coinbase".to_string()],
+                jitter_secs: None,
+            },
+        );
+        config.groups.insert(
+            "banks".to_string(),
+            ScheduleGroup {
+                cron: "0 0 9 * * *".to_string(),
+                companies: vec!["Chase".to_string()],
+                jitter_secs: Some(5),
+            },
+        );
+
+        let schedules = ScanSchedule::from_config(&config, "0 0 * * * *".to_string(), false);
+        let names: Vec<&str> = schedules.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["default", "banks", "startups"]);
+
+        match &schedules[0].scope {
+            ScanScope::Default { except, .. } => {
+                assert_eq!(except, &["Chase", "Stripe", "Coinbase"])
+            }
+            scope => panic!("expected the default scope, got {:?}", scope),
+        }
+        assert_eq!(schedules[0].jitter_secs, 60);
 
-        if res.status().is_success() {
-            println!("Message sent!");
+        assert_eq!(schedules[1].jitter_secs, 5);
+        assert_eq!(schedules[2].jitter_secs, 60);
+        match &schedules[2].scope {
+            ScanScope::Group(companies) => assert_eq!(companies, &["Stripe", "Coinbase"]),
+            scope => panic!("expected a group scope, got {:?}", scope),
         }
     }
 }
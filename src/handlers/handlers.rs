@@ -1,28 +1,39 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     error::Error,
     fmt::Display,
     thread::sleep,
-    time::{Duration, Instant},
+    time::Duration,
 };
 
+use chrono::{DateTime, Utc};
 use clipboard::{ClipboardContext, ClipboardProvider};
 use colored::*;
-use dialoguer::{theme::ColorfulTheme, Confirm, Editor, FuzzySelect, Input, Select};
+use dialoguer::{theme::ColorfulTheme, Confirm, Editor, FuzzySelect, Input, MultiSelect, Select};
 use headless_chrome::{Browser, LaunchOptions};
 use indicatif::{ProgressBar, ProgressStyle};
 use strum::IntoEnumIterator;
 use tabled::Table;
 
 use crate::{
-    company_options::{CompanyOption, ScrapeJobs},
+    company_options::CompanyOption,
     error::AppResult,
+    logging,
     models::{
-        ai::{AiModel, OpenAIClient},
         data::{Connection, Data},
+        description::JobDescription,
+        filter::{JobFilter, JobSort},
+        location::{normalize_location, LocationQuery},
+        metadata::EmploymentType,
+        pipeline::ApplicationStatus,
+        reminder::{self, parse_due_date, Agenda, Reminder, ReminderTarget},
+        salary::parse_annual_amount,
+        saved_search::SavedSearchMatches,
+        seniority::Seniority,
         scraper::{Job, JobsPayload, ScrapedJob},
     },
-    reports::{create_report, ReportMode},
+    profiles,
+    scrapers::job_details::fetch_and_store_job_description,
     utils::{clear_console, stall_and_present_countdown},
 };
 
@@ -133,14 +144,28 @@ pub enum JobOption {
     ReachOut,
     #[strum(to_string = "Bookmark Job [ ]")]
     Bookmark,
-    // #[strum(to_string = "Generate Job Details with AI (Experimental)")]
-    // GenerateJobDetails,
+    #[strum(to_string = "Application Status & Timeline")]
+    ApplicationStatus,
+    #[strum(to_string = "Notes, Tags & Custom Fields")]
+    Annotations,
+    #[strum(to_string = "Add Follow-up Reminder")]
+    AddReminder,
+    #[strum(to_string = "View Description")]
+    ViewDescription,
     #[strum(to_string = "Back")]
     Back,
 }
 
 pub fn prompt_user_for_job_option(job: &Job) -> JobOption {
-    let prompt = format!("Select an option for {}", job.title);
+    let mut prompt = format!("Select an option for {}", job.title);
+
+    if job.is_closed() {
+        prompt += &format!(" ({})", job.status_label()).red().to_string();
+    }
+
+    if let Some(status) = job.application_status() {
+        prompt += &format!(" [{} {}]", status.emoji(), status).cyan().to_string();
+    }
 
     let dialoguer_styles = ColorfulTheme::default();
 
@@ -164,6 +189,222 @@ pub fn prompt_user_for_job_option(job: &Job) -> JobOption {
 
 // pub fn handle_view_edit_connections() {}
 
+#[derive(Display, EnumIter)]
+enum JobFilterOption {
+    #[strum(to_string = "Show Results")]
+    ShowResults,
+    #[strum(to_string = "Keywords")]
+    Keywords,
+    #[strum(to_string = "Title")]
+    Title,
+    #[strum(to_string = "Companies")]
+    Companies,
+    #[strum(to_string = "Location")]
+    Location,
+    #[strum(to_string = "Seniority")]
+    Seniority,
+    #[strum(to_string = "Department")]
+    Department,
+    #[strum(to_string = "Employment Type")]
+    EmploymentType,
+    #[strum(to_string = "Toggle Remote Only")]
+    RemoteOnly,
+    #[strum(to_string = "Posted Within (days)")]
+    PostedWithin,
+    #[strum(to_string = "Toggle Include Closed Postings")]
+    IncludeClosed,
+    #[strum(to_string = "Minimum Salary")]
+    MinimumSalary,
+    #[strum(to_string = "Sort By")]
+    Sort,
+    #[strum(to_string = "Clear Filter")]
+    Clear,
+    #[strum(to_string = "Back")]
+    Back,
+}
+
+// INFO: Build up a JobFilter interactively, returns None when the user backs out
+pub fn prompt_user_for_job_filter(mut filter: JobFilter, data: &Data) -> AppResult<Option<JobFilter>> {
+    let dialoguer_styles = ColorfulTheme::default();
+
+    loop {
+        clear_console();
+        println!("{}", "Current Filter".bold());
+
+        let description = filter.describe();
+        if description.is_empty() {
+            println!("  {}", "No criteria, showing all open jobs".dimmed());
+        }
+        for line in description {
+            println!("  {}", line.cyan());
+        }
+        println!();
+
+        let idx = Select::with_theme(&dialoguer_styles)
+            .with_prompt("Refine the filter or show results")
+            .items(&JobFilterOption::display_strings())
+            .interact()?;
+
+        match JobFilterOption::iter().nth(idx).unwrap() {
+            JobFilterOption::ShowResults => return Ok(Some(filter)),
+            JobFilterOption::Back => return Ok(None),
+            JobFilterOption::Clear => filter = JobFilter::default(),
+            JobFilterOption::Keywords => {
+                let keywords = Input::<String>::with_theme(&dialoguer_styles)
+                    .with_prompt("Keywords (leave empty for any)")
+                    .with_initial_text(filter.keywords.clone().unwrap_or_default())
+                    .allow_empty(true)
+                    .interact_text()?;
+
+                filter.keywords = Some(keywords.trim().to_string()).filter(|k| !k.is_empty());
+            }
+            JobFilterOption::Title => {
+                let title = Input::<String>::with_theme(&dialoguer_styles)
+                    .with_prompt("Words the title has to contain (leave empty for any)")
+                    .with_initial_text(filter.title.clone().unwrap_or_default())
+                    .allow_empty(true)
+                    .interact_text()?;
+
+                filter.title = Some(title.trim().to_string()).filter(|t| !t.is_empty());
+            }
+            JobFilterOption::Companies => {
+                let company_keys = CompanyOption::keys();
+                let defaults: Vec<bool> = company_keys
+                    .iter()
+                    .map(|k| filter.companies.contains(k))
+                    .collect();
+
+                let selected = MultiSelect::with_theme(&dialoguer_styles)
+                    .with_prompt("Select companies (none for all)")
+                    .items(&company_keys)
+                    .defaults(&defaults)
+                    .interact()?;
+
+                filter.companies = selected.into_iter().map(|i| company_keys[i].clone()).collect();
+            }
+            JobFilterOption::Location => {
+                const TYPE_QUERY: &str = "Type a location (e.g. US, US-CA, remote-US)";
+
+                let location_counts = data.location_counts();
+                let mut options = vec!["Any".to_string(), TYPE_QUERY.to_string()];
+                options.extend(
+                    location_counts
+                        .iter()
+                        .map(|(query, count)| format!("{} ({})", query.label(), count)),
+                );
+
+                let idx = FuzzySelect::with_theme(&dialoguer_styles)
+                    .with_prompt("Select a location")
+                    .items(&options)
+                    .interact()?;
+
+                filter.location = match idx {
+                    0 => None,
+                    1 => {
+                        let input = Input::<String>::with_theme(&dialoguer_styles)
+                            .with_prompt("Location")
+                            .validate_with(|input: &String| -> Result<(), String> {
+                                LocationQuery::parse(input).map(|_| ())
+                            })
+                            .interact_text()?;
+
+                        LocationQuery::parse(&input).ok()
+                    }
+                    _ => Some(location_counts[idx - 2].0.clone()),
+                };
+            }
+            JobFilterOption::Seniority => {
+                let levels: Vec<Seniority> = Seniority::iter().collect();
+                let defaults: Vec<bool> = levels.iter().map(|l| filter.seniority.contains(l)).collect();
+
+                let selected = MultiSelect::with_theme(&dialoguer_styles)
+                    .with_prompt("Select seniority levels (none for all)")
+                    .items(&Seniority::display_strings())
+                    .defaults(&defaults)
+                    .interact()?;
+
+                filter.seniority = selected.into_iter().map(|i| levels[i]).collect();
+            }
+            JobFilterOption::Department => {
+                let mut departments: Vec<String> = data
+                    .companies
+                    .values()
+                    .flat_map(|c| c.jobs.iter().filter_map(|j| j.metadata.department.clone()))
+                    .collect::<HashSet<String>>()
+                    .into_iter()
+                    .collect();
+                departments.sort();
+                departments.insert(0, "Any".to_string());
+
+                let idx = FuzzySelect::with_theme(&dialoguer_styles)
+                    .with_prompt("Select a department")
+                    .items(&departments)
+                    .interact()?;
+
+                filter.department = Some(departments[idx].clone()).filter(|d| d != "Any");
+            }
+            JobFilterOption::EmploymentType => {
+                let mut options = vec!["Any".to_string()];
+                options.extend(EmploymentType::display_strings());
+
+                let idx = Select::with_theme(&dialoguer_styles)
+                    .with_prompt("Select an employment type")
+                    .items(&options)
+                    .interact()?;
+
+                filter.employment_type = idx.checked_sub(1).and_then(|i| EmploymentType::iter().nth(i));
+            }
+            JobFilterOption::RemoteOnly => filter.remote_only = !filter.remote_only,
+            JobFilterOption::PostedWithin => {
+                let days = Input::<String>::with_theme(&dialoguer_styles)
+                    .with_prompt("Posted within how many days? (leave empty for any)")
+                    .allow_empty(true)
+                    .validate_with(|input: &String| -> Result<(), &str> {
+                        if input.trim().is_empty() || input.trim().parse::<u32>().is_ok() {
+                            Ok(())
+                        } else {
+                            Err("Enter a whole number of days")
+                        }
+                    })
+                    .interact_text()?;
+
+                filter.posted_within_days = days.trim().parse::<i64>().ok();
+            }
+            JobFilterOption::IncludeClosed => filter.include_closed = !filter.include_closed,
+            JobFilterOption::MinimumSalary => {
+                filter.min_annual_salary = prompt_user_for_annual_salary(filter.min_annual_salary)?;
+            }
+            JobFilterOption::Sort => {
+                let idx = Select::with_theme(&dialoguer_styles)
+                    .with_prompt("Sort jobs by")
+                    .items(&JobSort::display_strings())
+                    .default(JobSort::iter().position(|s| s == filter.sort).unwrap_or(0))
+                    .interact()?;
+
+                filter.sort = JobSort::iter().nth(idx).unwrap();
+            }
+        }
+    }
+}
+
+// INFO: Ask for a yearly pay floor like "150000" or "150k", empty clears it
+fn prompt_user_for_annual_salary(current: Option<u64>) -> AppResult<Option<u64>> {
+    let amount = Input::<String>::with_theme(&ColorfulTheme::default())
+        .with_prompt("Minimum annual salary, e.g. 150k (leave empty for any)")
+        .with_initial_text(current.map(|c| c.to_string()).unwrap_or_default())
+        .allow_empty(true)
+        .validate_with(|input: &String| -> Result<(), &str> {
+            if input.trim().is_empty() || parse_annual_amount(input).is_some() {
+                Ok(())
+            } else {
+                Err("Enter an amount like 150000 or 150k")
+            }
+        })
+        .interact_text()?;
+
+    Ok(parse_annual_amount(&amount))
+}
+
 // INFO: Open Job in Browser
 pub fn handle_open_job_in_browser(job: &Job, data: &mut Data) -> AppResult<()> {
     webbrowser::open(&job.link)?;
@@ -176,11 +417,417 @@ pub fn handle_open_job_in_browser(job: &Job, data: &mut Data) -> AppResult<()> {
 
     if apply {
         data.mark_job_applied(&job.id);
+
+        let set_reminder = Confirm::with_theme(&dialoguer_styles)
+            .with_prompt("Set a follow-up reminder?")
+            .default(false)
+            .interact()?;
+
+        if set_reminder {
+            handle_add_job_reminder(&job.id, data)?;
+        }
+    }
+
+    Ok(())
+}
+
+// INFO: Ask for a reminder message and due date, relative ("7d") or absolute ("2025-01-31")
+fn prompt_user_for_reminder(default_message: &str) -> AppResult<(String, DateTime<Utc>)> {
+    let dialoguer_styles = ColorfulTheme::default();
+
+    let message = Input::<String>::with_theme(&dialoguer_styles)
+        .with_prompt("Reminder")
+        .default(default_message.to_string())
+        .interact_text()?;
+
+    let due = Input::<String>::with_theme(&dialoguer_styles)
+        .with_prompt("Due in (e.g. 3d, 1w, 12h) or on (YYYY-MM-DD)")
+        .default("7d".to_string())
+        .validate_with(|input: &String| parse_due_date(input, Utc::now()).map(|_| ()))
+        .interact_text()?;
+
+    Ok((message, parse_due_date(&due, Utc::now())?))
+}
+
+pub fn handle_add_job_reminder(job_id: &uuid::Uuid, data: &mut Data) -> AppResult<()> {
+    let Some((company, job)) = data.find_job(job_id) else {
+        return Ok(());
+    };
+
+    let target = ReminderTarget::Job {
+        company: company.clone(),
+        job_id: job.id,
+    };
+    let subject = format!("{} @ {}", job.title, company);
+
+    let (message, due_at) = prompt_user_for_reminder("Follow up on application")?;
+
+    data.add_reminder(Reminder::new(target, subject, message, due_at));
+    println!("Reminder set for {}", due_at.format("%Y-%m-%d %H:%M").to_string().green());
+    sleep(Duration::from_secs(1));
+
+    Ok(())
+}
+
+fn print_job_description(job: &Job, description: &JobDescription) {
+    println!("{}", job.title.bold());
+    println!(
+        "{}\n",
+        format!(
+            "Fetched {} · {}",
+            description.fetched_at.format("%Y-%m-%d %H:%M"),
+            job.link
+        )
+        .dimmed()
+    );
+
+    for line in description.markdown.lines() {
+        if line.starts_with('#') {
+            println!("{}", line.trim_start_matches('#').trim().cyan().bold());
+        } else {
+            println!("{}", line);
+        }
+    }
+
+    println!();
+}
+
+async fn fetch_job_description_with_spinner(
+    company: &str,
+    job: &Job,
+) -> AppResult<JobDescription> {
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg}")
+            .unwrap()
+            .tick_strings(&["-", "\\", "|", "/"]),
+    );
+    spinner.set_message(format!("Fetching description for {}...", job.title));
+    spinner.enable_steady_tick(Duration::from_millis(100));
+
+    let description = fetch_and_store_job_description(company, job).await;
+    spinner.finish_and_clear();
+
+    description
+}
+
+pub async fn handle_view_job_description(
+    company: &str,
+    job: &Job,
+    data: &mut Data,
+) -> AppResult<()> {
+    let dialoguer_styles = ColorfulTheme::default();
+    let mut description = JobDescription::load(&job.id);
+
+    loop {
+        let current = match description.take() {
+            Some(description) => description,
+            None => match fetch_job_description_with_spinner(company, job).await {
+                Ok(description) => description,
+                Err(e) => {
+                    eprintln!("{}", format!("Could not fetch the description: {}", e).red());
+                    Input::<String>::with_theme(&dialoguer_styles)
+                        .with_prompt("Press enter to continue")
+                        .allow_empty(true)
+                        .interact_text()?;
+                    return Ok(());
+                }
+            },
+        };
+
+        // INFO: The description may list the pay the board itself didn't
+        data.refresh_job_salary(&job.id, &current);
+
+        clear_console();
+        print_job_description(job, &current);
+
+        let selection = Select::with_theme(&dialoguer_styles)
+            .with_prompt("Description options")
+            .items(&["Refetch", "Back"])
+            .default(1)
+            .interact()?;
+
+        if selection == 1 {
+            return Ok(());
+        }
+    }
+}
+
+pub fn print_agenda_summary(data: &Data) {
+    let agenda = Agenda::build(&data.reminders, Utc::now());
+
+    if agenda.is_empty() {
+        return;
+    }
+
+    println!("{}", "Agenda".bold());
+
+    for r in agenda.overdue.iter() {
+        println!("  {} {}", "OVERDUE".red().bold(), r.display_string());
+    }
+
+    for r in agenda.upcoming.iter() {
+        println!("  {} {}", "UPCOMING".yellow(), r.display_string());
+    }
+
+    println!();
+}
+
+// INFO: Overdue and upcoming reminders across jobs and connections
+pub fn handle_agenda(data: &mut Data) -> AppResult<()> {
+    let dialoguer_styles = ColorfulTheme::default();
+
+    #[derive(EnumIter, Display)]
+    enum ReminderOption {
+        #[strum(to_string = "Mark as Done")]
+        Complete,
+        #[strum(to_string = "Snooze 1 Day")]
+        Snooze,
+        #[strum(to_string = "Reschedule")]
+        Reschedule,
+        #[strum(to_string = "Delete")]
+        Delete,
+        #[strum(to_string = "Back")]
+        Back,
+    }
+
+    loop {
+        clear_console();
+        let agenda = Agenda::build(&data.reminders, Utc::now());
+
+        if agenda.is_empty() {
+            println!(
+                "Nothing due in the next {} days.",
+                reminder::AGENDA_HORIZON_DAYS
+            );
+            sleep(Duration::from_secs(3));
+            return Ok(());
+        }
+
+        let reminders: Vec<&Reminder> = agenda
+            .overdue
+            .iter()
+            .chain(agenda.upcoming.iter())
+            .collect();
+
+        let mut options: Vec<String> = reminders
+            .iter()
+            .map(|r| {
+                if r.is_due(Utc::now()) {
+                    format!("{} {}", "OVERDUE".red().bold(), r.display_string())
+                } else {
+                    format!("{} {}", "UPCOMING".yellow(), r.display_string())
+                }
+            })
+            .collect();
+
+        options.push("Back".to_string());
+
+        let idx = FuzzySelect::with_theme(&dialoguer_styles)
+            .with_prompt("Select a reminder")
+            .items(&options)
+            .interact()?;
+
+        if options[idx] == "Back" {
+            return Ok(());
+        }
+
+        let reminder_id = reminders[idx].id;
+
+        let option_idx = Select::with_theme(&dialoguer_styles)
+            .with_prompt("Select an option")
+            .items(&ReminderOption::display_strings())
+            .interact()?;
+
+        match ReminderOption::iter().nth(option_idx).unwrap() {
+            ReminderOption::Complete => data.complete_reminder(&reminder_id),
+            ReminderOption::Snooze => {
+                data.reschedule_reminder(&reminder_id, Utc::now() + chrono::Duration::days(1))
+            }
+            ReminderOption::Reschedule => {
+                let due = Input::<String>::with_theme(&dialoguer_styles)
+                    .with_prompt("Due in (e.g. 3d, 1w, 12h) or on (YYYY-MM-DD)")
+                    .validate_with(|input: &String| parse_due_date(input, Utc::now()).map(|_| ()))
+                    .interact_text()?;
+
+                data.reschedule_reminder(&reminder_id, parse_due_date(&due, Utc::now())?);
+            }
+            ReminderOption::Delete => data.delete_reminder(&reminder_id),
+            ReminderOption::Back => {}
+        }
+    }
+}
+
+// INFO: Print a job's application timeline and let the user move it to another stage
+pub fn handle_application_status(job: &Job, data: &mut Data) -> AppResult<()> {
+    let dialoguer_styles = ColorfulTheme::default();
+
+    println!("{}", "Application Timeline".bold());
+
+    if job.status_history.is_empty() {
+        match job.application_status() {
+            Some(status) => println!("  {} {} (date unknown)", status.emoji(), status),
+            None => println!("  {}", "No application activity yet".dimmed()),
+        }
+    }
+
+    for change in job.status_history.iter() {
+        println!(
+            "  {} {} {}",
+            change.changed_at.format("%Y-%m-%d %H:%M"),
+            change.status.emoji(),
+            change.status
+        );
+    }
+
+    println!();
+
+    let mut options = ApplicationStatus::display_strings();
+    options.push("Back".to_string());
+
+    let idx = Select::with_theme(&dialoguer_styles)
+        .with_prompt("Move this job to")
+        .items(&options)
+        .interact()?;
+
+    if let Some(status) = ApplicationStatus::iter().nth(idx) {
+        data.set_job_application_status(&job.id, status);
     }
 
     Ok(())
 }
 
+// INFO: Edit a job's free-form notes, tags and custom fields
+pub fn handle_job_annotations(job_id: &uuid::Uuid, data: &mut Data) -> AppResult<()> {
+    let dialoguer_styles = ColorfulTheme::default();
+
+    #[derive(EnumIter, Display)]
+    enum AnnotationOption {
+        #[strum(to_string = "Edit Notes")]
+        EditNotes,
+        #[strum(to_string = "Add Tag")]
+        AddTag,
+        #[strum(to_string = "Remove Tag")]
+        RemoveTag,
+        #[strum(to_string = "Set Custom Field")]
+        SetCustomField,
+        #[strum(to_string = "Remove Custom Field")]
+        RemoveCustomField,
+        #[strum(to_string = "Back")]
+        Back,
+    }
+
+    loop {
+        clear_console();
+
+        let Some(job) = data
+            .companies
+            .values()
+            .flat_map(|c| c.jobs.iter())
+            .find(|j| j.id == *job_id)
+            .cloned()
+        else {
+            return Ok(());
+        };
+
+        println!("{}", job.title.bold());
+        println!(
+            "Tags: {}",
+            if job.tags.is_empty() {
+                "None".dimmed().to_string()
+            } else {
+                job.tags_label().cyan().to_string()
+            }
+        );
+        println!("Custom Fields:");
+        if job.custom_fields.is_empty() {
+            println!("  {}", "None".dimmed());
+        }
+        for (key, value) in job.custom_fields.iter() {
+            println!("  {}: {}", key.bold(), value);
+        }
+        println!("Notes:");
+        if job.notes.trim().is_empty() {
+            println!("  {}", "None".dimmed());
+        }
+        for line in job.notes.lines() {
+            println!("  {}", line);
+        }
+        println!();
+
+        let idx = Select::with_theme(&dialoguer_styles)
+            .with_prompt("Select an option")
+            .items(&AnnotationOption::display_strings())
+            .interact()?;
+
+        match AnnotationOption::iter().nth(idx).unwrap() {
+            AnnotationOption::EditNotes => {
+                if let Some(notes) = Editor::new().edit(&job.notes)? {
+                    data.set_job_notes(&job.id, notes);
+                }
+            }
+            AnnotationOption::AddTag => {
+                let existing_tags = data.all_job_tags();
+
+                if !existing_tags.is_empty() {
+                    println!("Existing tags: {}", existing_tags.join(", ").dimmed());
+                }
+
+                let tag = Input::<String>::with_theme(&dialoguer_styles)
+                    .with_prompt("Enter a tag")
+                    .interact_text()?;
+
+                data.add_job_tag(&job.id, &tag);
+            }
+            AnnotationOption::RemoveTag => {
+                if job.tags.is_empty() {
+                    continue;
+                }
+
+                let tag_idx = Select::with_theme(&dialoguer_styles)
+                    .with_prompt("Select a tag to remove")
+                    .items(&job.tags)
+                    .interact()?;
+
+                data.remove_job_tag(&job.id, &job.tags[tag_idx]);
+            }
+            AnnotationOption::SetCustomField => {
+                let key = Input::<String>::with_theme(&dialoguer_styles)
+                    .with_prompt("Field name (e.g. recruiter)")
+                    .interact_text()?;
+
+                let mut value_input = Input::<String>::with_theme(&dialoguer_styles);
+
+                if let Some(current) = job.custom_fields.get(key.trim()) {
+                    value_input = value_input.with_initial_text(current);
+                }
+
+                let value = value_input
+                    .with_prompt(format!("Value for {}", key.trim()))
+                    .interact_text()?;
+
+                data.set_job_custom_field(&job.id, &key, &value);
+            }
+            AnnotationOption::RemoveCustomField => {
+                let keys: Vec<&String> = job.custom_fields.keys().collect();
+
+                if keys.is_empty() {
+                    continue;
+                }
+
+                let key_idx = Select::with_theme(&dialoguer_styles)
+                    .with_prompt("Select a field to remove")
+                    .items(&keys)
+                    .interact()?;
+
+                data.remove_job_custom_field(&job.id, keys[key_idx]);
+            }
+            AnnotationOption::Back => return Ok(()),
+        }
+    }
+}
+
 // INFO: Craft a Message
 pub fn handle_craft_a_message(job: &Job, connection: &Connection) {
     let dialoguer_styles = ColorfulTheme::default();
@@ -312,7 +959,7 @@ pub fn handle_job_selection(
             .collect::<Vec<&str>>();
 
         let prompt = format!(
-            "Select a job @ {} ({}, 👀: Unseen, ❗: New Listing)",
+            "Select a job @ {} ({}, 👀: Unseen, ❗: New Listing, 🔒: Closed)",
             company_name,
             display_options.len()
         );
@@ -351,6 +998,22 @@ pub fn handle_job_selection(
                 display_string += " 👀".bright_green().bold().to_string().as_str();
             }
 
+            if j.is_closed() {
+                display_string += " 🔒 Closed".red().bold().to_string().as_str();
+            }
+
+            if let Some(status) = j.application_status() {
+                display_string += format!(" {} {}", status.emoji(), status)
+                    .cyan()
+                    .to_string()
+                    .as_str();
+            }
+
+            let annotations = j.annotations_label();
+            if !annotations.is_empty() {
+                display_string += format!(" | {}", annotations).dimmed().to_string().as_str();
+            }
+
             FormattedJob {
                 display_string,
                 original_job: j,
@@ -358,19 +1021,56 @@ pub fn handle_job_selection(
         })
         .collect::<Vec<FormattedJob>>();
 
-    // INFO: Filter jobs down by locations if data set too large
+    // INFO: Filter jobs down by locations if data set too large, grouped by normalized place so
+    // "SF", "San Francisco, CA" and "San Francisco Bay Area" end up in the same bucket
     if jobs.len() > 199 {
-        let mut locations = jobs
-            .iter()
-            .fold(HashSet::new(), |mut hash, job| {
-                hash.insert(&job.location);
+        #[derive(Clone, PartialEq, Eq, Hash)]
+        enum LocationGroup {
+            Place(LocationQuery),
+            // Locations the gazetteer can't place keep their raw text
+            Raw(String),
+        }
 
-                return hash;
-            })
+        fn location_groups(job: &Job) -> HashSet<LocationGroup> {
+            job.all_locations()
+                .into_iter()
+                .flat_map(|raw| {
+                    let groups: Vec<LocationGroup> = normalize_location(&raw)
+                        .iter()
+                        .filter_map(LocationQuery::for_location)
+                        .map(LocationGroup::Place)
+                        .collect();
+
+                    if groups.is_empty() {
+                        vec![LocationGroup::Raw(raw)]
+                    } else {
+                        groups
+                    }
+                })
+                .collect()
+        }
+
+        let mut group_counts: HashMap<LocationGroup, usize> = HashMap::new();
+        for job in jobs.iter() {
+            for group in location_groups(job) {
+                *group_counts.entry(group).or_default() += 1;
+            }
+        }
+
+        let mut groups: Vec<(String, LocationGroup)> = group_counts
             .into_iter()
-            .cloned()
-            .collect::<Vec<String>>();
+            .map(|(group, count)| {
+                let label = match &group {
+                    LocationGroup::Place(query) => query.label(),
+                    LocationGroup::Raw(raw) => raw.clone(),
+                };
+
+                (format!("{} ({})", label, count), group)
+            })
+            .collect();
+        groups.sort_by(|(a, _), (b, _)| a.cmp(b));
 
+        let mut locations: Vec<String> = groups.iter().map(|(label, _)| label.clone()).collect();
         locations.push("Back".to_string());
 
         let dialoguer_styles = ColorfulTheme::default();
@@ -382,15 +1082,15 @@ pub fn handle_job_selection(
                 .interact()
                 .unwrap();
 
-            let selected_location = &locations[selection];
-
-            if selected_location == "Back" {
+            if locations[selection] == "Back" {
                 return None;
             }
 
+            let selected_group = &groups[selection].1;
+
             let filtered_jobs_by_location: Vec<FormattedJob> = formatted_options
                 .iter()
-                .filter(|j| j.original_job.location == *selected_location)
+                .filter(|j| location_groups(j.original_job).contains(selected_group))
                 .cloned()
                 .collect();
 
@@ -409,123 +1109,27 @@ pub fn handle_job_selection(
     }
 }
 
+#[derive(Clone)]
 pub struct FormattedJob {
     pub company: String,
     pub display_name: String,
     pub job: Job,
 }
-pub async fn handle_scan_new_jobs_across_network_and_followed_companies(
-    data: &mut Data,
-) -> AppResult<Vec<FormattedJob>> {
-    clear_console();
-    let companies_to_scrape: Vec<CompanyOption> = CompanyOption::iter()
-        .filter(|c| {
-            let company_key = c.to_string();
-            let company = data.companies.get(&company_key).unwrap();
-            if !company.connections.is_empty() || company.is_following {
-                return true;
-            } else {
-                return false;
-            }
-        })
-        .collect();
-
-    if companies_to_scrape.is_empty() {
-        return Err("Looks like you’re not connected with any companies yet or following any. Start building your network by adding connections or following companies you’re interested in!".into());
-    }
-
-    let pb = ProgressBar::new(companies_to_scrape.len() as u64);
-    pb.set_style(
-    ProgressStyle::default_bar()
-        .template("{spinner:.green} [{elapsed_precise}] {bar:60.cyan/blue} {pos}/{len} ({percent}%) {msg}")
-        .unwrap()
-        .progress_chars("=>-"),
-);
-
-    let mut new_jobs_based_on_smart_criteria: Vec<FormattedJob> = vec![];
-    let mut all_new_jobs: Vec<FormattedJob> = vec![];
-
-    // Enable steady ticks for animation
-    pb.enable_steady_tick(Duration::from_millis(100));
-
-    for company_option in companies_to_scrape {
-        clear_console();
-
-        let company_key = company_option.to_string();
-
-        // Set a message to show current activity
-        pb.set_message(format!("Scraping {}", company_key));
-
-        // Start timing the operation
-        let start = Instant::now();
-
-        // Perform the scraping
-        let jobs_payload = match company_option.scrape_jobs(data).await {
-            Ok(jb) => jb,
-            Err(e) => {
-                eprintln!("{}", format!("Error: {}", e).red());
-                continue;
-            }
-        };
-
-        // Update progress and message
-        pb.inc(1);
-
-        if jobs_payload.are_new_jobs {
-            let new_jobs_count = jobs_payload.new_jobs.len();
-            pb.println(format!(
-                "✨ Found {} new jobs for {}!",
-                new_jobs_count, company_key
-            ));
-
-            all_new_jobs.extend(jobs_payload.new_jobs.iter().map(|j| FormattedJob {
-                display_name: format!("{} | {} | ({})", j.title, j.location, company_key),
-                job: j.clone(),
-                company: company_key.clone(),
-            }));
-
-            if data.smart_criteria_enabled {
-                pb.println("🧠 Filtering jobs based on smart criteria");
-                let openai_client = OpenAIClient::new();
-
-                let filtered_jobs = openai_client
-                    .filter_jobs_based_on_smart_criteria(&jobs_payload.new_jobs)
-                    .await?;
-
-                let formatted_jobs = filtered_jobs
-                    .iter()
-                    .map(|j| FormattedJob {
-                        display_name: format!("{} | {} | ({})", j.title, j.location, company_key),
-                        job: j.clone(),
-                        company: company_key.clone(),
-                    })
-                    .collect::<Vec<FormattedJob>>();
+pub fn print_saved_search_matches(matches: &[SavedSearchMatches]) {
+    for m in matches {
+        println!(
+            "\n🔎 {} ({} new)",
+            m.search.name.bold(),
+            m.jobs.len().to_string().green()
+        );
 
-                new_jobs_based_on_smart_criteria.extend(formatted_jobs);
-            }
+        for (company, job) in m.jobs.iter() {
+            println!("  {} | {} | ({})", job.title, job.location, company);
         }
-
-        // Optional: Show time taken for each company
-        let elapsed = start.elapsed();
-        pb.set_message(format!("Done in {:.2}s", elapsed.as_secs_f64()));
-    }
-
-    // Finish the progress bar
-    pb.finish_with_message("Scraping completed!");
-
-    // If no new jobs found, return an error
-    if all_new_jobs.is_empty() {
-        return Err("No new jobs found across your network and followed companies".into());
     }
 
-    // Create a new jobs report based on all new jobs
-    create_report(&all_new_jobs, ReportMode::HTML)?;
-
-    // Return the new jobs based on smart criteria if smart criteria is enabled
-    if data.smart_criteria_enabled {
-        Ok(new_jobs_based_on_smart_criteria)
-    } else {
-        Ok(all_new_jobs)
+    if !matches.is_empty() {
+        println!();
     }
 }
 
@@ -535,14 +1139,30 @@ pub enum MainMenuOption {
     SelectACompany,
     #[strum(to_string = "Scan for New Jobs Across Network and Followed Companies")]
     ScanForNewJobsAcrossNetworkAndFollowedCompanies,
+    #[strum(to_string = "Browse & Filter Jobs")]
+    BrowseJobs,
+    #[strum(to_string = "Search Jobs")]
+    SearchJobs,
+    #[strum(to_string = "Saved Searches")]
+    SavedSearches,
     #[strum(to_string = "View Bookmarked Jobs")]
     ViewBookmarkedJobs,
+    #[strum(to_string = "Application Pipeline")]
+    ApplicationPipeline,
+    #[strum(to_string = "Agenda")]
+    Agenda,
     #[strum(to_string = "My Connections")]
     MyConnections,
     #[strum(to_string = "View New Jobs Reports")]
     ViewNewJobsReports,
+    #[strum(to_string = "Job Market Stats")]
+    JobMarketStats,
+    #[strum(to_string = "Scan History")]
+    ScanHistory,
     #[strum(to_string = "Manage Smart Criteria")]
     ManageSmartCriteria,
+    #[strum(to_string = "Switch Profile")]
+    SwitchProfile,
     #[strum(to_string = "Exit")]
     Exit,
 }
@@ -569,6 +1189,8 @@ pub async fn handle_manage_connection(
     enum ManageConnectionOption {
         #[strum(to_string = "Open LinkedIn")]
         OpenLinkedIn,
+        #[strum(to_string = "Add Follow-up Reminder")]
+        AddReminder,
         #[strum(to_string = "Delete")]
         Delete,
         #[strum(to_string = "Back")]
@@ -592,6 +1214,22 @@ pub async fn handle_manage_connection(
                     println!("Connection does not have a LinkedIn URL set");
                 }
             }
+            ManageConnectionOption::AddReminder => {
+                let target = ReminderTarget::Connection {
+                    company: company_name.to_string(),
+                    first_name: connection.first_name.clone(),
+                    last_name: connection.last_name.clone(),
+                };
+                let subject = format!(
+                    "{} {} ({})",
+                    connection.first_name, connection.last_name, company_name
+                );
+
+                let (message, due_at) = prompt_user_for_reminder("Follow up on message")?;
+
+                data.add_reminder(Reminder::new(target, subject, message, due_at));
+                println!("Reminder set for {}", due_at.format("%Y-%m-%d %H:%M").to_string().green());
+            }
             ManageConnectionOption::Back => {
                 break;
             }
@@ -604,13 +1242,11 @@ pub async fn handle_manage_connection(
                     .interact()?;
 
                 if confirm {
-                    // Filter out the connection from all companies
-                    if let Some(company) = data.companies.get_mut(company_name) {
-                        company.connections.retain(|c| {
-                            c.first_name != connection.first_name
-                                || c.last_name != connection.last_name
-                        });
-                        data.save();
+                    if data.remove_connection(
+                        company_name,
+                        &connection.first_name,
+                        &connection.last_name,
+                    ) {
                         println!("Connection deleted successfully!");
                     } else {
                         println!("Something went wrong")
@@ -633,6 +1269,9 @@ pub enum ManageSmartCriteriaOptions {
     #[strum(to_string = "Enable Smart Criteria [ ]")]
     EnableSmartCriteria,
 
+    #[strum(to_string = "Set Minimum Salary")]
+    SetMinimumSalary,
+
     #[strum(to_string = "Back")]
     Back,
 }
@@ -689,6 +1328,9 @@ pub fn handle_manage_smart_criteria() {
                 data.smart_criteria.green()
             }
         );
+        if let Some(min_salary) = data.smart_criteria_min_salary {
+            println!("Minimum Salary: {}", format!("{} a year", min_salary).green());
+        }
         println!();
         let manage_smart_criteria_selection = prompt_user_for_manage_smart_criteria_selection();
 
@@ -713,11 +1355,108 @@ pub fn handle_manage_smart_criteria() {
                 data.toggle_smart_criteria_enabled();
             }
 
+            ManageSmartCriteriaOptions::SetMinimumSalary => {
+                let min_salary =
+                    prompt_user_for_annual_salary(data.smart_criteria_min_salary).unwrap();
+
+                data.set_smart_criteria_min_salary(min_salary);
+            }
+
             _ => break,
         }
     }
 }
 
+// INFO: Saved Search Option Prompt
+#[derive(Display, EnumIter)]
+pub enum SavedSearchOption {
+    #[strum(to_string = "View Matching Jobs")]
+    ViewMatchingJobs,
+    #[strum(to_string = "Edit Filter")]
+    EditFilter,
+    #[strum(to_string = "Rename")]
+    Rename,
+    #[strum(to_string = "Set Discord Webhook")]
+    SetDiscordWebhook,
+    #[strum(to_string = "Delete")]
+    Delete,
+    #[strum(to_string = "Back")]
+    Back,
+}
+
+pub fn prompt_user_for_saved_search_option(name: &str) -> AppResult<SavedSearchOption> {
+    let idx = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt(format!("Select an option for {}", name))
+        .items(&SavedSearchOption::display_strings())
+        .interact()?;
+
+    Ok(SavedSearchOption::iter().nth(idx).unwrap())
+}
+
+// INFO: An empty answer clears the webhook
+pub fn prompt_user_for_discord_webhook(current: Option<String>) -> AppResult<Option<String>> {
+    let webhook_url = Input::<String>::with_theme(&ColorfulTheme::default())
+        .with_prompt("Discord webhook URL for this search's matches (leave empty for none)")
+        .with_initial_text(current.unwrap_or_default())
+        .allow_empty(true)
+        .validate_with(|input: &String| -> Result<(), &str> {
+            if input.is_empty() || input.starts_with("https://discord.com/api/webhooks/") {
+                Ok(())
+            } else {
+                Err("Webhook URL must be a valid Discord webhook URL")
+            }
+        })
+        .interact_text()?;
+
+    Ok(Some(webhook_url.trim().to_string()).filter(|w| !w.is_empty()))
+}
+
+pub fn handle_switch_profile() -> AppResult<()> {
+    let dialoguer_styles = ColorfulTheme::default();
+    let active_profile = profiles::active_profile();
+
+    let mut options: Vec<String> = profiles::list_profiles()
+        .into_iter()
+        .map(|p| {
+            if p == active_profile {
+                format!("{} (active)", p)
+            } else {
+                p
+            }
+        })
+        .collect();
+
+    options.push("Create New Profile".to_string());
+    options.push("Back".to_string());
+
+    let idx = Select::with_theme(&dialoguer_styles)
+        .with_prompt("Select a profile")
+        .items(&options)
+        .interact()?;
+
+    let selected_profile = match options[idx].as_str() {
+        "Back" => return Ok(()),
+        "Create New Profile" => Input::<String>::with_theme(&dialoguer_styles)
+            .with_prompt("Enter a profile name")
+            .validate_with(|input: &String| profiles::validate_profile_name(input))
+            .interact_text()?,
+        profile => profile.trim_end_matches(" (active)").to_string(),
+    };
+
+    profiles::set_active_profile(&selected_profile)?;
+    if let Err(e) = logging::follow_profile() {
+        eprintln!("{}", format!("Could not move the log file to the new profile: {}", e).red());
+    }
+
+    // INFO: Loading the data creates the new profile's data store when it doesn't exist yet
+    Data::get_data();
+
+    println!("Switched to profile {}", selected_profile.green());
+    sleep(Duration::from_secs(1));
+
+    Ok(())
+}
+
 pub fn handle_view_new_jobs_reports() -> AppResult<()> {
     let v = Data::get_new_jobs_report_files();
     let data_path = Data::get_data_dir();
@@ -0,0 +1,168 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, IsTerminal, Write},
+    path::Path,
+    sync::Mutex,
+};
+
+use tracing::level_filters::LevelFilter;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::{
+    fmt::writer::MakeWriterExt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter,
+};
+
+use crate::{config, error::AppResult, models::data::Data};
+
+// INFO: Set by `init` when file logging is on, swapped by `follow_profile`
+static LOG_FILE: Mutex<Option<RollingFileAppender>> = Mutex::new(None);
+
+/// Where log lines go besides the JSON log file
+pub enum LogOutput {
+    /// The menu, TUI and commands, nothing is printed over them
+    FileOnly,
+    /// Discord mode and the daemon, warnings and errors to stderr and the rest to stdout
+    Console,
+}
+
+/// Sets up the global subscriber from the `[logging]` config, call once the profile is known
+///
+/// JSON lines go to `logs/jobshell.log.<date>` in the active profile's data dir, a new file each day
+/// and the last `logging.keep_days` of them kept. `log_file` also gets plain lines, see `daemon --log-file`.
+pub fn init(output: LogOutput, log_file: Option<&Path>) -> AppResult<()> {
+    let config = &config::get().logging;
+
+    let json_file = if config.file {
+        *LOG_FILE.lock().unwrap() = Some(profile_log_file()?);
+
+        Some(
+            tracing_subscriber::fmt::layer()
+                .json()
+                .with_current_span(true)
+                .with_span_list(true)
+                .with_writer(|| ProfileLogFile),
+        )
+    } else {
+        None
+    };
+
+    let console = match output {
+        LogOutput::FileOnly => None,
+        LogOutput::Console => Some(
+            tracing_subscriber::fmt::layer()
+                .with_target(false)
+                // INFO: No color codes in what systemd, Docker or a redirect captures
+                .with_ansi(std::io::stdout().is_terminal())
+                .with_writer(
+                    std::io::stderr
+                        .with_max_level(tracing::Level::WARN)
+                        .or_else(std::io::stdout),
+                ),
+        ),
+    };
+
+    let plain_file = match log_file {
+        Some(path) => {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .map_err(|e| format!("Could not open {}: {}", path.display(), e))?;
+
+            Some(
+                tracing_subscriber::fmt::layer()
+                    .with_target(false)
+                    .with_ansi(false)
+                    .with_writer(Mutex::new(file)),
+            )
+        }
+        None => None,
+    };
+
+    tracing_subscriber::registry()
+        .with(filter(&config.level))
+        .with(json_file)
+        .with(console)
+        .with(plain_file)
+        .try_init()
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Moves the JSON log file to the active profile's data dir, call after switching profiles
+pub fn follow_profile() -> AppResult<()> {
+    if !config::get().logging.file {
+        return Ok(());
+    }
+
+    let log_file = profile_log_file()?;
+    *LOG_FILE.lock().unwrap() = Some(log_file);
+
+    Ok(())
+}
+
+fn profile_log_file() -> AppResult<RollingFileAppender> {
+    let config = &config::get().logging;
+    let logs_dir = Data::get_data_dir().join("logs");
+    fs::create_dir_all(&logs_dir)?;
+
+    let mut appender = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix("jobshell.log");
+    if config.keep_days > 0 {
+        appender = appender.max_log_files(config.keep_days);
+    }
+
+    Ok(appender.build(logs_dir)?)
+}
+
+/// Writes to the log file of whichever profile is active, see `follow_profile`
+struct ProfileLogFile;
+
+impl Write for ProfileLogFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match LOG_FILE.lock().unwrap().as_mut() {
+            Some(file) => file.write(buf),
+            None => Ok(buf.len()),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match LOG_FILE.lock().unwrap().as_mut() {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A bare level only applies to JobShell, dependencies stay at warn so their chatter stays out
+fn directives(level: &str) -> String {
+    match level.parse::<LevelFilter>() {
+        Ok(level) => format!("warn,jobshell={}", level),
+        Err(_) => level.to_string(),
+    }
+}
+
+fn filter(level: &str) -> EnvFilter {
+    // INFO: `config validate` reports a bad level, logging shouldn't stop JobShell from starting
+    EnvFilter::try_new(directives(level)).unwrap_or_else(|_| EnvFilter::new(directives("info")))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_directives() {
+        assert_eq!(directives("debug"), "warn,jobshell=debug");
+        assert_eq!(directives("WARN"), "warn,jobshell=warn");
+        assert_eq!(
+            directives("info,jobshell::scrapers=trace"),
+            "info,jobshell::scrapers=trace"
+        );
+        assert_eq!(
+            filter("jobshell=loud").max_level_hint(),
+            Some(LevelFilter::INFO)
+        );
+    }
+}
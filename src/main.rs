@@ -1,9 +1,15 @@
 mod args;
+mod background_scan;
 mod company_options;
+mod completions;
+mod config;
+mod daemon;
 mod discord;
 mod error;
 mod handlers;
+mod logging;
 mod modes;
+mod profiles;
 mod reports;
 mod scrapers;
 mod utils;
@@ -11,24 +17,69 @@ mod models {
     pub mod ai;
     pub mod custom_error;
     pub mod data;
+    pub mod description;
+    pub mod diff;
+    pub mod filter;
+    pub mod identity;
+    pub mod location;
+    pub mod metadata;
+    pub mod pipeline;
+    pub mod reminder;
+    pub mod run;
+    pub mod salary;
+    pub mod saved_search;
+    pub mod search;
+    pub mod seniority;
     pub mod gemini;
     pub mod scraper;
 }
 
-use args::Args;
+use args::{Args, Command};
 use clap::Parser;
 use dotenv::dotenv;
 use error::AppResult;
 use jobshell::utils::clear_console;
+use logging::LogOutput;
 
 #[tokio::main]
 async fn main() -> AppResult<()> {
-    clear_console();
+    completions::complete();
+
     dotenv().ok();
     let args = Args::parse();
 
+    let config_loaded = config::init(args.config.clone(), args.set.clone());
+    // INFO: `config` commands still run with a broken config, they are how it gets fixed
+    if !matches!(
+        args.command,
+        Some(Command::Config(_) | Command::Completions { .. } | Command::Man { .. })
+    ) {
+        config_loaded?;
+    }
+
+    profiles::set_active_profile(&args.profile)?;
+
+    let (log_output, log_file) = match &args.command {
+        Some(Command::Daemon(daemon_args)) => (LogOutput::Console, daemon_args.log_file.clone()),
+        _ if args.discord => (LogOutput::Console, None),
+        _ => (LogOutput::FileOnly, None),
+    };
+    logging::init(log_output, log_file.as_deref())?;
+
+    if args.fetch_descriptions {
+        scrapers::job_details::enable_background_fetch();
+    }
+
+    if let Some(command) = args.command {
+        return modes::commands::run(command, args.format).await;
+    }
+
+    clear_console();
+
     if args.discord {
         modes::discord::run().await?;
+    } else if args.tui {
+        modes::tui::run().await?;
     } else {
         modes::cli::run().await?;
     }
@@ -2,8 +2,11 @@ use std::{error::Error, str::FromStr};
 
 use reqwest::Client;
 use serde_json::{json, Value};
+use tracing::{debug, info};
 use uuid::Uuid;
 
+use crate::config;
+
 use super::{data::Data, scraper::Job};
 
 pub trait AiModel {
@@ -23,11 +26,15 @@ pub struct OpenAIClient {
 
 impl AiModel for OpenAIClient {
     fn new() -> Self {
-        let token = std::env::var("OPENAI_KEY").expect("OPENAI_KEY must be set");
+        let token = config::get()
+            .ai
+            .openai_key
+            .clone()
+            .expect("ai.openai_key (or OPENAI_KEY) must be set");
 
         Self {
             token,
-            client: Client::new(),
+            client: config::http_client(),
         }
     }
 
@@ -37,13 +44,15 @@ impl AiModel for OpenAIClient {
         user_prompt: &str,
         response_format: Value,
     ) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        debug!(model = %config::get().ai.openai_model, "Requesting an OpenAI completion");
+
         let json: Value = self
             .client
             .post("https://api.openai.com/v1/chat/completions")
             .bearer_auth(&self.token)
             .json(&json!(
                 {
-                  "model": "gpt-4o",
+                  "model": config::get().ai.openai_model,
                   "messages": [
                     {
                       "role": "system",
@@ -73,14 +82,32 @@ impl OpenAIClient {
         &self,
         jobs: &Vec<Job>,
     ) -> Result<Vec<Job>, Box<dyn Error + Send + Sync>> {
-        let Data { smart_criteria, .. } = Data::get_data();
+        let Data {
+            smart_criteria,
+            smart_criteria_min_salary,
+            ..
+        } = Data::get_data();
+
+        // INFO: The salary floor is a hard rule, no need to spend tokens on jobs that pay too little
+        let jobs: Vec<&Job> = jobs
+            .iter()
+            .filter(|j| match (&j.salary, smart_criteria_min_salary) {
+                (Some(salary), Some(min_salary)) => salary.annual_max() >= min_salary,
+                _ => true,
+            })
+            .collect();
+
+        if jobs.is_empty() {
+            return Ok(Vec::new());
+        }
+
         let system_prompt = r#"
         You are a JSON processing assistant. Your job is to read a single string of text instructions—called "criteria"—and use it to filter an array of jobs, returning only those that match the user's criteria. Follow these instructions carefully:
 
 1. **Input Format**:
    - You will receive an object with two properties:  
      - `criteria`: A string describing which jobs the user wants.  
-     - `jobs`: An array of job objects, each containing properties like `id`, `title` and `location`.
+     - `jobs`: An array of job objects, each containing properties like `id`, `title` and `location`, plus `salary`, `annual_salary_min` and `annual_salary_max` when the posting lists pay (otherwise `null`).
 
 2. **Interpretation & Filtering**:
    - Parse the text in `criteria` to understand what the user is looking for (e.g., desired job titles, locations, keywords, etc.).
@@ -88,6 +115,7 @@ impl OpenAIClient {
      - For instance, if `criteria` is:  
        `"I am interested in any Software Engineer jobs Remote, US or based in any city in Southern California."`  
        You should keep jobs with a `title` containing "Software Engineer" and a `location` that suggests remote or a location in the US or southern California.
+   - If the criteria mention compensation, compare it against `annual_salary_max`. Do not drop a job only because its `salary` is `null`.
 
 3. **Output Format**:
    - Return the filtered jobs as a **raw JSON array** of the matching job ID strings.
@@ -117,7 +145,10 @@ impl OpenAIClient {
                 json!({
                     "id": j.id,
                     "title": j.title,
-                    "location": j.location
+                    "location": j.location,
+                    "salary": j.salary.as_ref().map(|s| s.label()),
+                    "annual_salary_min": j.salary.as_ref().map(|s| s.annual_min()),
+                    "annual_salary_max": j.salary.as_ref().map(|s| s.annual_max())
                 })
             })
             .collect::<Vec<Value>>();
@@ -172,9 +203,15 @@ impl OpenAIClient {
         let filtered_jobs: Vec<Job> = jobs
             .iter()
             .filter(|j| job_ids.contains(&j.id))
-            .cloned()
+            .map(|j| (*j).clone())
             .collect();
 
+        info!(
+            jobs = jobs.len(),
+            matched = filtered_jobs.len(),
+            "Filtered jobs with smart criteria"
+        );
+
         Ok(filtered_jobs)
     }
 }
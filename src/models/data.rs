@@ -5,18 +5,30 @@ use std::{
     path::PathBuf,
 };
 
+use chrono::{DateTime, Utc};
 use dialoguer::{theme::ColorfulTheme, Confirm, Input};
 use directories::ProjectDirs;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use strum::IntoEnumIterator;
 use tabled::Tabled;
+use tracing::{debug, error, warn};
+
+use crate::{company_options::CompanyOption, error::AppResult, profiles};
+
+use super::{
+    description::JobDescription,
+    location::{LocationQuery, WorkArrangement},
+    pipeline::ApplicationStatus,
+    reminder::{Reminder, ReminderTarget},
+    saved_search::SavedSearch,
+    scraper::Job,
+    search::{with_search_index, SearchFlag, SearchQuery},
+    seniority::Seniority,
+};
 
-use crate::{company_options::CompanyOption, error::AppResult};
-
-use super::scraper::Job;
-
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
 pub struct Company {
     pub connections: Vec<Connection>,
     pub jobs: Vec<Job>,
@@ -33,7 +45,7 @@ impl Company {
     }
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Tabled, Clone)]
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Tabled, Clone)]
 pub struct Connection {
     pub first_name: String,
     pub last_name: String,
@@ -116,111 +128,81 @@ fn display_option(opt: &Option<String>) -> &str {
     }
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Data {
     pub companies: HashMap<String, Company>,
     pub smart_criteria: String,
     pub smart_criteria_enabled: bool,
+    // INFO: Annual pay floor applied before the AI sees the jobs, jobs that list no salary still pass
+    #[serde(default)]
+    pub smart_criteria_min_salary: Option<u64>,
+    // INFO: Remembered per profile so each profile posts to its own Discord channel
+    #[serde(default)]
+    pub discord_webhook_url: Option<String>,
+    #[serde(default)]
+    pub reminders: Vec<Reminder>,
+    #[serde(default)]
+    pub saved_searches: Vec<SavedSearch>,
+    // INFO: Set on the copies background scans scrape into, saving them does nothing
+    #[serde(skip)]
+    pub detached: bool,
 }
 
+/// How many open jobs there are per seniority level, every job is counted exactly once
 #[derive(Debug)]
 pub struct JobCounts {
-    intern: i32,
-    junior: i32,
-    mid: i32,
-    senior: i32,
-    staff: i32,
-    principal: i32,
-    unidentified: i32,
+    pub by_seniority: Vec<(Seniority, usize)>,
+    pub unidentified: usize,
+    pub total: usize,
 }
 
 impl JobCounts {
-    pub fn export_csv(&self) -> Result<(), Box<dyn Error>> {
-        let csv_string = format!("Title,Quantity\nIntern,{}\nJunior,{}\nMid,{}\nSenior,{}\nStaff,{}\nPrincipal,{}\nUnidentified,{}", self.intern, self.junior, self.mid, self.senior, self.staff, self.principal, self.unidentified);
+    pub fn export_csv(&self) -> AppResult<()> {
+        let mut csv_string = String::from("Title,Quantity\n");
+
+        for (seniority, count) in self.by_seniority.iter() {
+            csv_string += &format!("{},{}\n", seniority, count);
+        }
+        csv_string += &format!("Unidentified,{}", self.unidentified);
+
         fs::write("./job_data.csv", csv_string)?;
 
         Ok(())
     }
 }
 pub trait AnalyzeData {
-    fn get_job_counts(&self) -> JobCounts;
+    fn get_job_counts(&self, companies: &[String]) -> JobCounts;
 }
 
 impl AnalyzeData for Data {
-    fn get_job_counts(&self) -> JobCounts {
-        let intern_tokens: HashSet<&str> = HashSet::from_iter(vec!["intern", "internship"]);
-        let junior_tokens: HashSet<&str> = HashSet::from_iter(vec![
-            "junior",
-            "i",
-            "new grad",
-            "new graduate",
-            "newgrad",
-            "entry",
-            "jr",
-            "jr.",
-            "entry",
-        ]);
-
-        let mid_tokens: HashSet<&str> = HashSet::from_iter(vec!["mid", "ii"]);
-        let senior_tokens: HashSet<&str> = HashSet::from_iter(vec!["senior", "iii", "sr", "sr."]);
-        let staff_tokens: HashSet<&str> = HashSet::from_iter(vec!["staff", "iv"]);
-        let principal_tokens: HashSet<&str> = HashSet::from_iter(vec!["principal"]);
-        let mut intern = 0;
-        let mut junior = 0;
-        let mut senior = 0;
-        let mut staff = 0;
-        let mut mid = 0;
-        let mut principal = 0;
+    // INFO: Counts open jobs of the given companies, or of every company when none are given
+    fn get_job_counts(&self, companies: &[String]) -> JobCounts {
+        let mut counts: HashMap<Seniority, usize> = HashMap::new();
         let mut unidentified = 0;
-        for (_, c) in self.companies.iter() {
-            'job_loop: for j in c.jobs.iter() {
-                let normalized_title = j.title.to_lowercase();
-
-                let tokens = normalized_title.split_whitespace().collect::<Vec<&str>>();
-
-                for token in tokens.iter() {
-                    if intern_tokens.contains(token) {
-                        intern += 1;
-                        continue 'job_loop;
-                    }
-                    if junior_tokens.contains(token) {
-                        junior += 1;
-                        continue 'job_loop;
-                    }
-
-                    if mid_tokens.contains(token) {
-                        mid += 1;
-                        continue 'job_loop;
-                    }
-                    if senior_tokens.contains(token) {
-                        senior += 1;
-                        continue 'job_loop;
-                    }
-
-                    if staff_tokens.contains(token) {
-                        staff += 1;
-                        continue 'job_loop;
-                    }
+        let mut total = 0;
 
-                    if principal_tokens.contains(token) {
-                        principal += 1;
-                        continue 'job_loop;
-                    }
+        for job in self
+            .companies
+            .iter()
+            .filter(|(name, _)| companies.is_empty() || companies.contains(name))
+            .flat_map(|(_, c)| c.jobs.iter())
+            .filter(|j| !j.is_closed())
+        {
+            total += 1;
 
-                    unidentified += 1;
-                }
+            match job.seniority {
+                Some(seniority) => *counts.entry(seniority).or_default() += 1,
+                None => unidentified += 1,
             }
         }
 
-        return JobCounts {
-            intern,
-            mid,
-            staff,
-            senior,
-            junior,
-            principal,
+        JobCounts {
+            by_seniority: Seniority::iter()
+                .map(|s| (s, counts.get(&s).copied().unwrap_or_default()))
+                .collect(),
             unidentified,
-        };
+            total,
+        }
     }
 }
 
@@ -233,26 +215,45 @@ impl Data {
             companies: HashMap::from_iter(companies),
             smart_criteria: "".to_string(),
             smart_criteria_enabled: false,
+            smart_criteria_min_salary: None,
+            discord_webhook_url: None,
+            reminders: Vec::new(),
+            saved_searches: Vec::new(),
+            detached: false,
         }
     }
     pub fn save(&self) {
+        if self.detached {
+            return;
+        }
+
         let data = json!({
             "companies": self.companies,
             "smart_criteria": self.smart_criteria,
-            "smart_criteria_enabled": self.smart_criteria_enabled
+            "smart_criteria_enabled": self.smart_criteria_enabled,
+            "smart_criteria_min_salary": self.smart_criteria_min_salary,
+            "discord_webhook_url": self.discord_webhook_url,
+            "reminders": self.reminders,
+            "saved_searches": self.saved_searches
         });
 
         let data_file_path = Self::get_data_dir().join("data.json");
-        fs::write(data_file_path, serde_json::to_string_pretty(&data).unwrap())
+        fs::write(&data_file_path, serde_json::to_string_pretty(&data).unwrap())
             .expect("Error writing to data.json");
+        debug!(path = %data_file_path.display(), "Saved data");
     }
 
-    pub fn get_data_dir() -> PathBuf {
+    pub fn get_base_data_dir() -> PathBuf {
         let project_dir = ProjectDirs::from("org", "jobshell", "jobshell")
             .expect("Problem configuring the project directory");
 
         project_dir.data_dir().to_path_buf()
     }
+
+    /// The data dir of the active profile
+    pub fn get_data_dir() -> PathBuf {
+        profiles::profile_dir(&Self::get_base_data_dir(), &profiles::active_profile())
+    }
     fn process_data() -> Result<Data, Box<dyn Error>> {
         let data_dir = Data::get_data_dir();
 
@@ -295,7 +296,15 @@ impl Data {
         }
 
         // Convert to your Data type
-        let data: Data = serde_json::from_value(json_value)?;
+        let mut data: Data = serde_json::from_value(json_value)?;
+
+        // INFO: Jobs saved before seniority was classified get it on load, persisted on the next save
+        for job in data.companies.values_mut().flat_map(|c| c.jobs.iter_mut()) {
+            if job.seniority.is_none() {
+                job.refresh_seniority();
+            }
+        }
+
         Ok(data)
     }
     pub fn get_data() -> Data {
@@ -304,9 +313,29 @@ impl Data {
         match Self::process_data() {
             Ok(data) => data,
             Err(e) => {
-                eprintln!("{}", e);
+                let mut default = default_data;
+                let data_file = Self::get_data_dir().join("data.json");
+
+                // INFO: No file is a first run, anything else is kept before defaults are saved over it
+                if data_file.exists() {
+                    error!(error = %e, path = %data_file.display(), "Could not load data.json, starting from defaults");
+
+                    let backup = data_file.with_file_name(format!(
+                        "data.json.corrupt-{}",
+                        Utc::now().format("%Y%m%d%H%M%S")
+                    ));
+
+                    match fs::copy(&data_file, &backup) {
+                        Ok(_) => warn!(backup = %backup.display(), "Kept a copy of the data.json that could not be loaded"),
+                        Err(copy_error) => {
+                            warn!(error = %copy_error, "Could not back up data.json, nothing will be saved over it");
+                            default.detached = true;
+                        }
+                    }
+                } else {
+                    debug!(path = %data_file.display(), "No data.json yet, starting from defaults");
+                }
 
-                let default = default_data;
                 default.save();
                 default
             }
@@ -331,24 +360,89 @@ impl Data {
         }
     }
 
-    pub fn mark_job_applied(&mut self, id: &uuid::Uuid) {
+    /// Returns whether the job moved to Applied
+    pub fn mark_job_applied(&mut self, id: &uuid::Uuid) -> bool {
+        self.update_job(id, |j| {
+            // INFO: Don't move a job that is already further down the pipeline back to Applied
+            matches!(
+                j.application_status(),
+                None | Some(ApplicationStatus::Interested)
+            ) && j.set_application_status(ApplicationStatus::Applied, Utc::now())
+        })
+    }
+
+    pub fn set_job_application_status(&mut self, id: &uuid::Uuid, status: ApplicationStatus) {
         if self
             .companies
             .iter_mut()
             .flat_map(|(_, c)| &mut c.jobs)
-            .any(|j| {
-                if j.id == *id {
-                    j.applied = true;
-                    true
-                } else {
-                    false
-                }
-            })
+            .any(|j| j.id == *id && j.set_application_status(status, Utc::now()))
         {
             self.save();
         }
     }
 
+    pub fn set_company_following(&mut self, company_key: &str, is_following: bool) {
+        let c = self.companies.get_mut(company_key).unwrap();
+
+        if c.is_following != is_following {
+            c.is_following = is_following;
+            self.save();
+        }
+    }
+
+    /// Adds a connection, refusing one with the same name at the same company
+    pub fn add_connection(&mut self, connection: Connection) -> AppResult<()> {
+        let company = self
+            .companies
+            .get_mut(&connection.company)
+            .ok_or_else(|| format!("Unknown company {}", connection.company))?;
+
+        if company.connections.iter().any(|c| {
+            c.first_name == connection.first_name && c.last_name == connection.last_name
+        }) {
+            return Err(format!(
+                "{} {} is already a connection at {}",
+                connection.first_name, connection.last_name, connection.company
+            )
+            .into());
+        }
+
+        company.connections.push(connection);
+        self.save();
+
+        Ok(())
+    }
+
+    /// Removes a connection along with its reminders, returns whether it existed
+    pub fn remove_connection(&mut self, company_key: &str, first_name: &str, last_name: &str) -> bool {
+        let Some(company) = self.companies.get_mut(company_key) else {
+            return false;
+        };
+
+        let count = company.connections.len();
+        company
+            .connections
+            .retain(|c| c.first_name != first_name || c.last_name != last_name);
+
+        if company.connections.len() == count {
+            return false;
+        }
+
+        // INFO: Reminders about a deleted connection would point at nothing
+        self.reminders.retain(|r| {
+            r.target
+                != ReminderTarget::Connection {
+                    company: company_key.to_string(),
+                    first_name: first_name.to_string(),
+                    last_name: last_name.to_string(),
+                }
+        });
+        self.save();
+
+        true
+    }
+
     pub fn toggle_company_follow(&mut self, company_key: &str) {
         let c = self.companies.get_mut(company_key).unwrap();
 
@@ -357,6 +451,134 @@ impl Data {
         self.save();
     }
 
+    // INFO: Applies `update` to the job with the given id and saves if it reports a change
+    /// Saves when `update` reports a change, returning whether it did
+    fn update_job<F>(&mut self, id: &uuid::Uuid, update: F) -> bool
+    where
+        F: FnOnce(&mut Job) -> bool,
+    {
+        let job = self
+            .companies
+            .iter_mut()
+            .flat_map(|(_, c)| &mut c.jobs)
+            .find(|j| j.id == *id);
+
+        let changed = job.is_some_and(update);
+        if changed {
+            self.save();
+        }
+
+        changed
+    }
+
+    pub fn set_job_notes(&mut self, id: &uuid::Uuid, notes: String) {
+        self.update_job(id, |j| {
+            j.notes = notes.trim_end().to_string();
+            true
+        });
+    }
+
+    pub fn add_job_tag(&mut self, id: &uuid::Uuid, tag: &str) {
+        self.update_job(id, |j| j.add_tag(tag));
+    }
+
+    pub fn remove_job_tag(&mut self, id: &uuid::Uuid, tag: &str) {
+        self.update_job(id, |j| j.remove_tag(tag));
+    }
+
+    pub fn set_job_custom_field(&mut self, id: &uuid::Uuid, key: &str, value: &str) {
+        self.update_job(id, |j| {
+            j.custom_fields
+                .insert(key.trim().to_string(), value.trim().to_string());
+            true
+        });
+    }
+
+    pub fn remove_job_custom_field(&mut self, id: &uuid::Uuid, key: &str) {
+        self.update_job(id, |j| j.custom_fields.remove(key).is_some());
+    }
+
+    /// Every tag in use across all companies, for suggesting existing tags
+    pub fn all_job_tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self
+            .companies
+            .values()
+            .flat_map(|c| c.jobs.iter().flat_map(|j| j.tags.iter().cloned()))
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect();
+
+        tags.sort();
+        tags
+    }
+
+    /// Every country, region and remote query matching at least one open job, with how many jobs match
+    pub fn location_counts(&self) -> Vec<(LocationQuery, usize)> {
+        let mut counts: HashMap<LocationQuery, usize> = HashMap::new();
+
+        for job in self
+            .companies
+            .values()
+            .flat_map(|c| c.jobs.iter())
+            .filter(|j| !j.is_closed())
+        {
+            let mut queries: HashSet<LocationQuery> = HashSet::new();
+
+            for location in job.normalized_locations() {
+                let remote = location.arrangement == Some(WorkArrangement::Remote);
+
+                if location.country.is_some() {
+                    queries.insert(LocationQuery {
+                        country: location.country.clone(),
+                        ..Default::default()
+                    });
+                }
+                if location.region.is_some() {
+                    queries.insert(LocationQuery {
+                        country: location.country.clone(),
+                        region: location.region.clone(),
+                        ..Default::default()
+                    });
+                }
+                if remote {
+                    queries.insert(LocationQuery {
+                        remote,
+                        ..Default::default()
+                    });
+                    queries.insert(LocationQuery {
+                        remote,
+                        country: location.country.clone(),
+                        ..Default::default()
+                    });
+                }
+            }
+
+            for query in queries {
+                *counts.entry(query).or_default() += 1;
+            }
+        }
+
+        let mut counts: Vec<(LocationQuery, usize)> = counts.into_iter().collect();
+        counts.sort_by(|(a, a_count), (b, b_count)| {
+            b_count.cmp(a_count).then_with(|| a.label().cmp(&b.label()))
+        });
+
+        counts
+    }
+
+    pub fn set_job_bookmarked(&mut self, id: &uuid::Uuid, is_bookmarked: bool) {
+        self.update_job(id, |j| {
+            let changed = j.is_bookmarked != is_bookmarked;
+            j.is_bookmarked = is_bookmarked;
+            changed
+        });
+    }
+
+    /// Reads a job's salary from its description, for when one is fetched or loaded
+    pub fn refresh_job_salary(&mut self, id: &uuid::Uuid, description: &JobDescription) {
+        self.update_job(id, |j| j.refresh_salary_from_description(description));
+    }
+
     pub fn toggle_job_bookmark(&mut self, id: &uuid::Uuid) {
         if self
             .companies
@@ -385,6 +607,150 @@ impl Data {
         self.save();
     }
 
+    pub fn set_smart_criteria_min_salary(&mut self, min_salary: Option<u64>) {
+        self.smart_criteria_min_salary = min_salary;
+        self.save();
+    }
+
+    /// Takes a background scan's jobs for a company, keeping what the user changed on them since the scan read them
+    ///
+    /// A closed job the scan dropped stays, marked closed, if the user started tracking it meanwhile.
+    pub fn merge_scanned_jobs(&mut self, company_key: &str, mut scanned_jobs: Vec<Job>) {
+        let reminded = self.reminded_job_ids(company_key);
+        let company = self.companies.get_mut(company_key).unwrap();
+
+        for job in scanned_jobs.iter_mut() {
+            if let Some(current) = company.jobs.iter().find(|j| j.id == job.id) {
+                job.replace_user_state(current);
+            }
+        }
+
+        let now = Utc::now();
+        let tracked_meanwhile: Vec<Job> = company
+            .jobs
+            .iter()
+            .filter(|j| j.has_user_state() || reminded.contains(&j.id))
+            .filter(|j| !scanned_jobs.iter().any(|s| s.id == j.id))
+            .map(|j| Job {
+                closed_at: j.closed_at.or(Some(now)),
+                ..j.clone()
+            })
+            .collect();
+
+        scanned_jobs.extend(tracked_meanwhile);
+        company.jobs = scanned_jobs;
+    }
+
+    /// The company key and job for a job id
+    pub fn find_job(&self, id: &uuid::Uuid) -> Option<(&String, &Job)> {
+        self.companies
+            .iter()
+            .find_map(|(company, c)| c.jobs.iter().find(|j| j.id == *id).map(|j| (company, j)))
+    }
+
+    /// Every job matching a search, newest first. Closed jobs only show up when the query asks about them
+    pub fn search_jobs(&self, query: &SearchQuery) -> Vec<(String, Job)> {
+        let query = if query.mentions_flag(SearchFlag::Closed) {
+            query.clone()
+        } else {
+            SearchQuery::And(vec![
+                query.clone(),
+                SearchQuery::Flag {
+                    flag: SearchFlag::Closed,
+                    value: false,
+                },
+            ])
+        };
+        let ids = with_search_index(|index| {
+            index.sync(&self.companies);
+            index.search(&query)
+        });
+
+        let mut results: Vec<(String, Job)> = self
+            .companies
+            .iter()
+            .flat_map(|(company, c)| c.jobs.iter().map(move |j| (company, j)))
+            .filter(|(_, j)| ids.contains(&j.id))
+            .map(|(company, j)| (company.clone(), j.clone()))
+            .collect();
+
+        results.sort_by(|(_, a), (_, b)| {
+            let a_posted = a.metadata.posted_at.or(a.first_seen_at);
+            let b_posted = b.metadata.posted_at.or(b.first_seen_at);
+
+            b_posted.cmp(&a_posted)
+        });
+
+        results
+    }
+
+    /// Adds the saved search, or replaces the one with the same id
+    pub fn upsert_saved_search(&mut self, search: SavedSearch) {
+        match self.saved_searches.iter_mut().find(|s| s.id == search.id) {
+            Some(existing) => *existing = search,
+            None => self.saved_searches.push(search),
+        }
+        self.save();
+    }
+
+    pub fn delete_saved_search(&mut self, id: &uuid::Uuid) {
+        self.saved_searches.retain(|s| s.id != *id);
+        self.save();
+    }
+
+    pub fn add_reminder(&mut self, reminder: Reminder) {
+        self.reminders.push(reminder);
+        self.save();
+    }
+
+    pub fn complete_reminder(&mut self, id: &uuid::Uuid) {
+        if let Some(r) = self.reminders.iter_mut().find(|r| r.id == *id) {
+            r.completed_at = Some(Utc::now());
+            self.save();
+        }
+    }
+
+    pub fn reschedule_reminder(&mut self, id: &uuid::Uuid, due_at: DateTime<Utc>) {
+        if let Some(r) = self.reminders.iter_mut().find(|r| r.id == *id) {
+            r.due_at = due_at;
+            // INFO: A rescheduled reminder should be delivered again when it comes due
+            r.notified_at = None;
+            self.save();
+        }
+    }
+
+    pub fn delete_reminder(&mut self, id: &uuid::Uuid) {
+        self.reminders.retain(|r| r.id != *id);
+        self.save();
+    }
+
+    /// Jobs at `company_key` with an open reminder, a scan keeps them even once they close
+    pub fn reminded_job_ids(&self, company_key: &str) -> HashSet<uuid::Uuid> {
+        self.reminders
+            .iter()
+            .filter(|r| r.is_open())
+            .filter_map(|r| match &r.target {
+                ReminderTarget::Job { company, job_id } if company == company_key => Some(*job_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn mark_reminders_notified(&mut self, ids: &[uuid::Uuid]) {
+        let now = Utc::now();
+
+        for r in self.reminders.iter_mut().filter(|r| ids.contains(&r.id)) {
+            r.notified_at = Some(now);
+        }
+
+        self.save();
+    }
+
+    pub fn set_discord_webhook_url(&mut self, webhook_url: String) {
+        self.discord_webhook_url = Some(webhook_url);
+        self.save();
+    }
+
     pub fn get_new_jobs_report_files() -> AppResult<Vec<String>> {
         let reports_dir = Self::get_data_dir().join("reports");
         let paths = fs::read_dir(reports_dir)?;
@@ -0,0 +1,205 @@
+use std::{fs, sync::LazyLock};
+
+use chrono::{DateTime, Utc};
+use ego_tree::NodeRef;
+use regex::Regex;
+use scraper::{Html, Node};
+use uuid::Uuid;
+
+use crate::error::AppResult;
+
+use super::{data::Data, search::with_search_index};
+
+static EXTRA_BLANK_LINES_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\n{3,}").unwrap());
+
+static INLINE_WHITESPACE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[ \t]+").unwrap());
+
+/// A job's description as stored on disk, next to data.json in `descriptions/<job id>.md`
+pub struct JobDescription {
+    pub markdown: String,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl JobDescription {
+    fn path(job_id: &Uuid) -> std::path::PathBuf {
+        Data::get_data_dir()
+            .join("descriptions")
+            .join(format!("{}.md", job_id))
+    }
+
+    pub fn load(job_id: &Uuid) -> Option<Self> {
+        let markdown = fs::read_to_string(Self::path(job_id)).ok()?;
+
+        Some(JobDescription {
+            markdown,
+            fetched_at: Self::fetched_at(job_id).unwrap_or_else(Utc::now),
+        })
+    }
+
+    /// When the stored description was written, without reading it
+    pub fn fetched_at(job_id: &Uuid) -> Option<DateTime<Utc>> {
+        fs::metadata(Self::path(job_id))
+            .and_then(|m| m.modified())
+            .map(DateTime::<Utc>::from)
+            .ok()
+    }
+
+    pub fn exists(job_id: &Uuid) -> bool {
+        Self::path(job_id).exists()
+    }
+
+    pub fn save(job_id: &Uuid, markdown: &str) -> AppResult<()> {
+        let path = Self::path(job_id);
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, markdown)?;
+        with_search_index(|index| index.invalidate(job_id));
+
+        Ok(())
+    }
+}
+
+fn render_children(node: NodeRef<Node>, out: &mut String, list_depth: usize) {
+    for child in node.children() {
+        render_node(child, out, list_depth);
+    }
+}
+
+fn render_node(node: NodeRef<Node>, out: &mut String, list_depth: usize) {
+    let element = match node.value() {
+        Node::Text(text) => {
+            out.push_str(&text.replace(['\n', '\r'], " "));
+            return;
+        }
+        Node::Element(element) => element,
+        _ => return render_children(node, out, list_depth),
+    };
+
+    match element.name() {
+        "script" | "style" | "noscript" | "svg" | "button" | "form" | "iframe" => {}
+        "br" => out.push('\n'),
+        "hr" => out.push_str("\n\n---\n\n"),
+        heading @ ("h1" | "h2" | "h3" | "h4" | "h5" | "h6") => {
+            let level = heading[1..].parse::<usize>().unwrap_or(2);
+            out.push_str(&format!("\n\n{} ", "#".repeat(level)));
+            render_children(node, out, list_depth);
+            out.push_str("\n\n");
+        }
+        // Nested lists hang off their parent item without blank lines around them
+        "ul" | "ol" if list_depth > 0 => render_children(node, out, list_depth + 1),
+        "ul" | "ol" => {
+            out.push('\n');
+            render_children(node, out, list_depth + 1);
+            out.push('\n');
+        }
+        "li" => {
+            out.push_str(&format!(
+                "\n{}- ",
+                "  ".repeat(list_depth.saturating_sub(1))
+            ));
+            render_children(node, out, list_depth);
+        }
+        "strong" | "b" => {
+            out.push_str("**");
+            render_children(node, out, list_depth);
+            out.push_str("**");
+        }
+        "em" | "i" => {
+            out.push('_');
+            render_children(node, out, list_depth);
+            out.push('_');
+        }
+        "p" | "div" | "section" | "article" | "header" | "footer" | "main" | "table" | "tr" => {
+            out.push_str("\n\n");
+            render_children(node, out, list_depth);
+            out.push_str("\n\n");
+        }
+        _ => render_children(node, out, list_depth),
+    }
+}
+
+/// Converts a description's HTML to Markdown that reads well in a terminal
+///
+/// Only headings, paragraphs, lists and emphasis survive, everything else is flattened to text.
+pub fn html_to_markdown(html: &str) -> String {
+    let fragment = Html::parse_fragment(html);
+    let mut raw = String::new();
+    render_children(fragment.tree.root(), &mut raw, 0);
+
+    let lines: Vec<String> = raw
+        .lines()
+        .map(|line| {
+            let indent: String = line.chars().take_while(|c| *c == ' ').collect();
+            let content = INLINE_WHITESPACE_RE.replace_all(line.trim(), " ");
+
+            // Keep the indentation of nested list items only
+            if content.starts_with("- ") {
+                format!("{}{}", indent, content)
+            } else {
+                content.to_string()
+            }
+        })
+        .collect();
+
+    EXTRA_BLANK_LINES_RE
+        .replace_all(&lines.join("\n"), "\n\n")
+        .replace("****", "")
+        .trim()
+        .to_string()
+}
+
+/// Some APIs (Greenhouse) return the description HTML entity-escaped, e.g. "&lt;p&gt;"
+pub fn unescape_html(escaped: &str) -> String {
+    Html::parse_fragment(escaped)
+        .root_element()
+        .text()
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_html_to_markdown() {
+        let html = r#"
+            <div>
+                <h2>About the role</h2>
+                <p>You will   build <strong>payments</strong> infrastructure.<br>Remote friendly.</p>
+                <h3>What you'll need</h3>
+                <ul>
+                    <li>5+ years of Rust</li>
+                    <li>Distributed systems
+                        <ul><li>Kafka</li></ul>
+                    </li>
+                </ul>
+                <script>track()</script>
+                <p>Pay range: $150,000 - $210,000 USD</p>
+            </div>
+        "#;
+
+        let markdown = html_to_markdown(html);
+
+        assert_eq!(
+            markdown,
+            "## About the role\n\n\
+             You will build **payments** infrastructure.\n\
+             Remote friendly.\n\n\
+             ### What you'll need\n\n\
+             - 5+ years of Rust\n\
+             - Distributed systems\n\
+             \x20\x20- Kafka\n\n\
+             Pay range: $150,000 - $210,000 USD"
+        );
+    }
+
+    #[test]
+    fn test_unescape_html() {
+        assert_eq!(
+            unescape_html("&lt;p&gt;Hello &amp;amp; welcome&lt;/p&gt;"),
+            "<p>Hello &amp; welcome</p>"
+        );
+    }
+}
@@ -0,0 +1,394 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use uuid::Uuid;
+
+use super::{
+    identity::{consolidate_scraped_jobs, match_scraped_jobs, same_posting},
+    scraper::{Job, ScrapedJob},
+};
+
+#[derive(Debug, Display, Deserialize, Serialize, Clone, PartialEq)]
+pub enum JobField {
+    #[strum(to_string = "Title")]
+    Title,
+    #[strum(to_string = "Location")]
+    Location,
+    #[strum(to_string = "Link")]
+    Link,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct FieldChange {
+    pub field: JobField,
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JobChange {
+    pub job: Job,
+    pub changes: Vec<FieldChange>,
+}
+
+/// The outcome of reconciling a fresh scrape against the jobs stored for a company
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct JobsDiff {
+    // INFO: True when the company had no stored jobs, every job is `new` but nothing really changed
+    pub is_baseline: bool,
+    pub new: Vec<Job>,
+    pub unchanged: Vec<Job>,
+    pub changed: Vec<JobChange>,
+    // INFO: Previously closed postings that showed up on the board again. Only jobs that were kept
+    // when they closed can be recognized, an untracked posting that comes back is `new`
+    pub reposted: Vec<Job>,
+    // INFO: Postings that disappeared during this scrape, whether or not they were kept as closed
+    pub removed: Vec<Job>,
+}
+
+impl JobsDiff {
+    /// Jobs worth telling the user about, optionally skipping a company's baseline scrape
+    pub fn jobs_to_notify(&self, notify_on_baseline: bool) -> Vec<Job> {
+        if self.is_baseline && !notify_on_baseline {
+            return Vec::new();
+        }
+
+        self.new.iter().chain(self.reposted.iter()).cloned().collect()
+    }
+}
+
+fn field_changes(job: &Job, scraped: &ScrapedJob) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+
+    if job.title != scraped.title {
+        changes.push(FieldChange {
+            field: JobField::Title,
+            from: job.title.clone(),
+            to: scraped.title.clone(),
+        });
+    }
+
+    if job.location != scraped.location {
+        changes.push(FieldChange {
+            field: JobField::Location,
+            from: job.location.clone(),
+            to: scraped.location.clone(),
+        });
+    }
+
+    // Tracking params and trailing slashes are noise, only a different canonical link counts
+    if job.canonical_link() != scraped.canonical_link() {
+        changes.push(FieldChange {
+            field: JobField::Link,
+            from: job.link.clone(),
+            to: scraped.link.clone(),
+        });
+    }
+
+    changes
+}
+
+/// Reconciles a scrape with the stored jobs and classifies every posting
+///
+/// Returns the company's updated job list alongside the diff. Jobs that disappear are dropped
+/// unless they carry user state or are in `reminded`, the jobs with open reminders, in which case
+/// they are kept and marked closed. Since nothing is remembered about a dropped job, only kept jobs
+/// are reported as `reposted` when they come back.
+pub fn diff_jobs(
+    existing: &[Job],
+    scraped_jobs: Vec<ScrapedJob>,
+    now: DateTime<Utc>,
+    reminded: &HashSet<Uuid>,
+) -> (Vec<Job>, JobsDiff) {
+    let scraped_jobs = consolidate_scraped_jobs(scraped_jobs);
+
+    let mut diff = JobsDiff {
+        is_baseline: existing.is_empty(),
+        ..Default::default()
+    };
+    let mut all_jobs: Vec<Job> = Vec::new();
+
+    let matches = match_scraped_jobs(existing, &scraped_jobs);
+    let matched: HashSet<usize> = matches.iter().flatten().copied().collect();
+
+    for (sc, existing_idx) in scraped_jobs.into_iter().zip(matches) {
+        let Some(idx) = existing_idx else {
+            let new_job = Job::from_scraped(sc, now);
+            diff.new.push(new_job.clone());
+            all_jobs.push(new_job);
+            continue;
+        };
+
+        // Keep the stored id and user state, but follow retitles and link changes
+        let mut job = existing[idx].clone();
+        let changes = field_changes(&job, &sc);
+        let was_closed = job.is_closed();
+
+        job.title = sc.title;
+        job.location = sc.location;
+        job.link = sc.link;
+        job.locations = sc.locations;
+        job.update_metadata(sc.metadata);
+        job.refresh_seniority();
+        if sc.external_id.is_some() {
+            job.external_id = sc.external_id;
+        }
+        job.first_seen_at = job.first_seen_at.or(Some(now));
+        job.last_seen_at = Some(now);
+        job.closed_at = None;
+
+        if was_closed {
+            diff.reposted.push(job.clone());
+        } else if !changes.is_empty() {
+            diff.changed.push(JobChange {
+                job: job.clone(),
+                changes,
+            });
+        } else {
+            diff.unchanged.push(job.clone());
+        }
+
+        all_jobs.push(job);
+    }
+
+    let scraped_count = all_jobs.len();
+
+    for (idx, job) in existing.iter().enumerate() {
+        if matched.contains(&idx) {
+            continue;
+        }
+
+        // INFO: Leftover per-location copies of a posting that is now stored as one job
+        if let Some(merged) = all_jobs[..scraped_count]
+            .iter_mut()
+            .find(|j| same_posting(j, job))
+        {
+            merged.absorb_user_state(job);
+            continue;
+        }
+
+        let mut gone = job.clone();
+
+        // Already closed on an earlier scrape, nothing new to report
+        if !gone.is_closed() {
+            gone.closed_at = Some(now);
+            diff.removed.push(gone.clone());
+        }
+
+        if gone.has_user_state() || reminded.contains(&gone.id) {
+            all_jobs.push(gone);
+        }
+    }
+
+    (all_jobs, diff)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::description::JobDescription;
+
+    fn scraped(title: &str, location: &str, link: &str) -> ScrapedJob {
+        ScrapedJob {
+            title: title.to_string(),
+            location: location.to_string(),
+            link: link.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn baseline(scraped_jobs: Vec<ScrapedJob>) -> Vec<Job> {
+        diff_jobs(&[], scraped_jobs, Utc::now(), &HashSet::new()).0
+    }
+
+    #[test]
+    fn test_baseline_scrape() {
+        let (jobs, diff) = diff_jobs(
+            &[],
+            vec![
+                scraped(" Software Engineer ", "Remote", "https://x.com/1"),
+                scraped("Staff Engineer", "NYC", "https://x.com/2"),
+            ],
+            Utc::now(),
+            &HashSet::new(),
+        );
+
+        assert!(diff.is_baseline);
+        assert_eq!(diff.new.len(), 2);
+        assert_eq!(jobs[0].title, "Software Engineer");
+        assert!(diff.jobs_to_notify(false).is_empty());
+        assert_eq!(diff.jobs_to_notify(true).len(), 2);
+    }
+
+    #[test]
+    fn test_new_unchanged_and_changed() {
+        let existing = baseline(vec![
+            scraped("Software Engineer", "Remote", "https://x.com/1"),
+            scraped("Data Engineer", "NYC", "https://x.com/2"),
+        ]);
+
+        let (jobs, diff) = diff_jobs(
+            &existing,
+            vec![
+                scraped("Software Engineer", "Remote", "https://x.com/1?utm_source=li"),
+                scraped("Senior Data Engineer", "NYC", "https://x.com/2"),
+                scraped("Security Engineer", "SF", "https://x.com/3"),
+            ],
+            Utc::now(),
+            &HashSet::new(),
+        );
+
+        assert!(!diff.is_baseline);
+        assert_eq!(jobs.len(), 3);
+        assert_eq!(diff.unchanged.len(), 1);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].job.id, existing[1].id);
+        assert_eq!(diff.changed[0].changes[0].field, JobField::Title);
+        assert_eq!(diff.new.len(), 1);
+        assert_eq!(diff.jobs_to_notify(false)[0].title, "Security Engineer");
+    }
+
+    #[test]
+    fn test_removed_jobs_keep_user_state() {
+        let mut existing = baseline(vec![
+            scraped("Software Engineer", "Remote", "https://x.com/1"),
+            scraped("Data Engineer", "NYC", "https://x.com/2"),
+        ]);
+        existing[0].applied = true;
+
+        let (jobs, diff) = diff_jobs(&existing, vec![], Utc::now(), &HashSet::new());
+
+        assert_eq!(diff.removed.len(), 2);
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].id, existing[0].id);
+        assert!(jobs[0].is_closed());
+
+        // A second scrape without the job does not report it as removed again
+        let (jobs, diff) = diff_jobs(&jobs, vec![], Utc::now(), &HashSet::new());
+
+        assert!(diff.removed.is_empty());
+        assert_eq!(jobs.len(), 1);
+    }
+
+    #[test]
+    fn test_legacy_per_location_rows_are_merged() {
+        let mut existing = baseline(vec![scraped(
+            "Software Engineer",
+            "Remote",
+            "https://x.com/1",
+        )]);
+        let mut seattle = existing[0].clone();
+        seattle.id = uuid::Uuid::new_v4();
+        seattle.location = "Seattle, WA".to_string();
+        seattle.is_bookmarked = true;
+        existing.push(seattle);
+
+        let (jobs, diff) = diff_jobs(
+            &existing,
+            vec![
+                scraped("Software Engineer", "Remote", "https://x.com/1"),
+                scraped("Software Engineer", "Seattle, WA", "https://x.com/1"),
+            ],
+            Utc::now(),
+            &HashSet::new(),
+        );
+
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].all_locations(), vec!["Remote", "Seattle, WA"]);
+        assert!(jobs[0].is_bookmarked);
+        assert!(diff.removed.is_empty());
+    }
+
+    #[test]
+    fn test_reposted_job() {
+        let mut existing = baseline(vec![scraped(
+            "Software Engineer",
+            "Remote",
+            "https://x.com/1",
+        )]);
+        existing[0].is_bookmarked = true;
+
+        let (closed, _) = diff_jobs(&existing, vec![], Utc::now(), &HashSet::new());
+        let (jobs, diff) = diff_jobs(
+            &closed,
+            vec![scraped("Software Engineer", "Remote", "https://x.com/1")],
+            Utc::now(),
+            &HashSet::new(),
+        );
+
+        assert_eq!(diff.reposted.len(), 1);
+        assert!(diff.new.is_empty());
+        assert!(!jobs[0].is_closed());
+        assert!(jobs[0].is_bookmarked);
+        assert_eq!(diff.jobs_to_notify(false).len(), 1);
+    }
+
+    #[test]
+    fn test_untracked_repost_is_new() {
+        let existing = baseline(vec![
+            scraped("Software Engineer", "Remote", "https://x.com/1"),
+            scraped("Data Engineer", "NYC", "https://x.com/2"),
+        ]);
+
+        let (closed, diff) = diff_jobs(&existing, vec![], Utc::now(), &HashSet::new());
+        assert_eq!(diff.removed.len(), 2);
+        assert!(closed.is_empty());
+
+        let (jobs, diff) = diff_jobs(
+            &closed,
+            vec![scraped("Software Engineer", "Remote", "https://x.com/1")],
+            Utc::now(),
+            &HashSet::new(),
+        );
+
+        // Nothing was kept to match against, so the posting looks brand new
+        assert!(diff.reposted.is_empty());
+        assert_eq!(diff.new.len(), 1);
+        assert_ne!(jobs[0].id, existing[0].id);
+    }
+
+    #[test]
+    fn test_removed_job_with_reminder_is_kept() {
+        let existing = baseline(vec![
+            scraped("Software Engineer", "Remote", "https://x.com/1"),
+            scraped("Data Engineer", "NYC", "https://x.com/2"),
+        ]);
+        let reminded = HashSet::from([existing[1].id]);
+
+        let (jobs, diff) = diff_jobs(&existing, vec![], Utc::now(), &reminded);
+
+        assert_eq!(diff.removed.len(), 2);
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].id, existing[1].id);
+        assert!(jobs[0].is_closed());
+    }
+
+    #[test]
+    fn test_salary_from_description_survives_rescrapes() {
+        let mut existing = baseline(vec![scraped(
+            "Software Engineer",
+            "Remote",
+            "https://x.com/1",
+        )]);
+        let description = JobDescription {
+            markdown: "Pay: **$150,000** - **$210,000** a year".to_string(),
+            fetched_at: Utc::now(),
+        };
+        assert!(existing[0].refresh_salary_from_description(&description));
+        assert!(!existing[0].refresh_salary_from_description(&description));
+
+        let rescrape = || vec![scraped("Software Engineer", "Remote", "https://x.com/1")];
+        let (jobs, _) = diff_jobs(&existing, rescrape(), Utc::now(), &HashSet::new());
+        assert_eq!(jobs[0].salary.as_ref().unwrap().annual_max(), 210_000);
+
+        // A range from the board itself wins
+        let mut with_range = rescrape();
+        with_range[0].metadata.salary_range = Some("$100,000 - $120,000".to_string());
+        let (jobs, _) = diff_jobs(&jobs, with_range, Utc::now(), &HashSet::new());
+        assert_eq!(jobs[0].salary.as_ref().unwrap().annual_max(), 120_000);
+        let mut job = jobs[0].clone();
+        assert!(!job.refresh_salary_from_description(&description));
+    }
+}
@@ -0,0 +1,427 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter};
+
+use super::{
+    data::Data, location::LocationQuery, metadata::EmploymentType, scraper::Job,
+    seniority::Seniority,
+};
+
+#[derive(Debug, Default, Display, EnumIter, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum JobSort {
+    #[default]
+    #[strum(to_string = "Newest First")]
+    Newest,
+    #[strum(to_string = "Highest Salary First")]
+    SalaryHighToLow,
+}
+
+/// Criteria a job has to meet to show up in a filtered view, unset criteria match everything
+#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq)]
+pub struct JobFilter {
+    // INFO: Every word has to appear in the title, location, department, tags or notes
+    #[serde(default)]
+    pub keywords: Option<String>,
+    // INFO: Every word has to appear in the title itself
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub companies: Vec<String>,
+    // INFO: Matches when any of the job's locations is in the queried place, e.g. "US-CA" or "remote-US"
+    #[serde(default)]
+    pub location: Option<LocationQuery>,
+    #[serde(default)]
+    pub seniority: Vec<Seniority>,
+    #[serde(default)]
+    pub department: Option<String>,
+    #[serde(default)]
+    pub employment_type: Option<EmploymentType>,
+    #[serde(default)]
+    pub remote_only: bool,
+    // INFO: Uses the posted date when the source has one, otherwise when JobShell first saw the job
+    #[serde(default)]
+    pub posted_within_days: Option<i64>,
+    #[serde(default)]
+    pub include_closed: bool,
+    // INFO: Compared against the top of the annualized range, jobs without pay information still match
+    #[serde(default)]
+    pub min_annual_salary: Option<u64>,
+    #[serde(default)]
+    pub sort: JobSort,
+}
+
+impl JobFilter {
+    pub fn matches(&self, company: &str, job: &Job, now: DateTime<Utc>) -> bool {
+        if !self.include_closed && job.is_closed() {
+            return false;
+        }
+
+        if !self.companies.is_empty() && !self.companies.iter().any(|c| c == company) {
+            return false;
+        }
+
+        if let Some(keywords) = &self.keywords {
+            let haystack = format!(
+                "{} {} {} {} {}",
+                job.title,
+                job.location,
+                job.metadata.department.as_deref().unwrap_or_default(),
+                job.tags.join(" "),
+                job.notes
+            )
+            .to_lowercase();
+
+            if !keywords
+                .to_lowercase()
+                .split_whitespace()
+                .all(|word| haystack.contains(word))
+            {
+                return false;
+            }
+        }
+
+        if let Some(title) = &self.title {
+            let job_title = job.title.to_lowercase();
+
+            if !title
+                .to_lowercase()
+                .split_whitespace()
+                .all(|word| job_title.contains(word))
+            {
+                return false;
+            }
+        }
+
+        if let Some(location) = &self.location {
+            if !job
+                .normalized_locations()
+                .iter()
+                .any(|l| location.matches(l))
+            {
+                return false;
+            }
+        }
+
+        if !self.seniority.is_empty()
+            && job.seniority.is_none_or(|s| !self.seniority.contains(&s))
+        {
+            return false;
+        }
+
+        if let Some(department) = &self.department {
+            let matches_department = job
+                .metadata
+                .department
+                .as_ref()
+                .is_some_and(|d| d.to_lowercase().contains(&department.to_lowercase()));
+
+            if !matches_department {
+                return false;
+            }
+        }
+
+        if self.employment_type.is_some() && job.metadata.employment_type != self.employment_type {
+            return false;
+        }
+
+        if self.remote_only && !job.is_remote() {
+            return false;
+        }
+
+        if let Some(days) = self.posted_within_days {
+            let posted = job.metadata.posted_at.or(job.first_seen_at);
+
+            if !matches!(posted, Some(p) if p >= now - Duration::days(days)) {
+                return false;
+            }
+        }
+
+        if let Some(min_annual_salary) = self.min_annual_salary {
+            if job
+                .salary
+                .as_ref()
+                .is_some_and(|s| s.annual_max() < min_annual_salary)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Every matching job across all companies, in the order picked by `sort`
+    pub fn apply<'a>(&self, data: &'a Data, now: DateTime<Utc>) -> Vec<(&'a String, &'a Job)> {
+        let mut results: Vec<(&String, &Job)> = data
+            .companies
+            .iter()
+            .flat_map(|(company, c)| c.jobs.iter().map(move |j| (company, j)))
+            .filter(|(company, j)| self.matches(company, j, now))
+            .collect();
+
+        results.sort_by(|(_, a), (_, b)| {
+            let a_posted = a.metadata.posted_at.or(a.first_seen_at);
+            let b_posted = b.metadata.posted_at.or(b.first_seen_at);
+
+            match self.sort {
+                JobSort::Newest => b_posted.cmp(&a_posted),
+                // Jobs without a salary sink to the bottom, ties stay newest first
+                JobSort::SalaryHighToLow => {
+                    let a_salary = a.salary.as_ref().map(|s| s.annual_max());
+                    let b_salary = b.salary.as_ref().map(|s| s.annual_max());
+
+                    b_salary.cmp(&a_salary).then(b_posted.cmp(&a_posted))
+                }
+            }
+        });
+
+        results
+    }
+
+    /// One line per active criterion, for showing the filter back to the user
+    pub fn describe(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        if let Some(keywords) = &self.keywords {
+            lines.push(format!("Keywords: {}", keywords));
+        }
+        if let Some(title) = &self.title {
+            lines.push(format!("Title: {}", title));
+        }
+        if !self.companies.is_empty() {
+            lines.push(format!("Companies: {}", self.companies.join(", ")));
+        }
+        if let Some(location) = &self.location {
+            lines.push(format!("Location: {} ({})", location.label(), location));
+        }
+        if !self.seniority.is_empty() {
+            let levels: Vec<String> = self.seniority.iter().map(|s| s.to_string()).collect();
+            lines.push(format!("Seniority: {}", levels.join(", ")));
+        }
+        if let Some(department) = &self.department {
+            lines.push(format!("Department: {}", department));
+        }
+        if let Some(employment_type) = &self.employment_type {
+            lines.push(format!("Employment Type: {}", employment_type));
+        }
+        if self.remote_only {
+            lines.push("Remote only".to_string());
+        }
+        if let Some(days) = self.posted_within_days {
+            lines.push(format!("Posted within {} days", days));
+        }
+        if self.include_closed {
+            lines.push("Including closed postings".to_string());
+        }
+        if let Some(min_annual_salary) = self.min_annual_salary {
+            lines.push(format!("Salary at least {} a year", min_annual_salary));
+        }
+        if self.sort != JobSort::default() {
+            lines.push(format!("Sorted by {}", self.sort));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::models::{
+        data::Company,
+        metadata::{greenhouse_metadata, JobMetadata},
+        salary::parse_salary,
+        scraper::{JobsPayload, ScrapedJob},
+    };
+
+    use super::*;
+
+    fn job(title: &str, metadata: JobMetadata) -> Job {
+        Job {
+            title: title.to_string(),
+            location: "Seattle, WA".to_string(),
+            metadata,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_empty_filter_matches_open_jobs() {
+        let now = Utc::now();
+        let mut closed = job("Software Engineer", JobMetadata::default());
+        closed.closed_at = Some(now);
+
+        let filter = JobFilter::default();
+
+        assert!(filter.matches(
+            "Meta",
+            &job("Software Engineer", JobMetadata::default()),
+            now
+        ));
+        assert!(!filter.matches("Meta", &closed, now));
+    }
+
+    #[test]
+    fn test_filter_criteria() {
+        let now = Utc::now();
+        let remote_backend = job(
+            "Backend Engineer",
+            JobMetadata {
+                posted_at: Some(now - Duration::days(2)),
+                department: Some("Engineering".to_string()),
+                employment_type: Some(EmploymentType::FullTime),
+                remote: Some(true),
+                ..Default::default()
+            },
+        );
+        let old_frontend = job(
+            "Frontend Engineer",
+            JobMetadata {
+                posted_at: Some(now - Duration::days(40)),
+                ..Default::default()
+            },
+        );
+
+        let filter = JobFilter {
+            keywords: Some("backend seattle".to_string()),
+            department: Some("engineer".to_string()),
+            employment_type: Some(EmploymentType::FullTime),
+            remote_only: true,
+            posted_within_days: Some(7),
+            ..Default::default()
+        };
+
+        assert!(filter.matches("Uber", &remote_backend, now));
+        assert!(!filter.matches("Uber", &old_frontend, now));
+
+        let by_company = JobFilter {
+            companies: vec!["Meta".to_string()],
+            ..Default::default()
+        };
+
+        assert!(!by_company.matches("Uber", &remote_backend, now));
+        assert!(by_company.matches("Meta", &remote_backend, now));
+
+        // "seattle" is in the location, not the title
+        let by_title = JobFilter {
+            title: Some("backend seattle".to_string()),
+            ..Default::default()
+        };
+
+        assert!(!by_title.matches("Uber", &remote_backend, now));
+        assert!(JobFilter {
+            title: Some("Backend".to_string()),
+            ..Default::default()
+        }
+        .matches("Uber", &remote_backend, now));
+
+        let by_seniority = JobFilter {
+            seniority: vec![Seniority::Senior, Seniority::Staff],
+            ..Default::default()
+        };
+        let mut senior_backend = remote_backend.clone();
+        senior_backend.title = "Senior Backend Engineer".to_string();
+        senior_backend.refresh_seniority();
+
+        assert!(by_seniority.matches("Uber", &senior_backend, now));
+        assert!(!by_seniority.matches("Uber", &remote_backend, now));
+
+        let by_location = JobFilter {
+            location: Some(LocationQuery::parse("US-WA").unwrap()),
+            ..Default::default()
+        };
+        let mut toronto = old_frontend.clone();
+        toronto.location = "Toronto, ON".to_string();
+
+        assert!(by_location.matches("Uber", &old_frontend, now));
+        assert!(!by_location.matches("Uber", &toronto, now));
+    }
+
+    #[test]
+    fn test_salary_filter_and_sort() {
+        let now = Utc::now();
+        let salaried = |title: &str, range: Option<&str>| Job {
+            salary: range.and_then(parse_salary),
+            ..job(title, JobMetadata::default())
+        };
+
+        let mut data = Data::default();
+        data.companies.insert(
+            "Meta".to_string(),
+            Company {
+                jobs: vec![
+                    salaried("No Salary", None),
+                    salaried("Mid", Some("$120,000 - $150,000")),
+                    salaried("Hourly", Some("$90/hr")),
+                    salaried("Low", Some("$80,000")),
+                ],
+                ..Default::default()
+            },
+        );
+
+        let filter = JobFilter {
+            min_annual_salary: Some(140_000),
+            sort: JobSort::SalaryHighToLow,
+            ..Default::default()
+        };
+
+        let titles: Vec<&str> = filter
+            .apply(&data, now)
+            .into_iter()
+            .map(|(_, j)| j.title.as_str())
+            .collect();
+
+        assert_eq!(titles, vec!["Hourly", "Mid", "No Salary"]);
+    }
+
+    #[test]
+    fn test_salary_from_scrape_to_filter() {
+        let mut data = Data::default();
+        data.detached = true;
+
+        let board = serde_json::json!([
+            {
+                "title": "Staff Engineer",
+                "content": "&lt;p&gt;Pay: $220,000 - $260,000 USD per year&lt;/p&gt;"
+            },
+            {
+                "title": "Software Engineer",
+                "pay_input_ranges": [
+                    { "min_cents": 11000000, "max_cents": 13000000, "currency_type": "USD" }
+                ]
+            },
+            { "title": "Security Engineer" }
+        ]);
+        let scraped_jobs = board
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|job| ScrapedJob {
+                title: job["title"].as_str().unwrap().to_string(),
+                location: "Remote".to_string(),
+                link: format!("https://x.com/{}", job["title"].as_str().unwrap()),
+                metadata: greenhouse_metadata(job, None),
+                ..Default::default()
+            })
+            .collect();
+
+        JobsPayload::from_scraped_jobs(scraped_jobs, "Robinhood", &mut data);
+
+        let filter = JobFilter {
+            companies: vec!["Robinhood".to_string()],
+            min_annual_salary: Some(150_000),
+            sort: JobSort::SalaryHighToLow,
+            ..Default::default()
+        };
+
+        let results: Vec<(&str, Option<u64>)> = filter
+            .apply(&data, Utc::now())
+            .into_iter()
+            .map(|(_, j)| (j.title.as_str(), j.salary.as_ref().map(|s| s.annual_max())))
+            .collect();
+
+        // The $130k job is below the floor, the one without pay stays
+        assert_eq!(
+            results,
+            vec![("Staff Engineer", Some(260_000)), ("Security Engineer", None)]
+        );
+    }
+}
@@ -1,10 +1,12 @@
-use std::{env, error::Error};
+use std::error::Error;
 
 use reqwest::Client;
 use serde::Deserialize;
 use serde_json::{json, Value};
 use tabled::{Table, Tabled};
+use tracing::error;
 
+use crate::config;
 use crate::models::scraper::Job;
 
 use super::{custom_error::CustomError, data::Data, scraper::JobsPayload};
@@ -166,12 +168,12 @@ If you do not have any data for a field just put ["NOT SPECIFIED"] for the ARRAY
                     return Ok(gemini_job);
                 }
                 Err(e) => {
-                    eprintln!("Error: {e}");
+                    error!(error = %e, "Could not read the Gemini response");
                     return Err(Box::new(e));
                 }
             },
             Err(e) => {
-                eprintln!("Error: {e}");
+                error!(error = %e, "Gemini request failed");
                 return Err(Box::new(e));
             }
         }
@@ -210,16 +212,20 @@ impl GeminiClient {
     pub fn new() -> Self {
         let url = format!(
             "https://generativelanguage.googleapis.com/v1beta/models/gemini-1.5-{}:generateContent",
-            env::var("GEMINI_MODEL").expect("GEMINI_MODEL is missing")
+            config::get().ai.gemini_model
         );
-        let key = env::var("GEMINI_KEY").expect("GEMINI_KEY is missing");
+        let key = config::get()
+            .ai
+            .gemini_key
+            .clone()
+            .expect("ai.gemini_key (or GEMINI_KEY) is missing");
         let url_with_api_key = format!("{url}?key={key}");
 
         GeminiClient {
             url,
             key,
             url_with_api_key,
-            client: Client::new(),
+            client: config::http_client(),
         }
     }
 }
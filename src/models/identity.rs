@@ -0,0 +1,339 @@
+use std::collections::{HashMap, HashSet};
+
+use reqwest::Url;
+use serde_json::Value;
+
+use super::scraper::{Job, ScrapedJob};
+
+// INFO: Query params that only track where a click came from and never identify a posting
+const TRACKING_PARAMS: [&str; 8] = [
+    "gh_src", "source", "src", "ref", "referrer", "lever-source", "lever-origin", "iis",
+];
+
+// INFO: Minimum token overlap for two titles to be considered the same posting
+const FUZZY_TITLE_THRESHOLD: f64 = 0.8;
+
+/// Reads an ATS identifier that may be serialized as either a string or a number
+pub fn external_id_from_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Normalizes a job link so the same posting reached through different URLs compares equal
+///
+/// Lowercases the host, drops `www.`, fragments, trailing slashes and tracking params, and
+/// sorts the remaining query params. Links that fail to parse are only trimmed and lowercased.
+pub fn canonicalize_link(link: &str) -> String {
+    let trimmed = link.trim();
+
+    let Ok(url) = Url::parse(trimmed) else {
+        return trimmed.trim_end_matches('/').to_lowercase();
+    };
+
+    let host = url
+        .host_str()
+        .unwrap_or_default()
+        .trim_start_matches("www.")
+        .to_lowercase();
+
+    let mut params: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(k, _)| {
+            let key = k.to_lowercase();
+            !key.starts_with("utm_") && !TRACKING_PARAMS.contains(&key.as_str())
+        })
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+    params.sort();
+
+    let mut canonical = format!("{}{}", host, url.path().trim_end_matches('/'));
+
+    if !params.is_empty() {
+        let query = params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<String>>()
+            .join("&");
+        canonical.push('?');
+        canonical.push_str(&query);
+    }
+
+    canonical
+}
+
+/// Lowercases text and reduces it to alphanumeric words separated by single spaces
+pub fn normalize_text(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn token_similarity(a: &str, b: &str) -> f64 {
+    let a_tokens: HashSet<&str> = a.split_whitespace().collect();
+    let b_tokens: HashSet<&str> = b.split_whitespace().collect();
+
+    if a_tokens.is_empty() && b_tokens.is_empty() {
+        return 1.0;
+    }
+
+    let intersection = a_tokens.intersection(&b_tokens).count() as f64;
+    let union = a_tokens.union(&b_tokens).count() as f64;
+
+    intersection / union
+}
+
+fn same_location(job: &Job, scraped: &ScrapedJob) -> bool {
+    normalize_text(&job.location) == normalize_text(&scraped.location)
+}
+
+// INFO: Strong identity, the ATS id when both sides have one, otherwise the canonical link
+fn strong_match(job: &Job, scraped: &ScrapedJob) -> bool {
+    match (&job.external_id, &scraped.external_id) {
+        (Some(a), Some(b)) => a == b,
+        _ => job.canonical_link() == scraped.canonical_link(),
+    }
+}
+
+fn fuzzy_score(job: &Job, scraped: &ScrapedJob) -> Option<f64> {
+    // Two different ATS ids are two different reqs, no matter how alike they look
+    if let (Some(a), Some(b)) = (&job.external_id, &scraped.external_id) {
+        if a != b {
+            return None;
+        }
+    }
+
+    if !same_location(job, scraped) {
+        return None;
+    }
+
+    let score = token_similarity(&normalize_text(&job.title), &normalize_text(&scraped.title));
+
+    if score >= FUZZY_TITLE_THRESHOLD {
+        Some(score)
+    } else {
+        None
+    }
+}
+
+// INFO: Key shared by every row of the same posting, the ATS id or else the link and title
+fn posting_key(external_id: &Option<String>, canonical_link: String, title: &str) -> String {
+    match external_id {
+        Some(id) => format!("id:{}", id),
+        None => format!("link:{}|{}", canonical_link, normalize_text(title)),
+    }
+}
+
+/// Whether two stored jobs are records of the same posting, e.g. legacy one-per-location rows
+pub fn same_posting(a: &Job, b: &Job) -> bool {
+    posting_key(&a.external_id, a.canonical_link(), &a.title)
+        == posting_key(&b.external_id, b.canonical_link(), &b.title)
+}
+
+/// Trims scraped jobs and merges rows that describe the same posting in different locations
+///
+/// The merged job lists every location in `locations` and joins them for its display `location`.
+pub fn consolidate_scraped_jobs(scraped_jobs: Vec<ScrapedJob>) -> Vec<ScrapedJob> {
+    let mut consolidated: Vec<ScrapedJob> = Vec::new();
+    let mut index_by_key: HashMap<String, usize> = HashMap::new();
+
+    for sj in scraped_jobs {
+        let raw_locations = if sj.locations.is_empty() {
+            vec![sj.location.clone()]
+        } else {
+            sj.locations.clone()
+        };
+
+        let locations: Vec<String> = raw_locations
+            .iter()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect();
+
+        let scraped = ScrapedJob {
+            title: sj.title.trim().to_string(),
+            location: sj.location.trim().to_string(),
+            link: sj.link.trim().to_string(),
+            external_id: sj
+                .external_id
+                .map(|id| id.trim().to_string())
+                .filter(|id| !id.is_empty()),
+            locations: Vec::new(),
+            metadata: sj.metadata,
+        };
+
+        let key = posting_key(&scraped.external_id, scraped.canonical_link(), &scraped.title);
+
+        let idx = *index_by_key.entry(key).or_insert_with(|| {
+            consolidated.push(scraped);
+            consolidated.len() - 1
+        });
+
+        let entry = &mut consolidated[idx];
+        for location in locations {
+            if !entry.locations.contains(&location) {
+                entry.locations.push(location);
+            }
+        }
+    }
+
+    for sj in consolidated.iter_mut() {
+        sj.location = sj.locations.join(" | ");
+
+        // INFO: Fall back to the locations when the source has no workplace type of its own
+        if sj.metadata.remote.is_none()
+            && sj.locations.iter().any(|l| l.to_lowercase().contains("remote"))
+        {
+            sj.metadata.remote = Some(true);
+        }
+    }
+
+    consolidated
+}
+
+/// Pairs every scraped job with the stored job it represents, if any
+///
+/// Strong identity (external id, then canonical link) is resolved for every scraped job before
+/// falling back to fuzzy title/location matching, and a stored job can only be claimed once, so
+/// two distinct reqs sharing a title and location never collapse into one.
+pub fn match_scraped_jobs(existing: &[Job], scraped: &[ScrapedJob]) -> Vec<Option<usize>> {
+    let mut matches: Vec<Option<usize>> = vec![None; scraped.len()];
+    let mut claimed: HashSet<usize> = HashSet::new();
+
+    // Strong matches, preferring the candidate in the same location when a posting spans several
+    for (si, sj) in scraped.iter().enumerate() {
+        let candidates: Vec<usize> = existing
+            .iter()
+            .enumerate()
+            .filter(|(ei, j)| !claimed.contains(ei) && strong_match(j, sj))
+            .map(|(ei, _)| ei)
+            .collect();
+
+        let chosen = candidates
+            .iter()
+            .find(|&&ei| same_location(&existing[ei], sj))
+            .or(candidates.first())
+            .copied();
+
+        if let Some(ei) = chosen {
+            claimed.insert(ei);
+            matches[si] = Some(ei);
+        }
+    }
+
+    // Fuzzy fallback for whatever strong identity could not place
+    for (si, sj) in scraped.iter().enumerate() {
+        if matches[si].is_some() {
+            continue;
+        }
+
+        let best = existing
+            .iter()
+            .enumerate()
+            .filter(|(ei, _)| !claimed.contains(ei))
+            .filter_map(|(ei, j)| fuzzy_score(j, sj).map(|score| (ei, score)))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((ei, _)) = best {
+            claimed.insert(ei);
+            matches[si] = Some(ei);
+        }
+    }
+
+    matches
+}
+
+#[cfg(test)]
+mod test {
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn job(title: &str, location: &str, link: &str, external_id: Option<&str>) -> Job {
+        Job {
+            id: Uuid::new_v4(),
+            title: title.to_string(),
+            location: location.to_string(),
+            link: link.to_string(),
+            external_id: external_id.map(String::from),
+            ..Default::default()
+        }
+    }
+
+    fn scraped(title: &str, location: &str, link: &str, external_id: Option<&str>) -> ScrapedJob {
+        ScrapedJob {
+            title: title.to_string(),
+            location: location.to_string(),
+            link: link.to_string(),
+            external_id: external_id.map(String::from),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_consolidate_multi_location_posting() {
+        let consolidated = consolidate_scraped_jobs(vec![
+            scraped("Software Engineer", "Menlo Park, CA", "https://x.com/1", Some("1")),
+            scraped("Software Engineer", " Seattle, WA ", "https://x.com/1", Some("1")),
+            scraped("Software Engineer", "Menlo Park, CA", "https://x.com/1", Some("1")),
+            scraped("Software Engineer", "Menlo Park, CA", "https://x.com/2", Some("2")),
+        ]);
+
+        assert_eq!(consolidated.len(), 2);
+        assert_eq!(consolidated[0].locations, vec!["Menlo Park, CA", "Seattle, WA"]);
+        assert_eq!(consolidated[0].location, "Menlo Park, CA | Seattle, WA");
+        assert_eq!(consolidated[1].locations, vec!["Menlo Park, CA"]);
+    }
+
+    #[test]
+    fn test_canonicalize_link() {
+        assert_eq!(
+            canonicalize_link("https://www.Example.com/jobs/123/?utm_source=x&gh_jid=9#apply"),
+            "example.com/jobs/123?gh_jid=9"
+        );
+        assert_eq!(
+            canonicalize_link("https://example.com/jobs/123"),
+            canonicalize_link("http://example.com/jobs/123/?gh_src=abc")
+        );
+    }
+
+    #[test]
+    fn test_retitled_posting_keeps_identity() {
+        let existing = vec![job("Software Engineer", "Remote", "https://x.com/jobs/1", None)];
+        let scraped = vec![scraped(
+            "Software Engineer, Payments",
+            "Remote",
+            "https://x.com/jobs/1?utm_campaign=a",
+            None,
+        )];
+
+        assert_eq!(match_scraped_jobs(&existing, &scraped), vec![Some(0)]);
+    }
+
+    #[test]
+    fn test_distinct_reqs_with_same_title_are_not_merged() {
+        let existing = vec![
+            job("Software Engineer", "NYC", "https://x.com/jobs/1", Some("1")),
+            job("Software Engineer", "NYC", "https://x.com/jobs/2", Some("2")),
+        ];
+        let scraped = vec![
+            scraped("Software Engineer", "NYC", "https://x.com/jobs/2", Some("2")),
+            scraped("Software Engineer", "NYC", "https://x.com/jobs/3", Some("3")),
+        ];
+
+        assert_eq!(match_scraped_jobs(&existing, &scraped), vec![Some(1), None]);
+    }
+
+    #[test]
+    fn test_fuzzy_fallback_when_link_changes() {
+        let existing = vec![job("Sr. Software Engineer", "Austin, TX", "https://old/1", None)];
+        let scraped = vec![scraped("Sr Software Engineer", "Austin TX", "https://new/1", None)];
+
+        assert_eq!(match_scraped_jobs(&existing, &scraped), vec![Some(0)]);
+    }
+}
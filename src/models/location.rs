@@ -0,0 +1,494 @@
+use std::{collections::HashMap, fmt, sync::LazyLock};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter};
+
+// INFO: Countries, US states, Canadian provinces and the cities the tracked companies hire in
+static GAZETTEER: LazyLock<Gazetteer> =
+    LazyLock::new(|| Gazetteer::load(include_str!("../geo/gazetteer.json")));
+
+// INFO: Lowercase only on purpose, "Portland, OR" must not be split
+static MULTI_LOCATION_SEPARATOR_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\s*(?:/|;|\||\n|\s+or\s+)\s*").unwrap());
+
+static PART_SEPARATOR_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\s+[-–—]\s+|[,()\[\]:]").unwrap());
+
+static HYBRID_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\bhybrid\b").unwrap());
+
+static REMOTE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:remote|anywhere|work from home|wfh|virtual|distributed)\b").unwrap()
+});
+
+static ONSITE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(?:on-?site|on site|in-?office)\b").unwrap());
+
+#[derive(Debug, Deserialize)]
+struct CountryEntry {
+    name: String,
+    code: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RegionEntry {
+    name: String,
+    code: String,
+    country: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CityEntry {
+    name: String,
+    #[serde(default)]
+    region: Option<String>,
+    country: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Gazetteer {
+    countries: Vec<CountryEntry>,
+    regions: Vec<RegionEntry>,
+    cities: Vec<CityEntry>,
+    // INFO: Lowercase names, aliases and codes to indexes into the lists above
+    #[serde(skip)]
+    country_index: HashMap<String, usize>,
+    #[serde(skip)]
+    region_index: HashMap<String, usize>,
+    #[serde(skip)]
+    city_index: HashMap<String, usize>,
+}
+
+impl Gazetteer {
+    fn load(raw: &str) -> Self {
+        let mut gazetteer: Gazetteer = serde_json::from_str(raw).expect("Invalid gazetteer.json");
+
+        for (i, c) in gazetteer.countries.iter().enumerate() {
+            for key in [&c.name, &c.code].into_iter().chain(c.aliases.iter()) {
+                gazetteer.country_index.insert(key.to_lowercase(), i);
+            }
+        }
+
+        // Region codes are only unique within a country, names are the only global key
+        for (i, r) in gazetteer.regions.iter().enumerate() {
+            for key in std::iter::once(&r.name).chain(r.aliases.iter()) {
+                gazetteer.region_index.insert(key.to_lowercase(), i);
+            }
+        }
+
+        for (i, c) in gazetteer.cities.iter().enumerate() {
+            for key in std::iter::once(&c.name).chain(c.aliases.iter()) {
+                gazetteer.city_index.insert(key.to_lowercase(), i);
+            }
+        }
+
+        gazetteer
+    }
+
+    fn country_by_key(&self, key: &str) -> Option<&CountryEntry> {
+        self.country_index
+            .get(&key.to_lowercase())
+            .map(|&i| &self.countries[i])
+    }
+
+    fn country(&self, code: &str) -> Option<&CountryEntry> {
+        self.countries.iter().find(|c| c.code == code)
+    }
+
+    fn region_by_name(&self, key: &str) -> Option<&RegionEntry> {
+        self.region_index
+            .get(&key.to_lowercase())
+            .map(|&i| &self.regions[i])
+    }
+
+    /// A region by its code, limited to one country when it is known
+    fn region_by_code(&self, code: &str, country: Option<&str>) -> Option<&RegionEntry> {
+        self.regions
+            .iter()
+            .find(|r| r.code.eq_ignore_ascii_case(code) && country.is_none_or(|c| c == r.country))
+    }
+
+    fn city_by_key(&self, key: &str) -> Option<&CityEntry> {
+        self.city_index
+            .get(&key.to_lowercase())
+            .map(|&i| &self.cities[i])
+    }
+}
+
+#[derive(Debug, Display, EnumIter, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WorkArrangement {
+    #[strum(to_string = "Remote")]
+    Remote,
+    #[strum(to_string = "Hybrid")]
+    Hybrid,
+    #[strum(to_string = "On-site")]
+    Onsite,
+}
+
+/// One location of a posting resolved against the gazetteer
+///
+/// Region and country are codes ("CA", "US"), the city is kept as written when the gazetteer doesn't know it.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NormalizedLocation {
+    pub city: Option<String>,
+    pub region: Option<String>,
+    pub country: Option<String>,
+    pub arrangement: Option<WorkArrangement>,
+}
+
+impl NormalizedLocation {
+    pub fn is_empty(&self) -> bool {
+        self.city.is_none()
+            && self.region.is_none()
+            && self.country.is_none()
+            && self.arrangement.is_none()
+    }
+
+    pub fn country_name(&self) -> Option<String> {
+        let code = self.country.as_deref()?;
+
+        GAZETTEER.country(code).map(|c| c.name.clone())
+    }
+
+    pub fn region_name(&self) -> Option<String> {
+        let code = self.region.as_deref()?;
+
+        GAZETTEER
+            .region_by_code(code, self.country.as_deref())
+            .map(|r| r.name.clone())
+    }
+
+    /// e.g. "Remote · United States" or "San Francisco, California, United States"
+    pub fn label(&self) -> String {
+        let place = [self.city.clone(), self.region_name(), self.country_name()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        match (self.arrangement, place.is_empty()) {
+            (Some(arrangement), true) => arrangement.to_string(),
+            (Some(arrangement), false) => format!("{} · {}", arrangement, place),
+            (None, _) => place,
+        }
+    }
+
+    fn set_city(&mut self, city: &CityEntry) {
+        if self.country.as_ref().is_some_and(|c| *c != city.country) {
+            return;
+        }
+
+        self.city = Some(city.name.clone());
+        self.region = self.region.take().or(city.region.clone());
+        self.country = Some(city.country.clone());
+    }
+
+    fn set_region(&mut self, region: &RegionEntry) {
+        if let Some(current) = &self.region {
+            // "Washington, DC" and "New York, NY": the first region was really a city name
+            if *current != region.code && self.city.is_none() {
+                self.city = GAZETTEER
+                    .region_by_code(current, self.country.as_deref())
+                    .map(|r| r.name.clone());
+            } else {
+                return;
+            }
+        }
+
+        self.region = Some(region.code.clone());
+        self.country = Some(region.country.clone());
+    }
+}
+
+fn arrangement_from_text(raw: &str) -> Option<WorkArrangement> {
+    if HYBRID_RE.is_match(raw) {
+        Some(WorkArrangement::Hybrid)
+    } else if REMOTE_RE.is_match(raw) {
+        Some(WorkArrangement::Remote)
+    } else if ONSITE_RE.is_match(raw) {
+        Some(WorkArrangement::Onsite)
+    } else {
+        None
+    }
+}
+
+fn normalize_single_location(raw: &str) -> NormalizedLocation {
+    let mut location = NormalizedLocation {
+        arrangement: arrangement_from_text(raw),
+        ..Default::default()
+    };
+
+    let without_arrangement = [&*HYBRID_RE, &*REMOTE_RE, &*ONSITE_RE]
+        .into_iter()
+        .fold(raw.to_string(), |text, re| {
+            re.replace_all(&text, ",").to_string()
+        });
+
+    let tokens: Vec<&str> = PART_SEPARATOR_RE
+        .split(&without_arrangement)
+        .map(|t| t.trim_matches(|c: char| c.is_whitespace() || "-–—*".contains(c)))
+        .filter(|t| !t.is_empty())
+        .collect();
+
+    // INFO: The first token the gazetteer doesn't know, most likely a smaller city
+    let mut unknown_token: Option<&str> = None;
+
+    for token in tokens {
+        let is_code = token.len() == 2 && token.chars().all(|c| c.is_ascii_alphabetic());
+
+        if let Some(city) = GAZETTEER.city_by_key(token) {
+            if location.city.is_none() {
+                location.set_city(city);
+            }
+        } else if is_code {
+            let country = GAZETTEER.country_by_key(token);
+            let region = GAZETTEER.region_by_code(token, location.country.as_deref());
+            let after_place = location.city.is_some() || unknown_token.is_some();
+
+            // Codes like "CA", "IN" or "DE" are both a region and a country, context decides
+            match (region, country) {
+                (_, Some(c)) if location.country.as_deref() == Some(c.code.as_str()) => {}
+                (Some(r), _)
+                    if location.region.is_none() && (after_place || location.country.is_some()) =>
+                {
+                    location.set_region(r)
+                }
+                (_, Some(c)) if location.country.is_none() => {
+                    location.country = Some(c.code.clone())
+                }
+                (Some(r), None) => location.set_region(r),
+                _ => {}
+            }
+        } else if let Some(region) = GAZETTEER.region_by_name(token) {
+            location.set_region(region);
+        } else if let Some(country) = GAZETTEER.country_by_key(token) {
+            if location.country.is_none() {
+                location.country = Some(country.code.clone());
+            }
+        } else if unknown_token.is_none() && location.city.is_none() {
+            unknown_token = Some(token);
+        }
+    }
+
+    if location.city.is_none() && (location.region.is_some() || location.country.is_some()) {
+        location.city = unknown_token.map(|t| t.to_string());
+    }
+
+    location
+}
+
+/// Resolves free-text locations like "SF / NYC", "Remote, United States" or "California"
+///
+/// Returns one entry per location mentioned, parts the gazetteer can't place are dropped.
+pub fn normalize_location(raw: &str) -> Vec<NormalizedLocation> {
+    MULTI_LOCATION_SEPARATOR_RE
+        .split(raw)
+        .map(normalize_single_location)
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+/// A location to search for, written as "US", "US-CA", "remote" or "remote-US"
+#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq, Eq, Hash)]
+pub struct LocationQuery {
+    #[serde(default)]
+    pub remote: bool,
+    #[serde(default)]
+    pub country: Option<String>,
+    #[serde(default)]
+    pub region: Option<String>,
+}
+
+impl LocationQuery {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        let lowercase = input.to_lowercase();
+
+        let (remote, place) = match lowercase.strip_prefix("remote") {
+            Some(rest) => (
+                true,
+                rest.trim_matches(|c: char| c == '-' || c == ',' || c.is_whitespace()),
+            ),
+            None => (false, lowercase.as_str()),
+        };
+
+        if place.is_empty() {
+            return if remote {
+                Ok(LocationQuery {
+                    remote,
+                    ..Default::default()
+                })
+            } else {
+                Err("Enter a location, e.g. US, US-CA or remote-US".to_string())
+            };
+        }
+
+        if let Some(country) = GAZETTEER.country_by_key(place) {
+            return Ok(LocationQuery {
+                remote,
+                country: Some(country.code.clone()),
+                region: None,
+            });
+        }
+
+        let region = match place.split_once('-') {
+            Some((country, region)) => GAZETTEER
+                .country_by_key(country.trim())
+                .and_then(|c| GAZETTEER.region_by_code(region.trim(), Some(&c.code))),
+            None => GAZETTEER.region_by_name(place),
+        };
+
+        region
+            .map(|r| LocationQuery {
+                remote,
+                country: Some(r.country.clone()),
+                region: Some(r.code.clone()),
+            })
+            .ok_or_else(|| format!("Unknown location \"{}\"", input))
+    }
+
+    /// The most specific query a location belongs to, used to group locations in pickers
+    pub fn for_location(location: &NormalizedLocation) -> Option<Self> {
+        let remote = location.arrangement == Some(WorkArrangement::Remote);
+
+        if location.country.is_none() && !remote {
+            return None;
+        }
+
+        Some(LocationQuery {
+            remote,
+            country: location.country.clone(),
+            region: location.region.clone().filter(|_| !remote),
+        })
+    }
+
+    pub fn matches(&self, location: &NormalizedLocation) -> bool {
+        if self.remote && location.arrangement != Some(WorkArrangement::Remote) {
+            return false;
+        }
+
+        if self.country.is_some() && location.country != self.country {
+            return false;
+        }
+
+        self.region.is_none() || location.region == self.region
+    }
+
+    /// e.g. "Remote · United States" or "California, United States"
+    pub fn label(&self) -> String {
+        NormalizedLocation {
+            city: None,
+            region: self.region.clone(),
+            country: self.country.clone(),
+            arrangement: self.remote.then_some(WorkArrangement::Remote),
+        }
+        .label()
+    }
+}
+
+impl fmt::Display for LocationQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let place = [self.country.clone(), self.region.clone()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<String>>()
+            .join("-");
+
+        match (self.remote, place.is_empty()) {
+            (true, true) => write!(f, "remote"),
+            (true, false) => write!(f, "remote-{}", place),
+            (false, _) => write!(f, "{}", place),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn single(raw: &str) -> NormalizedLocation {
+        let mut locations = normalize_location(raw);
+        assert_eq!(locations.len(), 1, "{}", raw);
+        locations.remove(0)
+    }
+
+    fn code(value: &Option<String>) -> Option<&str> {
+        value.as_deref()
+    }
+
+    #[test]
+    fn test_normalize_location() {
+        let sf = single("San Francisco, CA");
+        assert_eq!(code(&sf.city), Some("San Francisco"));
+        assert_eq!(code(&sf.region), Some("CA"));
+        assert_eq!(code(&sf.country), Some("US"));
+
+        let remote = single("Remote, United States");
+        assert_eq!(remote.arrangement, Some(WorkArrangement::Remote));
+        assert_eq!(code(&remote.country), Some("US"));
+        assert_eq!(remote.label(), "Remote · United States");
+
+        let us_remote = single("US-Remote");
+        assert_eq!(us_remote.arrangement, Some(WorkArrangement::Remote));
+        assert_eq!(code(&us_remote.country), Some("US"));
+
+        // Disney collapses multi-location postings to the state name
+        let state = single("California");
+        assert_eq!(code(&state.region), Some("CA"));
+        assert_eq!(code(&state.country), Some("US"));
+
+        let dc = single("Washington, DC");
+        assert_eq!(code(&dc.region), Some("DC"));
+        assert_eq!(code(&dc.city), Some("Washington"));
+
+        let small_city = single("Springfield, IL");
+        assert_eq!(code(&small_city.city), Some("Springfield"));
+        assert_eq!(code(&small_city.region), Some("IL"));
+
+        let toronto = single("Toronto, ON, CA - Hybrid");
+        assert_eq!(code(&toronto.country), Some("CA"));
+        assert_eq!(code(&toronto.region), Some("ON"));
+        assert_eq!(toronto.arrangement, Some(WorkArrangement::Hybrid));
+
+        assert_eq!(code(&single("Bengaluru, India").country), Some("IN"));
+        assert_eq!(code(&single("Berlin, DE").country), Some("DE"));
+
+        let both = normalize_location("SF / NYC");
+        assert_eq!(both.len(), 2);
+        assert_eq!(code(&both[1].city), Some("New York"));
+
+        assert_eq!(normalize_location("Portland, OR").len(), 1);
+        assert!(normalize_location("3 Locations").is_empty());
+    }
+
+    #[test]
+    fn test_location_query() {
+        let remote_us = LocationQuery::parse("remote-US").unwrap();
+        assert!(remote_us.matches(&single("Remote, United States")));
+        assert!(!remote_us.matches(&single("Seattle, WA")));
+        assert!(!remote_us.matches(&single("Remote - Canada")));
+        assert_eq!(remote_us.to_string(), "remote-US");
+
+        let california = LocationQuery::parse("us-ca").unwrap();
+        assert!(california.matches(&single("Menlo Park, CA")));
+        assert!(!california.matches(&single("Austin, TX")));
+        assert_eq!(california.label(), "California, United States");
+
+        assert_eq!(
+            LocationQuery::parse("Germany").unwrap().country.as_deref(),
+            Some("DE")
+        );
+        assert_eq!(
+            LocationQuery::parse("texas").unwrap().region.as_deref(),
+            Some("TX")
+        );
+        assert!(LocationQuery::parse("remote").unwrap().country.is_none());
+        assert!(LocationQuery::parse("atlantis").is_err());
+    }
+}
@@ -0,0 +1,282 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum_macros::{Display, EnumIter};
+
+use super::{description::unescape_html, salary::salary_text};
+
+#[derive(Debug, Display, EnumIter, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq)]
+pub enum EmploymentType {
+    #[strum(to_string = "Full-time")]
+    FullTime,
+    #[strum(to_string = "Part-time")]
+    PartTime,
+    #[strum(to_string = "Contract")]
+    Contract,
+    #[strum(to_string = "Internship")]
+    Internship,
+    #[strum(to_string = "Temporary")]
+    Temporary,
+}
+
+impl EmploymentType {
+    /// Maps the many spellings ATSs use ("FULL_TIME", "Full-Time", "Regular", ...) to one variant
+    pub fn parse(raw: &str) -> Option<Self> {
+        let normalized: String = raw
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect();
+
+        match normalized.as_str() {
+            "fulltime" | "regular" | "permanent" | "regularfulltime" | "fulltimeregular" => {
+                Some(EmploymentType::FullTime)
+            }
+            "parttime" | "regularparttime" => Some(EmploymentType::PartTime),
+            "contract" | "contractor" | "fixedterm" | "freelance" => Some(EmploymentType::Contract),
+            "intern" | "internship" | "coop" => Some(EmploymentType::Internship),
+            "temporary" | "temp" | "seasonal" => Some(EmploymentType::Temporary),
+            _ => None,
+        }
+    }
+}
+
+/// Extra facts about a posting that some sources provide, every field is optional
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+pub struct JobMetadata {
+    #[serde(default)]
+    pub posted_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub department: Option<String>,
+    #[serde(default)]
+    pub employment_type: Option<EmploymentType>,
+    #[serde(default)]
+    pub remote: Option<bool>,
+    // INFO: Compensation exactly as the source printed it, e.g. "$150,000 - $210,000 USD"
+    #[serde(default)]
+    pub salary_range: Option<String>,
+}
+
+impl JobMetadata {
+    pub fn posted_label(&self) -> String {
+        self.posted_at
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn remote_label(&self) -> String {
+        match self.remote {
+            Some(true) => "Remote".to_string(),
+            Some(false) => "On-site".to_string(),
+            None => String::new(),
+        }
+    }
+
+    pub fn employment_type_label(&self) -> String {
+        self.employment_type
+            .map(|t| t.to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// A non-empty, trimmed string field
+pub fn string_from_value(value: &Value) -> Option<String> {
+    value
+        .as_str()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Reads a posted/created date in whatever shape the source uses
+///
+/// Handles RFC 3339 timestamps, naive timestamps, plain dates, and epoch seconds or millis.
+pub fn parse_posted_date(value: &Value) -> Option<DateTime<Utc>> {
+    match value {
+        Value::Number(n) => {
+            let n = n.as_i64()?;
+            // INFO: Anything this large is epoch millis, seconds won't get there for a long time
+            if n > 100_000_000_000 {
+                Utc.timestamp_millis_opt(n).single()
+            } else {
+                Utc.timestamp_opt(n, 0).single()
+            }
+        }
+        Value::String(s) => parse_posted_date_str(s),
+        _ => None,
+    }
+}
+
+fn parse_posted_date_str(raw: &str) -> Option<DateTime<Utc>> {
+    let raw = raw.trim();
+
+    if let Ok(d) = DateTime::parse_from_rfc3339(raw) {
+        return Some(d.with_timezone(&Utc));
+    }
+
+    // e.g. "2024-11-20T00:00:00+0000"
+    if let Ok(d) = DateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S%z") {
+        return Some(d.with_timezone(&Utc));
+    }
+
+    if let Ok(d) = NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Some(Utc.from_utc_datetime(&d));
+    }
+
+    NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|d| Utc.from_utc_datetime(&d))
+}
+
+/// Reads a workplace type ("remote", "ORA_HYBRID", "On-site", ...) as whether the job is fully remote
+pub fn remote_from_text(raw: &str) -> Option<bool> {
+    let normalized = raw.to_lowercase();
+
+    if normalized.contains("remote") {
+        Some(true)
+    } else if normalized.contains("site")
+        || normalized.contains("office")
+        || normalized.contains("hybrid")
+    {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// The pay range printed in a description, which can be HTML or plain text
+pub fn salary_from_description(description: &str) -> Option<String> {
+    salary_text(&unescape_html(description))
+}
+
+/// The first of a Greenhouse job's pay ranges, falling back to its description
+///
+/// Ranges come with `pay_transparency=true`, the description with `content=true`.
+fn greenhouse_salary_range(job: &Value) -> Option<String> {
+    let range = &job["pay_input_ranges"][0];
+
+    match (range["min_cents"].as_i64(), range["max_cents"].as_i64()) {
+        (Some(min), Some(max)) => Some(format!(
+            "{} - {} {}",
+            min / 100,
+            max / 100,
+            range["currency_type"].as_str().unwrap_or("USD")
+        )),
+        // INFO: Greenhouse entity-escapes the description HTML
+        _ => salary_from_description(&unescape_html(job["content"].as_str()?)),
+    }
+}
+
+/// Metadata from a Greenhouse job board API job
+pub fn greenhouse_metadata(job: &Value, department: Option<String>) -> JobMetadata {
+    JobMetadata {
+        posted_at: parse_posted_date(&job["first_published"])
+            .or_else(|| parse_posted_date(&job["updated_at"])),
+        department,
+        salary_range: greenhouse_salary_range(job),
+        ..Default::default()
+    }
+}
+
+/// Metadata from an Eightfold careers API position (Netflix, PayPal, CoStar, ...)
+pub fn eightfold_metadata(position: &Value) -> JobMetadata {
+    JobMetadata {
+        posted_at: parse_posted_date(&position["t_create"]),
+        department: string_from_value(&position["department"]),
+        remote: position["work_location_option"]
+            .as_str()
+            .and_then(remote_from_text),
+        salary_range: position["job_description"]
+            .as_str()
+            .and_then(salary_from_description),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_posted_date() {
+        let expected = Utc.with_ymd_and_hms(2024, 11, 20, 17, 0, 0).unwrap();
+
+        assert_eq!(
+            parse_posted_date(&json!("2024-11-20T12:00:00-05:00")),
+            Some(expected)
+        );
+        assert_eq!(
+            parse_posted_date(&json!("2024-11-20T17:00:00+0000")),
+            Some(expected)
+        );
+        assert_eq!(
+            parse_posted_date(&json!("2024-11-20T17:00:00.000")),
+            Some(expected)
+        );
+        assert_eq!(parse_posted_date(&json!(1732122000)), Some(expected));
+        assert_eq!(parse_posted_date(&json!(1732122000000_i64)), Some(expected));
+        assert_eq!(
+            parse_posted_date(&json!("2024-11-20")),
+            Some(Utc.with_ymd_and_hms(2024, 11, 20, 0, 0, 0).unwrap())
+        );
+        assert_eq!(parse_posted_date(&json!("last week")), None);
+        assert_eq!(parse_posted_date(&Value::Null), None);
+    }
+
+    #[test]
+    fn test_employment_type_and_remote() {
+        assert_eq!(
+            EmploymentType::parse("FULL_TIME"),
+            Some(EmploymentType::FullTime)
+        );
+        assert_eq!(
+            EmploymentType::parse("Full-Time"),
+            Some(EmploymentType::FullTime)
+        );
+        assert_eq!(
+            EmploymentType::parse("Intern"),
+            Some(EmploymentType::Internship)
+        );
+        assert_eq!(EmploymentType::parse("Whatever"), None);
+
+        assert_eq!(remote_from_text("ORA_REMOTE"), Some(true));
+        assert_eq!(remote_from_text("Hybrid"), Some(false));
+        assert_eq!(remote_from_text("On-site"), Some(false));
+        assert_eq!(remote_from_text("Menlo Park, CA"), None);
+    }
+
+    #[test]
+    fn test_salary_range_from_payloads() {
+        let with_ranges = json!({
+            "pay_input_ranges": [
+                { "min_cents": 15000000, "max_cents": 21000000, "currency_type": "USD" }
+            ]
+        });
+        assert_eq!(
+            greenhouse_metadata(&with_ranges, None).salary_range.as_deref(),
+            Some("150000 - 210000 USD")
+        );
+
+        let with_content = json!({
+            "content": "&lt;p&gt;The base pay range is &lt;strong&gt;$150,000 - $210,000&lt;/strong&gt; USD.&lt;/p&gt;"
+        });
+        assert_eq!(
+            greenhouse_metadata(&with_content, None).salary_range.as_deref(),
+            Some("$150,000 - $210,000 USD")
+        );
+
+        let position = json!({
+            "job_description": "<p>5+ years of Go.</p><p>The market range is $200,000 - $400,000.</p>"
+        });
+        assert_eq!(
+            eightfold_metadata(&position).salary_range.as_deref(),
+            Some("$200,000 - $400,000")
+        );
+
+        assert_eq!(greenhouse_metadata(&json!({}), None).salary_range, None);
+        assert_eq!(eightfold_metadata(&json!({})).salary_range, None);
+    }
+}
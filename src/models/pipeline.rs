@@ -0,0 +1,125 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter};
+
+#[derive(
+    Debug, Display, EnumIter, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash,
+)]
+pub enum ApplicationStatus {
+    #[strum(to_string = "Interested")]
+    Interested,
+    #[strum(to_string = "Applied")]
+    Applied,
+    #[strum(to_string = "Recruiter Screen")]
+    RecruiterScreen,
+    #[strum(to_string = "Technical")]
+    Technical,
+    #[strum(to_string = "Onsite")]
+    Onsite,
+    #[strum(to_string = "Offer")]
+    Offer,
+    #[strum(to_string = "Rejected")]
+    Rejected,
+    #[strum(to_string = "Withdrawn")]
+    Withdrawn,
+    #[strum(to_string = "Ghosted")]
+    Ghosted,
+}
+
+impl ApplicationStatus {
+    /// Every stage past `Interested` means an application went out
+    pub fn counts_as_applied(&self) -> bool {
+        !matches!(self, ApplicationStatus::Interested)
+    }
+
+    /// Stages where the process is over one way or another
+    pub fn is_closed(&self) -> bool {
+        matches!(
+            self,
+            ApplicationStatus::Rejected | ApplicationStatus::Withdrawn | ApplicationStatus::Ghosted
+        )
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            ApplicationStatus::Interested => "⭐",
+            ApplicationStatus::Applied => "📨",
+            ApplicationStatus::RecruiterScreen => "📞",
+            ApplicationStatus::Technical => "💻",
+            ApplicationStatus::Onsite => "🏢",
+            ApplicationStatus::Offer => "🎉",
+            ApplicationStatus::Rejected => "❌",
+            ApplicationStatus::Withdrawn => "↩️",
+            ApplicationStatus::Ghosted => "👻",
+        }
+    }
+}
+
+/// One entry in a job's application timeline
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+pub struct StatusChange {
+    pub status: ApplicationStatus,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Appends a transition to the timeline unless the job is already in that stage
+///
+/// Returns whether the timeline changed.
+pub fn record_status_change(
+    timeline: &mut Vec<StatusChange>,
+    status: ApplicationStatus,
+    now: DateTime<Utc>,
+) -> bool {
+    if timeline.last().map(|c| c.status) == Some(status) {
+        return false;
+    }
+
+    timeline.push(StatusChange {
+        status,
+        changed_at: now,
+    });
+
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_record_status_change() {
+        let now = Utc::now();
+        let mut timeline = Vec::new();
+
+        assert!(record_status_change(
+            &mut timeline,
+            ApplicationStatus::Applied,
+            now
+        ));
+        assert!(!record_status_change(
+            &mut timeline,
+            ApplicationStatus::Applied,
+            now + Duration::days(1)
+        ));
+        assert!(record_status_change(
+            &mut timeline,
+            ApplicationStatus::Technical,
+            now + Duration::days(7)
+        ));
+
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline[1].status, ApplicationStatus::Technical);
+        assert_eq!(timeline[0].changed_at, now);
+    }
+
+    #[test]
+    fn test_status_flags() {
+        assert!(!ApplicationStatus::Interested.counts_as_applied());
+        assert!(ApplicationStatus::Ghosted.counts_as_applied());
+        assert!(ApplicationStatus::Rejected.is_closed());
+        assert!(!ApplicationStatus::Offer.is_closed());
+    }
+}
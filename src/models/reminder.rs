@@ -0,0 +1,189 @@
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// INFO: How far ahead the agenda looks for upcoming reminders
+pub const AGENDA_HORIZON_DAYS: i64 = 14;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub enum ReminderTarget {
+    Job {
+        company: String,
+        job_id: Uuid,
+    },
+    // INFO: Connections have no id, they are identified by company and name like everywhere else
+    Connection {
+        company: String,
+        first_name: String,
+        last_name: String,
+    },
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Reminder {
+    pub id: Uuid,
+    pub target: ReminderTarget,
+    // INFO: Human readable label of the target captured at creation, e.g. "Software Engineer @ Meta"
+    pub subject: String,
+    pub message: String,
+    pub due_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Utc>>,
+    // INFO: Set once Discord mode delivered the reminder so it isn't posted on every tick
+    #[serde(default)]
+    pub notified_at: Option<DateTime<Utc>>,
+}
+
+impl Reminder {
+    pub fn new(
+        target: ReminderTarget,
+        subject: String,
+        message: String,
+        due_at: DateTime<Utc>,
+    ) -> Self {
+        Reminder {
+            id: Uuid::new_v4(),
+            target,
+            subject,
+            message,
+            due_at,
+            created_at: Utc::now(),
+            completed_at: None,
+            notified_at: None,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.completed_at.is_none()
+    }
+
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.is_open() && self.due_at <= now
+    }
+
+    pub fn display_string(&self) -> String {
+        format!(
+            "{} | {} | {}",
+            self.due_at.format("%Y-%m-%d %H:%M"),
+            self.subject,
+            self.message
+        )
+    }
+}
+
+/// Open reminders split into overdue ones and ones due within the agenda horizon, both by due date
+pub struct Agenda {
+    pub overdue: Vec<Reminder>,
+    pub upcoming: Vec<Reminder>,
+}
+
+impl Agenda {
+    pub fn build(reminders: &[Reminder], now: DateTime<Utc>) -> Self {
+        let horizon = now + Duration::days(AGENDA_HORIZON_DAYS);
+
+        let mut open: Vec<Reminder> = reminders.iter().filter(|r| r.is_open()).cloned().collect();
+        open.sort_by_key(|r| r.due_at);
+
+        let (overdue, upcoming): (Vec<Reminder>, Vec<Reminder>) = open
+            .into_iter()
+            .filter(|r| r.due_at <= horizon)
+            .partition(|r| r.is_due(now));
+
+        Agenda { overdue, upcoming }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.overdue.is_empty() && self.upcoming.is_empty()
+    }
+}
+
+/// Parses a due date typed by the user, either relative ("3d", "2w", "12h") or a date ("2025-01-31")
+///
+/// Dates resolve to 9am UTC of that day.
+pub fn parse_due_date(input: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    let input = input.trim().to_lowercase();
+
+    if let Ok(date) = NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+        let naive = date.and_hms_opt(9, 0, 0).unwrap();
+        return Ok(Utc.from_utc_datetime(&naive));
+    }
+
+    let split_at = input
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| format!("Missing unit in \"{}\", use h, d or w", input))?;
+    let (amount, unit) = input.split_at(split_at);
+
+    let amount: i64 = amount
+        .parse()
+        .map_err(|_| format!("Invalid due date \"{}\"", input))?;
+
+    let duration = match unit {
+        "h" => Duration::hours(amount),
+        "d" => Duration::days(amount),
+        "w" => Duration::weeks(amount),
+        _ => return Err(format!("Unknown unit \"{}\", use h, d or w", unit)),
+    };
+
+    Ok(now + duration)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn reminder(due_at: DateTime<Utc>) -> Reminder {
+        Reminder::new(
+            ReminderTarget::Job {
+                company: "Meta".to_string(),
+                job_id: Uuid::new_v4(),
+            },
+            "Software Engineer @ Meta".to_string(),
+            "Follow up".to_string(),
+            due_at,
+        )
+    }
+
+    #[test]
+    fn test_parse_due_date() {
+        let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+
+        assert_eq!(parse_due_date("7d", now).unwrap(), now + Duration::days(7));
+        assert_eq!(
+            parse_due_date(" 2W ", now).unwrap(),
+            now + Duration::weeks(2)
+        );
+        assert_eq!(
+            parse_due_date("12h", now).unwrap(),
+            now + Duration::hours(12)
+        );
+        assert_eq!(
+            parse_due_date("2025-01-31", now).unwrap(),
+            Utc.with_ymd_and_hms(2025, 1, 31, 9, 0, 0).unwrap()
+        );
+        assert!(parse_due_date("7", now).is_err());
+        assert!(parse_due_date("soon", now).is_err());
+        assert!(parse_due_date("3y", now).is_err());
+    }
+
+    #[test]
+    fn test_agenda() {
+        let now = Utc::now();
+        let mut done = reminder(now - Duration::days(2));
+        done.completed_at = Some(now);
+
+        let reminders = vec![
+            reminder(now + Duration::days(3)),
+            reminder(now - Duration::days(1)),
+            reminder(now + Duration::days(1)),
+            reminder(now + Duration::days(AGENDA_HORIZON_DAYS + 1)),
+            done,
+        ];
+
+        let agenda = Agenda::build(&reminders, now);
+
+        assert_eq!(agenda.overdue.len(), 1);
+        assert_eq!(agenda.upcoming.len(), 2);
+        assert!(agenda.upcoming[0].due_at < agenda.upcoming[1].due_at);
+    }
+}
@@ -0,0 +1,409 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    time::Instant,
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use tabled::{settings::Style, Table, Tabled};
+use tracing::{info_span, Span};
+use uuid::Uuid;
+
+use crate::error::AppResult;
+
+use super::{data::Data, scraper::JobsPayload};
+
+// INFO: Older runs are dropped once the history grows past this, a few months of scans every 4 hours
+const MAX_RUNS: usize = 1000;
+
+/// What started a scan
+#[derive(Debug, Display, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RunTrigger {
+    #[strum(to_string = "CLI")]
+    Cli,
+    #[strum(to_string = "Menu")]
+    Menu,
+    #[strum(to_string = "TUI")]
+    Tui,
+    #[strum(to_string = "Cron")]
+    Cron,
+}
+
+/// How scraping one company went during a scan
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CompanyRun {
+    pub company: String,
+    pub started_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    /// Open jobs on the board
+    pub jobs: usize,
+    pub new_jobs: usize,
+    /// Postings that disappeared from the board
+    pub removed_jobs: usize,
+    pub is_baseline: bool,
+    pub error: Option<String>,
+}
+
+/// A scan of one or more companies, kept in `runs.jsonl` in the profile's data dir
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ScanRun {
+    pub id: Uuid,
+    pub trigger: RunTrigger,
+    /// The Discord mode schedule that started it, "default" or a group's name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    #[serde(default)]
+    pub cancelled: bool,
+    pub companies: Vec<CompanyRun>,
+}
+
+/// Measures one company's scrape, see `ScanRun::record`
+pub struct CompanyTimer {
+    company: String,
+    started_at: DateTime<Utc>,
+    instant: Instant,
+}
+
+impl CompanyTimer {
+    pub fn start(company: &str) -> Self {
+        CompanyTimer {
+            company: company.to_string(),
+            started_at: Utc::now(),
+            instant: Instant::now(),
+        }
+    }
+
+    pub fn finish(self, result: Result<&JobsPayload, String>) -> CompanyRun {
+        let mut company_run = CompanyRun {
+            company: self.company,
+            started_at: self.started_at,
+            duration_ms: self.instant.elapsed().as_millis() as u64,
+            jobs: 0,
+            new_jobs: 0,
+            removed_jobs: 0,
+            is_baseline: false,
+            error: None,
+        };
+
+        match result {
+            Ok(jobs_payload) => {
+                company_run.jobs = jobs_payload
+                    .all_jobs
+                    .iter()
+                    .filter(|j| !j.is_closed())
+                    .count();
+                company_run.new_jobs =
+                    jobs_payload.diff.new.len() + jobs_payload.diff.reposted.len();
+                company_run.removed_jobs = jobs_payload.diff.removed.len();
+                company_run.is_baseline = jobs_payload.diff.is_baseline;
+            }
+            Err(e) => company_run.error = Some(e),
+        }
+
+        company_run
+    }
+}
+
+impl ScanRun {
+    pub fn start(trigger: RunTrigger) -> Self {
+        let now = Utc::now();
+
+        ScanRun {
+            id: Uuid::new_v4(),
+            trigger,
+            schedule: None,
+            started_at: now,
+            finished_at: now,
+            cancelled: false,
+            companies: Vec::new(),
+        }
+    }
+
+    /// The span the scan's log lines go under, each company's scrape gets its own inside it
+    pub fn span(&self) -> Span {
+        info_span!(
+            "scan",
+            run = %self.id,
+            trigger = %self.trigger,
+            schedule = self.schedule.as_deref()
+        )
+    }
+
+    pub fn record(&mut self, company_run: CompanyRun) {
+        self.companies.push(company_run);
+    }
+
+    /// Stamps the end time and appends the run to the history
+    pub fn finish(&mut self) -> AppResult<()> {
+        self.finished_at = Utc::now();
+
+        // INFO: A detached data copy (tests, background scans) still has the real data dir
+        let path = Self::history_path();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        writeln!(file, "{}", serde_json::to_string(self)?)?;
+        drop(file);
+
+        let runs = Self::load_all();
+        if runs.len() > MAX_RUNS {
+            let kept: Vec<String> = runs[..MAX_RUNS]
+                .iter()
+                .rev()
+                .filter_map(|run| serde_json::to_string(run).ok())
+                .collect();
+            fs::write(&path, kept.join("\n") + "\n")?;
+        }
+
+        Ok(())
+    }
+
+    pub fn duration_ms(&self) -> u64 {
+        (self.finished_at - self.started_at)
+            .num_milliseconds()
+            .max(0) as u64
+    }
+
+    pub fn new_jobs(&self) -> usize {
+        self.companies.iter().map(|c| c.new_jobs).sum()
+    }
+
+    pub fn removed_jobs(&self) -> usize {
+        self.companies.iter().map(|c| c.removed_jobs).sum()
+    }
+
+    pub fn errors(&self) -> usize {
+        self.companies.iter().filter(|c| c.error.is_some()).count()
+    }
+
+    fn history_path() -> PathBuf {
+        Data::get_data_dir().join("runs.jsonl")
+    }
+
+    /// Every recorded run, newest first; lines that don't parse are skipped
+    pub fn load_all() -> Vec<ScanRun> {
+        let mut runs: Vec<ScanRun> = fs::read_to_string(Self::history_path())
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+
+        runs.reverse();
+        runs
+    }
+}
+
+/// How a company's scraper has been doing across runs, for spotting slow or flaky ones
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ScraperStats {
+    pub company: String,
+    pub runs: usize,
+    pub failures: usize,
+    pub avg_duration_ms: u64,
+    pub max_duration_ms: u64,
+    pub last_error: Option<String>,
+}
+
+impl ScraperStats {
+    /// One entry per company that appears in `runs`, most failures first, then slowest
+    pub fn from_runs(runs: &[ScanRun]) -> Vec<ScraperStats> {
+        let mut by_company: BTreeMap<&str, Vec<&CompanyRun>> = BTreeMap::new();
+
+        // `runs` is newest first, so the first error seen is the latest
+        for company_run in runs.iter().flat_map(|r| r.companies.iter()) {
+            by_company
+                .entry(company_run.company.as_str())
+                .or_default()
+                .push(company_run);
+        }
+
+        let mut stats: Vec<ScraperStats> = by_company
+            .into_iter()
+            .map(|(company, company_runs)| ScraperStats {
+                company: company.to_string(),
+                runs: company_runs.len(),
+                failures: company_runs.iter().filter(|c| c.error.is_some()).count(),
+                avg_duration_ms: company_runs.iter().map(|c| c.duration_ms).sum::<u64>()
+                    / company_runs.len() as u64,
+                max_duration_ms: company_runs
+                    .iter()
+                    .map(|c| c.duration_ms)
+                    .max()
+                    .unwrap_or(0),
+                last_error: company_runs.iter().find_map(|c| c.error.clone()),
+            })
+            .collect();
+
+        stats.sort_by(|a, b| {
+            b.failures
+                .cmp(&a.failures)
+                .then(b.avg_duration_ms.cmp(&a.avg_duration_ms))
+        });
+
+        stats
+    }
+
+    pub fn failure_rate(&self) -> f64 {
+        self.failures as f64 * 100.0 / self.runs as f64
+    }
+}
+
+/// One row per run, for `runs list` and the Scan History view
+pub fn runs_table(runs: &[ScanRun]) -> Table {
+    #[derive(Tabled)]
+    struct DisplayRun {
+        id: String,
+        started: String,
+        trigger: String,
+        companies: usize,
+        duration: String,
+        new: usize,
+        removed: usize,
+        errors: usize,
+    }
+
+    let rows: Vec<DisplayRun> = runs
+        .iter()
+        .map(|run| DisplayRun {
+            id: run.id.to_string()[..8].to_string(),
+            started: run.started_at.format("%Y-%m-%d %H:%M").to_string(),
+            trigger: match (&run.schedule, run.cancelled) {
+                (_, true) => format!("{} (cancelled)", run.trigger),
+                (Some(schedule), _) => format!("{} ({})", run.trigger, schedule),
+                (None, _) => run.trigger.to_string(),
+            },
+            companies: run.companies.len(),
+            duration: format_duration_ms(run.duration_ms()),
+            new: run.new_jobs(),
+            removed: run.removed_jobs(),
+            errors: run.errors(),
+        })
+        .collect();
+
+    let mut table = Table::new(rows);
+    table.with(Style::modern());
+    table
+}
+
+/// One row per company scraped in `run`
+pub fn company_runs_table(run: &ScanRun) -> Table {
+    #[derive(Tabled)]
+    struct DisplayCompanyRun {
+        company: String,
+        duration: String,
+        jobs: usize,
+        new: String,
+        removed: usize,
+        error: String,
+    }
+
+    let rows: Vec<DisplayCompanyRun> = run
+        .companies
+        .iter()
+        .map(|c| DisplayCompanyRun {
+            company: c.company.clone(),
+            duration: format_duration_ms(c.duration_ms),
+            jobs: c.jobs,
+            new: if c.is_baseline {
+                "baseline".to_string()
+            } else {
+                c.new_jobs.to_string()
+            },
+            removed: c.removed_jobs,
+            error: c.error.clone().unwrap_or_default(),
+        })
+        .collect();
+
+    let mut table = Table::new(rows);
+    table.with(Style::modern());
+    table
+}
+
+/// One row per company, see `ScraperStats::from_runs`
+pub fn scraper_stats_table(stats: &[ScraperStats]) -> Table {
+    #[derive(Tabled)]
+    struct DisplayStats {
+        company: String,
+        runs: usize,
+        failures: String,
+        average: String,
+        slowest: String,
+        last_error: String,
+    }
+
+    let rows: Vec<DisplayStats> = stats
+        .iter()
+        .map(|s| DisplayStats {
+            company: s.company.clone(),
+            runs: s.runs,
+            failures: format!("{} ({:.0}%)", s.failures, s.failure_rate()),
+            average: format_duration_ms(s.avg_duration_ms),
+            slowest: format_duration_ms(s.max_duration_ms),
+            last_error: s.last_error.clone().unwrap_or_default(),
+        })
+        .collect();
+
+    let mut table = Table::new(rows);
+    table.with(Style::modern());
+    table
+}
+
+/// Milliseconds as e.g. "850ms", "12.4s" or "3m 05s"
+pub fn format_duration_ms(ms: u64) -> String {
+    match ms {
+        0..=999 => format!("{}ms", ms),
+        1000..=59_999 => format!("{:.1}s", ms as f64 / 1000.0),
+        _ => format!("{}m {:02}s", ms / 60_000, (ms % 60_000) / 1000),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn company_run(company: &str, duration_ms: u64, error: Option<&str>) -> CompanyRun {
+        CompanyRun {
+            company: company.to_string(),
+            started_at: Utc::now(),
+            duration_ms,
+            jobs: 10,
+            new_jobs: 1,
+            removed_jobs: 0,
+            is_baseline: false,
+            error: error.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_scraper_stats() {
+        let mut older = ScanRun::start(RunTrigger::Cron);
+        older.record(company_run("Stripe", 4000, Some("Timed out")));
+        older.record(company_run("Meta", 1000, None));
+
+        let mut newer = ScanRun::start(RunTrigger::Cli);
+        newer.record(company_run("Stripe", 2000, Some("No value returned")));
+        newer.record(company_run("Meta", 3000, None));
+
+        let stats = ScraperStats::from_runs(&[newer, older]);
+
+        assert_eq!(stats[0].company, "Stripe");
+        assert_eq!(stats[0].failures, 2);
+        assert_eq!(stats[0].avg_duration_ms, 3000);
+        assert_eq!(stats[0].last_error.as_deref(), Some("No value returned"));
+        assert_eq!(stats[1].max_duration_ms, 3000);
+        assert_eq!(stats[1].failure_rate(), 0.0);
+
+        assert_eq!(format_duration_ms(850), "850ms");
+        assert_eq!(format_duration_ms(12_400), "12.4s");
+        assert_eq!(format_duration_ms(185_000), "3m 05s");
+    }
+}
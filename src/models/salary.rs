@@ -0,0 +1,283 @@
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+// INFO: Full-time hours in a year, used to annualize hourly pay
+const HOURS_PER_YEAR: f64 = 2080.0;
+
+// INFO: Anything below this without an explicit period is assumed to be hourly pay
+const HOURLY_CEILING: f64 = 1000.0;
+
+static SALARY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?ix)
+        (?P<sym1>[$€£])?\s*(?P<code1>usd|cad|eur|gbp|aud)?\s*
+        (?P<min>\d{1,3}(?:,\d{3})+(?:\.\d+)?|\d+(?:\.\d+)?)\s*(?P<k1>k\b)?
+        (?:
+            \s*(?:-|–|—|to)\s*
+            (?P<sym2>[$€£])?\s*
+            (?P<max>\d{1,3}(?:,\d{3})+(?:\.\d+)?|\d+(?:\.\d+)?)\s*(?P<k2>k\b)?
+        )?
+        \s*(?P<code2>usd|cad|eur|gbp|aud)?
+        (?:\s*(?:/|per|an|a)\s*(?P<period>hour|hr|year|yr|annum|month|mo)\b)?
+        ",
+    )
+    .unwrap()
+});
+
+#[derive(Debug, Display, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq)]
+pub enum PayPeriod {
+    #[strum(to_string = "hour")]
+    Hourly,
+    #[strum(to_string = "month")]
+    Monthly,
+    #[strum(to_string = "year")]
+    Yearly,
+}
+
+impl PayPeriod {
+    fn per_year(&self) -> f64 {
+        match self {
+            PayPeriod::Hourly => HOURS_PER_YEAR,
+            PayPeriod::Monthly => 12.0,
+            PayPeriod::Yearly => 1.0,
+        }
+    }
+}
+
+/// A pay range as advertised, with helpers to compare postings on a yearly basis
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+pub struct Salary {
+    pub min: f64,
+    pub max: f64,
+    pub currency: String,
+    pub period: PayPeriod,
+}
+
+impl Salary {
+    pub fn annual_min(&self) -> u64 {
+        (self.min * self.period.per_year()).round() as u64
+    }
+
+    pub fn annual_max(&self) -> u64 {
+        (self.max * self.period.per_year()).round() as u64
+    }
+
+    pub fn label(&self) -> String {
+        let amount = |v: f64| {
+            if self.period == PayPeriod::Hourly {
+                format!("{:.2}", v)
+            } else {
+                format_thousands(v.round() as u64)
+            }
+        };
+
+        if self.min == self.max {
+            format!("{} {}/{}", amount(self.min), self.currency, self.period)
+        } else {
+            format!(
+                "{} - {} {}/{}",
+                amount(self.min),
+                amount(self.max),
+                self.currency,
+                self.period
+            )
+        }
+    }
+}
+
+fn format_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut out = String::new();
+
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+
+    out
+}
+
+fn currency_from_captures(caps: &Captures) -> Option<String> {
+    if let Some(code) = caps.name("code1").or(caps.name("code2")) {
+        return Some(code.as_str().to_uppercase());
+    }
+
+    match caps.name("sym1").or(caps.name("sym2"))?.as_str() {
+        "€" => Some("EUR".to_string()),
+        "£" => Some("GBP".to_string()),
+        _ => Some("USD".to_string()),
+    }
+}
+
+fn amount_from_match(raw: &str, thousands: bool) -> Option<f64> {
+    let value: f64 = raw.replace(',', "").parse().ok()?;
+
+    Some(if thousands { value * 1000.0 } else { value })
+}
+
+/// Reads a yearly amount typed by the user, e.g. "150000", "150,000" or "$150k"
+pub fn parse_annual_amount(input: &str) -> Option<u64> {
+    let normalized = input
+        .trim()
+        .trim_start_matches('$')
+        .replace(',', "")
+        .to_lowercase();
+
+    let (digits, multiplier) = match normalized.strip_suffix('k') {
+        Some(digits) => (digits, 1000.0),
+        None => (normalized.as_str(), 1.0),
+    };
+
+    let value: f64 = digits.trim().parse().ok()?;
+
+    if value < 0.0 {
+        return None;
+    }
+
+    Some((value * multiplier).round() as u64)
+}
+
+/// Extracts the first pay range from free text, e.g. "$150,000 - $210,000 USD" or "$45-$60/hr"
+///
+/// Only amounts with a currency symbol or code count, so "5-10 years" is never read as pay.
+pub fn parse_salary(text: &str) -> Option<Salary> {
+    find_salary(text).map(|(salary, _)| salary)
+}
+
+/// The part of a longer text, like a description, that `parse_salary` reads the pay from
+pub fn salary_text(text: &str) -> Option<String> {
+    find_salary(text).map(|(_, matched)| matched.trim().to_string())
+}
+
+fn find_salary(text: &str) -> Option<(Salary, &str)> {
+    for caps in SALARY_RE.captures_iter(text) {
+        let Some(currency) = currency_from_captures(&caps) else {
+            continue;
+        };
+
+        let k1 = caps.name("k1").is_some();
+        let k2 = caps.name("k2").is_some();
+
+        // "150-210k" only puts the k on the upper bound, but it applies to both
+        let Some(mut min) = amount_from_match(&caps["min"], k1 || k2) else {
+            continue;
+        };
+
+        let mut max = match caps.name("max") {
+            Some(max) => match amount_from_match(max.as_str(), k2) {
+                Some(max) => max,
+                None => continue,
+            },
+            None => min,
+        };
+
+        if min <= 0.0 {
+            continue;
+        }
+
+        if max < min {
+            std::mem::swap(&mut min, &mut max);
+        }
+
+        let following: String = text[caps.get(0).unwrap().end()..]
+            .chars()
+            .take(20)
+            .collect::<String>()
+            .to_lowercase();
+
+        let period = match caps.name("period").map(|p| p.as_str().to_lowercase()) {
+            Some(p) if p == "hour" || p == "hr" => PayPeriod::Hourly,
+            Some(p) if p == "month" || p == "mo" => PayPeriod::Monthly,
+            Some(_) => PayPeriod::Yearly,
+            None if following.contains("hourly") => PayPeriod::Hourly,
+            None if following.contains("monthly") => PayPeriod::Monthly,
+            None if max < HOURLY_CEILING => PayPeriod::Hourly,
+            None => PayPeriod::Yearly,
+        };
+
+        let salary = Salary {
+            min,
+            max,
+            currency,
+            period,
+        };
+
+        return Some((salary, caps.get(0).unwrap().as_str()));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_yearly_range() {
+        let salary =
+            parse_salary("The base pay range is $150,000 - $210,000 USD per year.").unwrap();
+
+        assert_eq!(salary.min, 150_000.0);
+        assert_eq!(salary.max, 210_000.0);
+        assert_eq!(salary.currency, "USD");
+        assert_eq!(salary.period, PayPeriod::Yearly);
+        assert_eq!(salary.annual_max(), 210_000);
+        assert_eq!(salary.label(), "150,000 - 210,000 USD/year");
+    }
+
+    #[test]
+    fn test_parse_hourly_and_shorthand() {
+        let hourly = parse_salary("Pay: $45.50 to $60/hr").unwrap();
+        assert_eq!(hourly.period, PayPeriod::Hourly);
+        assert_eq!(hourly.annual_min(), 94_640);
+
+        let implied_hourly = parse_salary("$30 - $40 hourly").unwrap();
+        assert_eq!(implied_hourly.period, PayPeriod::Hourly);
+
+        let shorthand = parse_salary("€90k-€110k").unwrap();
+        assert_eq!(shorthand.currency, "EUR");
+        assert_eq!(shorthand.min, 90_000.0);
+        assert_eq!(shorthand.max, 110_000.0);
+
+        let upper_k = parse_salary("CAD 150-210k").unwrap();
+        assert_eq!(upper_k.currency, "CAD");
+        assert_eq!(upper_k.min, 150_000.0);
+
+        let monthly = parse_salary("£4,000 per month").unwrap();
+        assert_eq!(monthly.period, PayPeriod::Monthly);
+        assert_eq!(monthly.annual_max(), 48_000);
+    }
+
+    #[test]
+    fn test_parse_annual_amount() {
+        assert_eq!(parse_annual_amount("150000"), Some(150_000));
+        assert_eq!(parse_annual_amount(" $150,000 "), Some(150_000));
+        assert_eq!(parse_annual_amount("152.5K"), Some(152_500));
+        assert_eq!(parse_annual_amount("lots"), None);
+    }
+
+    #[test]
+    fn test_salary_text() {
+        assert_eq!(
+            salary_text("5+ years of Rust.\n\nThe base pay range is $150,000 - $210,000 USD per year. Plus equity.")
+                .as_deref(),
+            Some("$150,000 - $210,000 USD per year")
+        );
+        assert_eq!(salary_text("5-10 years of experience"), None);
+    }
+
+    #[test]
+    fn test_ignores_amounts_without_currency() {
+        assert_eq!(parse_salary("5-10 years of experience, 401k match"), None);
+        assert_eq!(
+            parse_salary("3+ years experience. Salary: $120,000").map(|s| s.max),
+            Some(120_000.0)
+        );
+    }
+}
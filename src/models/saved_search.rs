@@ -0,0 +1,141 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{filter::JobFilter, scraper::Job};
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct SavedSearch {
+    pub id: Uuid,
+    pub name: String,
+    pub filter: JobFilter,
+    // INFO: Discord mode posts this search's matches here in addition to the profile's webhook
+    #[serde(default)]
+    pub discord_webhook_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SavedSearch {
+    pub fn new(name: String, filter: JobFilter) -> Self {
+        SavedSearch {
+            id: Uuid::new_v4(),
+            name,
+            filter,
+            discord_webhook_url: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn display_string(&self) -> String {
+        let criteria = self.filter.describe();
+        let mut display = if criteria.is_empty() {
+            format!("{} | All open jobs", self.name)
+        } else {
+            format!("{} | {}", self.name, criteria.join(" · "))
+        };
+
+        if self.discord_webhook_url.is_some() {
+            display += " | 🔔 Discord";
+        }
+
+        display
+    }
+}
+
+/// The new jobs of a scan that a saved search picked up
+pub struct SavedSearchMatches {
+    pub search: SavedSearch,
+    pub jobs: Vec<(String, Job)>,
+}
+
+/// Runs every saved search over a scan's new jobs, searches without matches are left out
+pub fn match_saved_searches(
+    searches: &[SavedSearch],
+    new_jobs: &[(String, Job)],
+    now: DateTime<Utc>,
+) -> Vec<SavedSearchMatches> {
+    searches
+        .iter()
+        .map(|search| SavedSearchMatches {
+            search: search.clone(),
+            jobs: new_jobs
+                .iter()
+                .filter(|(company, job)| search.filter.matches(company, job, now))
+                .cloned()
+                .collect(),
+        })
+        .filter(|m| !m.jobs.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::{
+        location::LocationQuery,
+        seniority::{classify_seniority, Seniority},
+    };
+
+    fn job(title: &str, location: &str) -> Job {
+        Job {
+            title: title.to_string(),
+            location: location.to_string(),
+            seniority: classify_seniority(title),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_match_saved_searches() {
+        let now = Utc::now();
+        let new_jobs = vec![
+            (
+                "Stripe".to_string(),
+                job("Staff Infrastructure Engineer", "San Francisco, CA"),
+            ),
+            (
+                "Stripe".to_string(),
+                job("Rust Backend Engineer", "Remote - US"),
+            ),
+            (
+                "Meta".to_string(),
+                job("Staff Infrastructure Engineer", "Menlo Park, CA"),
+            ),
+            (
+                "Meta".to_string(),
+                job("Staff Infrastructure Engineer", "London, UK"),
+            ),
+        ];
+
+        let sf_staff_infra = SavedSearch::new(
+            "SF staff infra".to_string(),
+            JobFilter {
+                title: Some("infrastructure".to_string()),
+                seniority: vec![Seniority::Staff],
+                location: LocationQuery::parse("US-CA").ok(),
+                ..Default::default()
+            },
+        );
+        let rust = SavedSearch::new(
+            "Rust at Meta".to_string(),
+            JobFilter {
+                title: Some("rust".to_string()),
+                companies: vec!["Meta".to_string()],
+                ..Default::default()
+            },
+        );
+
+        let matches = match_saved_searches(&[sf_staff_infra.clone(), rust], &new_jobs, now);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].search.id, sf_staff_infra.id);
+        assert_eq!(matches[0].jobs.len(), 2);
+        assert!(
+            matches[0]
+                .jobs
+                .iter()
+                .all(|(_, j)| j.title == "Staff Infrastructure Engineer"
+                    && j.location.ends_with("CA"))
+        );
+    }
+}
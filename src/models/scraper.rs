@@ -100,22 +100,51 @@
 //
 
 use headless_chrome::{Browser, LaunchOptions};
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::future::Future;
 use std::pin::Pin;
-use std::{collections::HashSet, error::Error};
+use std::error::Error;
 use tabled::Tabled;
 use uuid::Uuid;
 
-use super::data::{Company, Data};
+use super::{
+    data::{Company, Data},
+    description::JobDescription,
+    diff::{diff_jobs, JobsDiff},
+    identity::canonicalize_link,
+    location::{normalize_location, NormalizedLocation, WorkArrangement},
+    metadata::JobMetadata,
+    pipeline::{record_status_change, ApplicationStatus, StatusChange},
+    salary::{parse_salary, Salary},
+    search::with_search_index,
+    seniority::{classify_seniority, Seniority},
+};
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
 pub struct ScrapedJob {
     pub title: String,
     pub location: String,
     pub link: String,
+    // INFO: ATS identifier (Greenhouse id, Workday req id, Meta job id, ...) when the source has one
+    #[serde(default)]
+    pub external_id: Option<String>,
+    // INFO: Every location of a posting, scrapers may fill this instead of emitting one job per location
+    #[serde(default)]
+    pub locations: Vec<String>,
+    #[serde(default)]
+    pub metadata: JobMetadata,
+}
+
+impl ScrapedJob {
+    pub fn canonical_link(&self) -> String {
+        canonicalize_link(&self.link)
+    }
 }
-#[derive(Debug, Deserialize, Serialize, Clone, Tabled)]
+
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, Tabled)]
 pub struct Job {
     pub id: Uuid,
     pub is_seen: bool,
@@ -124,6 +153,307 @@ pub struct Job {
     pub link: String,
     pub applied: bool,
     pub is_bookmarked: bool,
+    #[serde(default)]
+    #[tabled(skip)]
+    pub external_id: Option<String>,
+    // INFO: `location` is the display string, this holds each location of a multi-location posting
+    #[serde(default)]
+    #[tabled(skip)]
+    pub locations: Vec<String>,
+    #[serde(default)]
+    #[tabled(skip)]
+    pub first_seen_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    #[tabled(skip)]
+    pub last_seen_at: Option<DateTime<Utc>>,
+    // INFO: Set when the posting disappears from the company's board, cleared if it comes back
+    #[serde(default)]
+    #[tabled(skip)]
+    pub closed_at: Option<DateTime<Utc>>,
+    // INFO: Every application status the job went through, the last entry is the current one
+    #[serde(default)]
+    #[tabled(skip)]
+    pub status_history: Vec<StatusChange>,
+    #[serde(default)]
+    #[tabled(skip)]
+    pub notes: String,
+    #[serde(default)]
+    #[tabled(skip)]
+    pub tags: Vec<String>,
+    // INFO: User-defined key/value pairs, e.g. "recruiter" => "Jane Doe", "referral" => "pending"
+    #[serde(default)]
+    #[tabled(skip)]
+    pub custom_fields: BTreeMap<String, String>,
+    // INFO: Refreshed from the source on every scrape
+    #[serde(default)]
+    #[tabled(skip)]
+    pub metadata: JobMetadata,
+    // INFO: Parsed from `metadata.salary_range` or else a fetched description, None when neither has pay
+    #[serde(default)]
+    #[tabled(skip)]
+    pub salary: Option<Salary>,
+    // INFO: Classified from the title, None when the title doesn't give the level away
+    #[serde(default)]
+    #[tabled(skip)]
+    pub seniority: Option<Seniority>,
+}
+
+impl Job {
+    pub fn from_scraped(scraped: ScrapedJob, now: DateTime<Utc>) -> Self {
+        let mut job = Job {
+            id: Uuid::new_v4(),
+            is_seen: false,
+            title: scraped.title,
+            location: scraped.location,
+            link: scraped.link,
+            applied: false,
+            is_bookmarked: false,
+            external_id: scraped.external_id,
+            locations: scraped.locations,
+            first_seen_at: Some(now),
+            last_seen_at: Some(now),
+            closed_at: None,
+            status_history: Vec::new(),
+            notes: String::new(),
+            tags: Vec::new(),
+            custom_fields: BTreeMap::new(),
+            metadata: scraped.metadata,
+            salary: None,
+            seniority: None,
+        };
+
+        job.refresh_salary();
+        job.refresh_seniority();
+
+        job
+    }
+
+    /// Re-parses the structured salary from the raw range the source printed
+    pub fn refresh_salary(&mut self) {
+        self.salary = self.metadata.salary_range.as_deref().and_then(parse_salary);
+    }
+
+    /// Reads the pay from the job's description when the source gave no range, returns whether the
+    /// salary changed
+    ///
+    /// Most boards only mention pay in the description, call this wherever one is fetched or loaded.
+    pub fn refresh_salary_from_description(&mut self, description: &JobDescription) -> bool {
+        if self.metadata.salary_range.is_some() {
+            return false;
+        }
+
+        // Bold amounts would split "**$150,000** - **$210,000**" into two
+        let salary = parse_salary(&description.markdown.replace("**", ""));
+        let changed = salary != self.salary;
+        self.salary = salary;
+
+        changed
+    }
+
+    /// Takes the metadata of a new scrape, pay read from the description is kept while the source
+    /// still gives no range
+    pub fn update_metadata(&mut self, metadata: JobMetadata) {
+        let description_salary = match self.metadata.salary_range {
+            None => self.salary.take(),
+            Some(_) => None,
+        };
+
+        self.metadata = metadata;
+        self.refresh_salary();
+        if self.metadata.salary_range.is_none() {
+            self.salary = description_salary;
+        }
+    }
+
+    pub fn refresh_seniority(&mut self) {
+        self.seniority = classify_seniority(&self.title);
+    }
+
+    pub fn seniority_label(&self) -> String {
+        self.seniority.map(|s| s.to_string()).unwrap_or_default()
+    }
+
+    /// The normalized salary, or the raw text when it couldn't be parsed
+    pub fn salary_label(&self) -> String {
+        match &self.salary {
+            Some(salary) => salary.label(),
+            None => self.metadata.salary_range.clone().unwrap_or_default(),
+        }
+    }
+
+    pub fn canonical_link(&self) -> String {
+        canonicalize_link(&self.link)
+    }
+
+    pub fn all_locations(&self) -> Vec<String> {
+        if self.locations.is_empty() {
+            vec![self.location.clone()]
+        } else {
+            self.locations.clone()
+        }
+    }
+
+    /// Every location resolved against the gazetteer, the source's remote flag fills in unstated arrangements
+    pub fn normalized_locations(&self) -> Vec<NormalizedLocation> {
+        let fallback = match self.metadata.remote {
+            Some(true) => Some(WorkArrangement::Remote),
+            Some(false) => Some(WorkArrangement::Onsite),
+            None => None,
+        };
+
+        self.all_locations()
+            .iter()
+            .flat_map(|l| normalize_location(l))
+            .map(|mut l| {
+                l.arrangement = l.arrangement.or(fallback);
+                l
+            })
+            .collect()
+    }
+
+    pub fn is_remote(&self) -> bool {
+        self.metadata.remote == Some(true)
+            || self
+                .normalized_locations()
+                .iter()
+                .any(|l| l.arrangement == Some(WorkArrangement::Remote))
+    }
+
+    /// Swaps this record's user state for `other`'s, for when `other` is the more recent copy
+    pub fn replace_user_state(&mut self, other: &Job) {
+        // INFO: Reset everything `absorb_user_state` merges, so only `other`'s state is left
+        self.applied = false;
+        self.is_bookmarked = false;
+        self.is_seen = false;
+        self.status_history.clear();
+        self.notes.clear();
+        self.tags.clear();
+        self.custom_fields.clear();
+
+        self.absorb_user_state(other);
+    }
+
+    /// Carries the user's state over from a duplicate record of the same posting
+    pub fn absorb_user_state(&mut self, other: &Job) {
+        self.applied |= other.applied;
+        self.is_bookmarked |= other.is_bookmarked;
+        self.is_seen |= other.is_seen;
+        if self.status_history.is_empty() {
+            self.status_history = other.status_history.clone();
+        }
+        if self.notes.is_empty() {
+            self.notes = other.notes.clone();
+        }
+        for tag in other.tags.iter() {
+            self.add_tag(tag);
+        }
+        for (key, value) in other.custom_fields.iter() {
+            self.custom_fields
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+        self.first_seen_at = match (self.first_seen_at, other.first_seen_at) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed_at.is_some()
+    }
+
+    /// The current pipeline stage, jobs marked applied before the pipeline existed count as `Applied`
+    pub fn application_status(&self) -> Option<ApplicationStatus> {
+        match self.status_history.last() {
+            Some(change) => Some(change.status),
+            None if self.applied => Some(ApplicationStatus::Applied),
+            None => None,
+        }
+    }
+
+    /// Moves the job to a pipeline stage, keeping `applied` in sync with the timeline
+    pub fn set_application_status(&mut self, status: ApplicationStatus, now: DateTime<Utc>) -> bool {
+        let changed = record_status_change(&mut self.status_history, status, now);
+
+        self.applied = self
+            .status_history
+            .iter()
+            .any(|c| c.status.counts_as_applied());
+
+        changed
+    }
+
+    /// Whether the user has done anything with this job that would be lost if it were dropped
+    pub fn has_user_state(&self) -> bool {
+        self.applied
+            || self.is_bookmarked
+            || !self.status_history.is_empty()
+            || !self.notes.trim().is_empty()
+            || !self.tags.is_empty()
+            || !self.custom_fields.is_empty()
+    }
+
+    /// Adds a tag, tags are lowercased and trimmed so "Referral" and "referral " are the same tag
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        let tag = tag.trim().to_lowercase();
+
+        if tag.is_empty() || self.tags.contains(&tag) {
+            return false;
+        }
+
+        self.tags.push(tag);
+        true
+    }
+
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        let len = self.tags.len();
+        self.tags.retain(|t| t != tag);
+
+        self.tags.len() != len
+    }
+
+    pub fn tags_label(&self) -> String {
+        self.tags
+            .iter()
+            .map(|t| format!("#{}", t))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    pub fn custom_fields_label(&self) -> String {
+        self.custom_fields
+            .iter()
+            .map(|(k, v)| format!("{}: {}", k, v))
+            .collect::<Vec<String>>()
+            .join("; ")
+    }
+
+    /// Notes, tags and custom field values folded into one line so the fuzzy job menus can match them
+    pub fn annotations_label(&self) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if !self.tags.is_empty() {
+            parts.push(self.tags_label());
+        }
+
+        if !self.custom_fields.is_empty() {
+            parts.push(self.custom_fields_label());
+        }
+
+        if let Some(first_line) = self.notes.lines().find(|l| !l.trim().is_empty()) {
+            let snippet: String = first_line.trim().chars().take(40).collect();
+            parts.push(format!("📝 {}", snippet));
+        }
+
+        parts.join(" | ")
+    }
+
+    pub fn status_label(&self) -> String {
+        match self.closed_at {
+            Some(closed_at) => format!("Closed {}", closed_at.format("%Y-%m-%d")),
+            None => "Open".to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -131,88 +461,48 @@ pub struct JobsPayload {
     pub are_new_jobs: bool,
     pub new_jobs: Vec<Job>, // Reference to Job without extra lifetime parameter on Job
     pub all_jobs: Vec<Job>,
+    pub diff: JobsDiff,
 }
 
 impl JobsPayload {
-    pub fn from_jobs(jobs: &Vec<Job>, snapshot_of_jobs: &Vec<Job>) -> Self {
-        let old_jobs: HashSet<&str> = snapshot_of_jobs.iter().map(|j| j.title.as_str()).collect();
-
-        let new_jobs = jobs
-            .iter()
-            .filter(|&j| !old_jobs.contains(j.title.as_str()))
-            .cloned()
-            .collect::<Vec<Job>>();
-
-        JobsPayload {
-            are_new_jobs: new_jobs.len() > 0,
-            new_jobs,
-            all_jobs: jobs.clone(),
-        }
-    }
-
     pub fn from_scraped_jobs(
         scraped_jobs: Vec<ScrapedJob>,
         company_key: &str,
         data: &mut Data,
     ) -> Self {
-        let mut all_jobs: Vec<Job> = Vec::new();
-        let mut new_jobs: Vec<Job> = Vec::new();
-
+        let reminded = data.reminded_job_ids(company_key);
         let company = data.companies.get_mut(company_key).unwrap();
-        // Check for first scrape
-        if company.jobs.is_empty() {
-            all_jobs = scraped_jobs
-                .into_iter()
-                .map(|sj| Job {
-                    id: Uuid::new_v4(),
-                    title: sj.title.trim().to_string(),
-                    location: sj.location.trim().to_string(),
-                    link: sj.link.trim().to_string(),
-                    applied: false,
-                    is_seen: false,
-                    is_bookmarked: false,
-                })
-                .collect();
-        } else {
-            for sc in scraped_jobs {
-                let existing = company.jobs.iter().find(|j| {
-                    let job_identifier = format!("{}{}", j.title, j.location);
-                    let scraped_job_identifier =
-                        format!("{}{}", sc.title.trim(), sc.location.trim());
-
-                    if job_identifier == scraped_job_identifier {
-                        return true;
-                    } else {
-                        return false;
-                    }
-                });
-
-                if let Some(existing_job) = existing {
-                    all_jobs.push(existing_job.clone());
-                } else {
-                    let new_job = Job {
-                        id: Uuid::new_v4(),
-                        title: sc.title.clone().trim().to_string(),
-                        link: sc.link.clone().trim().to_string(),
-                        location: sc.location.clone().trim().to_string(),
-                        applied: false,
-                        is_seen: false,
-                        is_bookmarked: false,
-                    };
-
-                    new_jobs.push(new_job.clone());
-                    all_jobs.push(new_job.clone());
-                }
-            }
-        }
+
+        let (all_jobs, diff) = diff_jobs(&company.jobs, scraped_jobs, Utc::now(), &reminded);
+
+        // INFO: Reposts are surfaced alongside brand new postings
+        let new_jobs: Vec<Job> = diff.new.iter().chain(diff.reposted.iter()).cloned().collect();
 
         company.jobs = all_jobs.clone();
+        with_search_index(|index| index.update_company(company_key, &all_jobs));
         data.save();
 
-        return JobsPayload {
-            are_new_jobs: new_jobs.len() > 0,
+        JobsPayload {
+            are_new_jobs: !new_jobs.is_empty(),
             new_jobs,
             all_jobs,
-        };
+            diff,
+        }
+    }
+
+    /// New and reposted jobs, leaving out a company's baseline scrape unless asked for
+    pub fn jobs_to_notify(&self, notify_on_baseline: bool) -> Vec<Job> {
+        self.diff.jobs_to_notify(notify_on_baseline)
+    }
+
+    /// Tracked jobs (applied, bookmarked, reminded, ...) whose posting closed during this scrape
+    pub fn closed_jobs(&self) -> Vec<Job> {
+        // INFO: Removed jobs that were kept are the tracked ones
+        self.diff
+            .removed
+            .iter()
+            .filter(|j| self.all_jobs.iter().any(|kept| kept.id == j.id))
+            .cloned()
+            .collect()
     }
 }
@@ -0,0 +1,816 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{DefaultHasher, Hash, Hasher},
+    iter::Peekable,
+    sync::{LazyLock, Mutex},
+    vec::IntoIter,
+};
+
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter};
+use uuid::Uuid;
+
+use crate::{error::AppResult, profiles};
+
+use super::{data::Company, description::JobDescription, scraper::Job};
+
+// INFO: One index per profile for the whole session, `Data` is reloaded far too often to own it
+static SEARCH_INDEXES: LazyLock<Mutex<HashMap<String, SearchIndex>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// INFO: Shown under the search prompt, keep in sync with `SearchField` and `SearchFlag`
+pub const SEARCH_SYNTAX_HELP: &str = "Words must all match, \"quoted phrases\" match in order, \
+OR / NOT / -word / ( ) combine terms, rust* matches prefixes. \
+Fields: title: location: company: level: description: notes: tag:. \
+Flags: remote: bookmarked: applied: closed: (true/false)";
+
+/// The parts of a job a term can be restricted to with `field:term`
+#[derive(Debug, Display, EnumIter, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchField {
+    #[strum(to_string = "title")]
+    Title,
+    #[strum(to_string = "location")]
+    Location,
+    #[strum(to_string = "company")]
+    Company,
+    #[strum(to_string = "level")]
+    Level,
+    #[strum(to_string = "description")]
+    Description,
+    #[strum(to_string = "notes")]
+    Notes,
+    #[strum(to_string = "tag")]
+    Tag,
+}
+
+impl SearchField {
+    fn from_qualifier(qualifier: &str) -> Option<Self> {
+        match qualifier {
+            "title" => Some(SearchField::Title),
+            "location" | "loc" => Some(SearchField::Location),
+            "company" => Some(SearchField::Company),
+            "level" | "seniority" => Some(SearchField::Level),
+            "description" | "desc" => Some(SearchField::Description),
+            "notes" | "note" => Some(SearchField::Notes),
+            "tag" | "tags" => Some(SearchField::Tag),
+            _ => None,
+        }
+    }
+}
+
+/// Yes/no properties of a job, queried with `flag:true` or `flag:false`
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum SearchFlag {
+    #[strum(to_string = "remote")]
+    Remote,
+    #[strum(to_string = "bookmarked")]
+    Bookmarked,
+    #[strum(to_string = "applied")]
+    Applied,
+    #[strum(to_string = "closed")]
+    Closed,
+}
+
+impl SearchFlag {
+    fn from_qualifier(qualifier: &str) -> Option<Self> {
+        match qualifier {
+            "remote" => Some(SearchFlag::Remote),
+            "bookmarked" => Some(SearchFlag::Bookmarked),
+            "applied" => Some(SearchFlag::Applied),
+            "closed" => Some(SearchFlag::Closed),
+            _ => None,
+        }
+    }
+}
+
+/// A parsed search, e.g. `company:Stripe remote:true title:"staff"`
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchQuery {
+    // INFO: More than one term is a phrase, the terms have to follow each other in one field
+    Terms {
+        field: Option<SearchField>,
+        terms: Vec<String>,
+    },
+    Prefix {
+        field: Option<SearchField>,
+        prefix: String,
+    },
+    Flag {
+        flag: SearchFlag,
+        value: bool,
+    },
+    And(Vec<SearchQuery>),
+    Or(Vec<SearchQuery>),
+    Not(Box<SearchQuery>),
+}
+
+#[derive(Debug, PartialEq)]
+enum QueryToken {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Word {
+        field: Option<String>,
+        text: String,
+        quoted: bool,
+    },
+}
+
+/// Lowercased words of a text, keeping the symbols of names like "C++" and "C#"
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !(c.is_alphanumeric() || c == '+' || c == '#'))
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_string())
+        .collect()
+}
+
+fn lex(input: &str) -> Vec<QueryToken> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            '(' => {
+                chars.next();
+                tokens.push(QueryToken::Open);
+                continue;
+            }
+            ')' => {
+                chars.next();
+                tokens.push(QueryToken::Close);
+                continue;
+            }
+            '-' => {
+                chars.next();
+                tokens.push(QueryToken::Not);
+                continue;
+            }
+            _ => {}
+        }
+
+        let mut text = String::new();
+        let mut field = None;
+        let mut quoted = false;
+
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() || c == '(' || c == ')' {
+                break;
+            }
+            chars.next();
+
+            match c {
+                // An unterminated quote runs to the end of the query
+                '"' => {
+                    quoted = true;
+                    for c in chars.by_ref() {
+                        if c == '"' {
+                            break;
+                        }
+                        text.push(c);
+                    }
+                }
+                ':' if field.is_none() && !quoted && !text.is_empty() => {
+                    field = Some(std::mem::take(&mut text).to_lowercase())
+                }
+                _ => text.push(c),
+            }
+        }
+
+        let token = match (field.is_none() && !quoted, text.as_str()) {
+            (true, "AND") => QueryToken::And,
+            (true, "OR") => QueryToken::Or,
+            (true, "NOT") => QueryToken::Not,
+            _ => QueryToken::Word {
+                field,
+                text,
+                quoted,
+            },
+        };
+
+        tokens.push(token);
+    }
+
+    tokens
+}
+
+fn parse_word(field: Option<String>, text: String, quoted: bool) -> AppResult<SearchQuery> {
+    if let Some(flag) = field.as_deref().and_then(SearchFlag::from_qualifier) {
+        let value = match text.to_lowercase().as_str() {
+            "true" | "yes" | "1" => true,
+            "false" | "no" | "0" => false,
+            _ => return Err(format!("{}: takes true or false, not \"{}\"", flag, text).into()),
+        };
+
+        return Ok(SearchQuery::Flag { flag, value });
+    }
+
+    let field = match field {
+        Some(qualifier) => Some(SearchField::from_qualifier(&qualifier).ok_or_else(|| {
+            format!(
+                "Unknown field \"{}\", use one of {}",
+                qualifier,
+                SearchField::iter()
+                    .map(|f| format!("{}:", f))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            )
+        })?),
+        None => None,
+    };
+
+    let terms = tokenize(&text);
+
+    if !quoted && text.ends_with('*') && terms.len() == 1 {
+        return Ok(SearchQuery::Prefix {
+            field,
+            prefix: terms[0].clone(),
+        });
+    }
+
+    if terms.is_empty() {
+        return Err(format!("Nothing to search for in \"{}\"", text).into());
+    }
+
+    Ok(SearchQuery::Terms { field, terms })
+}
+
+struct QueryParser {
+    tokens: Peekable<IntoIter<QueryToken>>,
+}
+
+impl QueryParser {
+    fn parse_or(&mut self) -> AppResult<SearchQuery> {
+        let mut parts = vec![self.parse_and()?];
+
+        while self.tokens.peek() == Some(&QueryToken::Or) {
+            self.tokens.next();
+            parts.push(self.parse_and()?);
+        }
+
+        Ok(if parts.len() == 1 {
+            parts.remove(0)
+        } else {
+            SearchQuery::Or(parts)
+        })
+    }
+
+    fn parse_and(&mut self) -> AppResult<SearchQuery> {
+        let mut parts = vec![self.parse_unary()?];
+
+        loop {
+            match self.tokens.peek() {
+                None | Some(QueryToken::Close) | Some(QueryToken::Or) => break,
+                Some(QueryToken::And) => {
+                    self.tokens.next();
+                }
+                _ => {}
+            }
+            parts.push(self.parse_unary()?);
+        }
+
+        Ok(if parts.len() == 1 {
+            parts.remove(0)
+        } else {
+            SearchQuery::And(parts)
+        })
+    }
+
+    fn parse_unary(&mut self) -> AppResult<SearchQuery> {
+        match self.tokens.next() {
+            Some(QueryToken::Not) => Ok(SearchQuery::Not(Box::new(self.parse_unary()?))),
+            Some(QueryToken::Open) => {
+                let query = self.parse_or()?;
+
+                match self.tokens.next() {
+                    Some(QueryToken::Close) => Ok(query),
+                    _ => Err("Missing closing parenthesis".into()),
+                }
+            }
+            Some(QueryToken::Word {
+                field,
+                text,
+                quoted,
+            }) => parse_word(field, text, quoted),
+            Some(QueryToken::Close) => Err("Unexpected closing parenthesis".into()),
+            Some(QueryToken::And) | Some(QueryToken::Or) => {
+                Err("AND and OR need a term on both sides".into())
+            }
+            None => Err("The search ends where a term was expected".into()),
+        }
+    }
+}
+
+impl SearchQuery {
+    pub fn parse(input: &str) -> AppResult<Self> {
+        let tokens = lex(input);
+
+        if tokens.is_empty() {
+            return Err("Enter something to search for".into());
+        }
+
+        let mut parser = QueryParser {
+            tokens: tokens.into_iter().peekable(),
+        };
+        let query = parser.parse_or()?;
+
+        if parser.tokens.peek().is_some() {
+            return Err("Unexpected closing parenthesis".into());
+        }
+
+        Ok(query)
+    }
+
+    /// Whether the query says anything about `flag`, used to hide closed jobs unless asked for
+    pub fn mentions_flag(&self, flag: SearchFlag) -> bool {
+        match self {
+            SearchQuery::Flag { flag: f, .. } => *f == flag,
+            SearchQuery::And(parts) | SearchQuery::Or(parts) => {
+                parts.iter().any(|p| p.mentions_flag(flag))
+            }
+            SearchQuery::Not(inner) => inner.mentions_flag(flag),
+            SearchQuery::Terms { .. } | SearchQuery::Prefix { .. } => false,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct IndexedJob {
+    company: String,
+    fingerprint: u64,
+    fields: HashMap<SearchField, Vec<String>>,
+    remote: bool,
+    bookmarked: bool,
+    applied: bool,
+    closed: bool,
+}
+
+impl IndexedJob {
+    fn flag(&self, flag: SearchFlag) -> bool {
+        match flag {
+            SearchFlag::Remote => self.remote,
+            SearchFlag::Bookmarked => self.bookmarked,
+            SearchFlag::Applied => self.applied,
+            SearchFlag::Closed => self.closed,
+        }
+    }
+}
+
+// INFO: Changes whenever anything searchable about the job does, so unchanged jobs are not re-indexed.
+// Descriptions are left out, storing one calls `SearchIndex::invalidate` instead of costing a file
+// lookup per job on every search.
+fn fingerprint(company: &str, job: &Job) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    company.hash(&mut hasher);
+    job.title.hash(&mut hasher);
+    job.all_locations().hash(&mut hasher);
+    job.seniority.hash(&mut hasher);
+    job.notes.hash(&mut hasher);
+    job.tags.hash(&mut hasher);
+    job.is_remote().hash(&mut hasher);
+    job.is_bookmarked.hash(&mut hasher);
+    job.applied.hash(&mut hasher);
+    job.is_closed().hash(&mut hasher);
+
+    hasher.finish()
+}
+
+/// An inverted index over every stored job, from (field, word) to the jobs containing it
+///
+/// Kept in memory and updated per company, only jobs whose fingerprint changed are re-indexed.
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    docs: HashMap<Uuid, IndexedJob>,
+    postings: HashMap<SearchField, HashMap<String, HashSet<Uuid>>>,
+    is_built: bool,
+}
+
+impl SearchIndex {
+    fn remove(&mut self, id: &Uuid) {
+        let Some(doc) = self.docs.remove(id) else {
+            return;
+        };
+
+        for (field, terms) in doc.fields {
+            let Some(postings) = self.postings.get_mut(&field) else {
+                continue;
+            };
+
+            for term in terms {
+                if let Some(ids) = postings.get_mut(&term) {
+                    ids.remove(id);
+                    if ids.is_empty() {
+                        postings.remove(&term);
+                    }
+                }
+            }
+        }
+    }
+
+    fn insert(&mut self, company: &str, job: &Job, fingerprint: u64) {
+        let mut locations = job.all_locations();
+        locations.extend(job.normalized_locations().iter().map(|l| l.label()));
+
+        let description = JobDescription::load(&job.id)
+            .map(|d| d.markdown)
+            .unwrap_or_default();
+
+        let fields = HashMap::from([
+            (SearchField::Title, tokenize(&job.title)),
+            (SearchField::Location, tokenize(&locations.join(" / "))),
+            (SearchField::Company, tokenize(company)),
+            (SearchField::Level, tokenize(&job.seniority_label())),
+            (SearchField::Description, tokenize(&description)),
+            (SearchField::Notes, tokenize(&job.notes)),
+            (SearchField::Tag, tokenize(&job.tags.join(" / "))),
+        ]);
+
+        for (field, terms) in fields.iter() {
+            let postings = self.postings.entry(*field).or_default();
+
+            for term in terms {
+                postings.entry(term.clone()).or_default().insert(job.id);
+            }
+        }
+
+        self.docs.insert(
+            job.id,
+            IndexedJob {
+                company: company.to_string(),
+                fingerprint,
+                fields,
+                remote: job.is_remote(),
+                bookmarked: job.is_bookmarked,
+                applied: job.applied,
+                closed: job.is_closed(),
+            },
+        );
+    }
+
+    fn index_company(&mut self, company: &str, jobs: &[Job]) {
+        let current: HashSet<Uuid> = jobs.iter().map(|j| j.id).collect();
+        let stale: Vec<Uuid> = self
+            .docs
+            .iter()
+            .filter(|(id, doc)| doc.company == company && !current.contains(id))
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in stale {
+            self.remove(&id);
+        }
+
+        for job in jobs {
+            let fingerprint = fingerprint(company, job);
+
+            if self
+                .docs
+                .get(&job.id)
+                .is_some_and(|doc| doc.fingerprint == fingerprint)
+            {
+                continue;
+            }
+
+            self.remove(&job.id);
+            self.insert(company, job, fingerprint);
+        }
+    }
+
+    /// Drops a job so the next sync indexes it again, for changes the fingerprint doesn't see
+    pub fn invalidate(&mut self, id: &Uuid) {
+        self.remove(id);
+    }
+
+    /// Re-indexes a company after a scrape, nothing to do until the index is first built
+    pub fn update_company(&mut self, company: &str, jobs: &[Job]) {
+        if self.is_built {
+            self.index_company(company, jobs);
+        }
+    }
+
+    /// Brings the index in line with every company, building it on first use
+    pub fn sync(&mut self, companies: &HashMap<String, Company>) {
+        let gone: Vec<Uuid> = self
+            .docs
+            .iter()
+            .filter(|(_, doc)| !companies.contains_key(&doc.company))
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in gone {
+            self.remove(&id);
+        }
+
+        for (company, c) in companies.iter() {
+            self.index_company(company, &c.jobs);
+        }
+
+        self.is_built = true;
+    }
+
+    fn fields(field: Option<SearchField>) -> Vec<SearchField> {
+        match field {
+            Some(field) => vec![field],
+            None => SearchField::iter().collect(),
+        }
+    }
+
+    fn matching_terms(&self, field: SearchField, terms: &[String]) -> HashSet<Uuid> {
+        let Some(postings) = self.postings.get(&field) else {
+            return HashSet::new();
+        };
+
+        let mut candidates: Option<HashSet<Uuid>> = None;
+
+        for term in terms {
+            let ids = postings.get(term).cloned().unwrap_or_default();
+            candidates = Some(match candidates {
+                Some(c) => &c & &ids,
+                None => ids,
+            });
+        }
+
+        // The index only knows the words are there, check they are in order for phrases
+        candidates
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|id| {
+                terms.len() == 1
+                    || self.docs[id].fields[&field]
+                        .windows(terms.len())
+                        .any(|w| w == terms)
+            })
+            .collect()
+    }
+
+    fn evaluate(&self, query: &SearchQuery) -> HashSet<Uuid> {
+        match query {
+            SearchQuery::Terms { field, terms } => Self::fields(*field)
+                .into_iter()
+                .flat_map(|f| self.matching_terms(f, terms))
+                .collect(),
+            SearchQuery::Prefix { field, prefix } => Self::fields(*field)
+                .into_iter()
+                .filter_map(|f| self.postings.get(&f))
+                .flat_map(|postings| {
+                    postings
+                        .iter()
+                        .filter(|(term, _)| term.starts_with(prefix.as_str()))
+                        .flat_map(|(_, ids)| ids.iter().copied())
+                })
+                .collect(),
+            SearchQuery::Flag { flag, value } => self
+                .docs
+                .iter()
+                .filter(|(_, doc)| doc.flag(*flag) == *value)
+                .map(|(id, _)| *id)
+                .collect(),
+            SearchQuery::And(parts) => {
+                let mut sets = parts.iter().map(|p| self.evaluate(p));
+                let first = sets.next().unwrap_or_default();
+
+                sets.fold(first, |acc, set| &acc & &set)
+            }
+            SearchQuery::Or(parts) => parts.iter().flat_map(|p| self.evaluate(p)).collect(),
+            SearchQuery::Not(inner) => {
+                let excluded = self.evaluate(inner);
+
+                self.docs
+                    .keys()
+                    .filter(|id| !excluded.contains(id))
+                    .copied()
+                    .collect()
+            }
+        }
+    }
+
+    /// Ids of the indexed jobs matching the query, call `sync` first for up to date results
+    pub fn search(&self, query: &SearchQuery) -> HashSet<Uuid> {
+        self.evaluate(query)
+    }
+}
+
+/// Runs `f` with the active profile's search index
+pub fn with_search_index<T>(f: impl FnOnce(&mut SearchIndex) -> T) -> T {
+    let mut indexes = SEARCH_INDEXES.lock().unwrap();
+
+    f(indexes.entry(profiles::active_profile()).or_default())
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::models::{pipeline::ApplicationStatus, scraper::ScrapedJob};
+
+    fn job(title: &str, location: &str) -> Job {
+        Job::from_scraped(
+            ScrapedJob {
+                title: title.to_string(),
+                location: location.to_string(),
+                link: format!("https://example.com/{}", title),
+                ..Default::default()
+            },
+            Utc::now(),
+        )
+    }
+
+    fn index(companies: Vec<(&str, Vec<Job>)>) -> SearchIndex {
+        let companies: HashMap<String, Company> = companies
+            .into_iter()
+            .map(|(name, jobs)| {
+                (
+                    name.to_string(),
+                    Company {
+                        jobs,
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect();
+
+        let mut index = SearchIndex::default();
+        index.sync(&companies);
+        index
+    }
+
+    fn titles(index: &SearchIndex, jobs: &[&Job], query: &str) -> Vec<String> {
+        let ids = index.search(&SearchQuery::parse(query).unwrap());
+        let mut titles: Vec<String> = jobs
+            .iter()
+            .filter(|j| ids.contains(&j.id))
+            .map(|j| j.title.clone())
+            .collect();
+        titles.sort();
+        titles
+    }
+
+    #[test]
+    fn test_parse_query() {
+        assert_eq!(
+            SearchQuery::parse(r#"company:Stripe remote:true title:"staff engineer""#).unwrap(),
+            SearchQuery::And(vec![
+                SearchQuery::Terms {
+                    field: Some(SearchField::Company),
+                    terms: vec!["stripe".to_string()],
+                },
+                SearchQuery::Flag {
+                    flag: SearchFlag::Remote,
+                    value: true,
+                },
+                SearchQuery::Terms {
+                    field: Some(SearchField::Title),
+                    terms: vec!["staff".to_string(), "engineer".to_string()],
+                },
+            ])
+        );
+
+        assert_eq!(
+            SearchQuery::parse("(rust OR go*) -intern").unwrap(),
+            SearchQuery::And(vec![
+                SearchQuery::Or(vec![
+                    SearchQuery::Terms {
+                        field: None,
+                        terms: vec!["rust".to_string()],
+                    },
+                    SearchQuery::Prefix {
+                        field: None,
+                        prefix: "go".to_string(),
+                    },
+                ]),
+                SearchQuery::Not(Box::new(SearchQuery::Terms {
+                    field: None,
+                    terms: vec!["intern".to_string()],
+                })),
+            ])
+        );
+
+        assert!(SearchQuery::parse("salary:lots").is_err());
+        assert!(SearchQuery::parse("remote:maybe").is_err());
+        assert!(SearchQuery::parse("(rust OR").is_err());
+        assert!(SearchQuery::parse("rust)").is_err());
+        assert!(SearchQuery::parse("   ").is_err());
+    }
+
+    #[test]
+    fn test_search() {
+        let rust = job("Senior Rust Engineer, Distributed Systems", "Remote - US");
+        let mut staff = job("Staff Software Engineer", "San Francisco, CA");
+        staff.notes = "Referral from Sam, systems distributed across regions".to_string();
+        staff.tags = vec!["dream-job".to_string()];
+        let go = job("Backend Engineer (Golang)", "London, UK");
+
+        let index = index(vec![
+            ("Stripe", vec![rust.clone(), staff.clone()]),
+            ("Cloudflare", vec![go.clone()]),
+        ]);
+        let all = [&rust, &staff, &go];
+
+        assert_eq!(index.docs.len(), 3);
+        assert_eq!(titles(&index, &all, "engineer").len(), 3);
+        assert_eq!(
+            titles(&index, &all, "company:stripe remote:true"),
+            vec![rust.title.clone()]
+        );
+        // Both contain the words, only one has them as a phrase
+        assert_eq!(
+            titles(&index, &all, r#""distributed systems""#),
+            vec![rust.title.clone()]
+        );
+        assert_eq!(
+            titles(&index, &all, "title:staff OR tag:dream-job"),
+            vec![staff.title.clone()]
+        );
+        assert_eq!(
+            titles(&index, &all, "location:california"),
+            vec![staff.title.clone()]
+        );
+        assert_eq!(
+            titles(&index, &all, "gol* OR level:senior"),
+            vec![go.title.clone(), rust.title.clone()]
+        );
+        assert_eq!(
+            titles(&index, &all, "engineer -company:stripe"),
+            vec![go.title.clone()]
+        );
+        assert_eq!(
+            titles(&index, &all, "NOT (rust OR staff)"),
+            vec![go.title.clone()]
+        );
+    }
+
+    #[test]
+    fn test_applied_flag() {
+        let mut interested = job("Rust Engineer", "Remote");
+        interested.set_application_status(ApplicationStatus::Interested, Utc::now());
+        let mut applied = job("Go Engineer", "Remote");
+        applied.set_application_status(ApplicationStatus::Applied, Utc::now());
+
+        let mut index = index(vec![("Stripe", vec![interested.clone(), applied.clone()])]);
+        let all = [&interested, &applied];
+        assert_eq!(
+            titles(&index, &all, "applied:true"),
+            vec![applied.title.clone()]
+        );
+
+        interested.set_application_status(ApplicationStatus::Applied, Utc::now());
+        index.update_company("Stripe", &[interested.clone(), applied.clone()]);
+        let all = [&interested, &applied];
+        assert_eq!(titles(&index, &all, "applied:true").len(), 2);
+    }
+
+    #[test]
+    fn test_incremental_update() {
+        let mut rust = job("Rust Engineer", "Remote");
+        let go = job("Go Engineer", "Remote");
+        let mut index = index(vec![("Stripe", vec![rust.clone(), go.clone()])]);
+
+        rust.title = "Elixir Engineer".to_string();
+        index.update_company("Stripe", &[rust.clone()]);
+
+        assert!(index
+            .search(&SearchQuery::parse("rust OR go").unwrap())
+            .is_empty());
+        assert_eq!(
+            index.search(&SearchQuery::parse("elixir").unwrap()),
+            HashSet::from([rust.id])
+        );
+        assert_eq!(index.docs.len(), 1);
+        assert!(!index
+            .postings
+            .get(&SearchField::Title)
+            .unwrap()
+            .contains_key("rust"));
+    }
+
+    #[test]
+    fn test_invalidate() {
+        let rust = job("Rust Engineer", "Remote");
+        let companies = HashMap::from([(
+            "Stripe".to_string(),
+            Company {
+                jobs: vec![rust.clone()],
+                ..Default::default()
+            },
+        )]);
+        let mut index = SearchIndex::default();
+        index.sync(&companies);
+
+        index.invalidate(&rust.id);
+        assert!(index.docs.is_empty());
+
+        index.sync(&companies);
+        assert_eq!(
+            index.search(&SearchQuery::parse("rust").unwrap()),
+            HashSet::from([rust.id])
+        );
+    }
+}
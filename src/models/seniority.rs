@@ -0,0 +1,290 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter};
+
+// INFO: Level codes used on job boards, e.g. L5 (Google/Amazon), E5 (Meta), IC4, M2
+static LEVEL_CODE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(l|e|ic|m)(\d{1,2})$").unwrap());
+
+// INFO: Nouns a trailing level ("Engineer II", "Analyst 3") can follow
+const ROLE_NOUNS: [&str; 18] = [
+    "engineer",
+    "developer",
+    "scientist",
+    "analyst",
+    "designer",
+    "administrator",
+    "specialist",
+    "architect",
+    "consultant",
+    "technician",
+    "researcher",
+    "sre",
+    "accountant",
+    "representative",
+    "writer",
+    "associate",
+    "programmer",
+    "tester",
+];
+
+// INFO: "Manager" titles that are individual contributor roles, not people management
+const IC_MANAGER_PREFIXES: [&str; 12] = [
+    "product",
+    "program",
+    "project",
+    "account",
+    "partner",
+    "portfolio",
+    "relationship",
+    "territory",
+    "success",
+    "community",
+    "release",
+    "case",
+];
+
+#[derive(
+    Debug,
+    Display,
+    EnumIter,
+    Deserialize,
+    Serialize,
+    JsonSchema,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+)]
+pub enum Seniority {
+    #[strum(to_string = "Intern")]
+    Intern,
+    #[strum(to_string = "Junior")]
+    Junior,
+    #[strum(to_string = "Mid")]
+    Mid,
+    #[strum(to_string = "Senior")]
+    Senior,
+    #[strum(to_string = "Staff")]
+    Staff,
+    #[strum(to_string = "Principal")]
+    Principal,
+    #[strum(to_string = "Distinguished")]
+    Distinguished,
+    #[strum(to_string = "Manager")]
+    Manager,
+    #[strum(to_string = "Senior Manager")]
+    SeniorManager,
+    #[strum(to_string = "Director")]
+    Director,
+    #[strum(to_string = "Executive")]
+    Executive,
+}
+
+impl Seniority {
+    pub fn is_management(&self) -> bool {
+        matches!(
+            self,
+            Seniority::Manager
+                | Seniority::SeniorManager
+                | Seniority::Director
+                | Seniority::Executive
+        )
+    }
+}
+
+fn ic_level(level: u32) -> Seniority {
+    match level {
+        0 | 1 => Seniority::Junior,
+        2 => Seniority::Mid,
+        3 => Seniority::Senior,
+        4 => Seniority::Staff,
+        5 => Seniority::Principal,
+        _ => Seniority::Distinguished,
+    }
+}
+
+fn roman_numeral(token: &str) -> Option<u32> {
+    match token {
+        "i" => Some(1),
+        "ii" => Some(2),
+        "iii" => Some(3),
+        "iv" => Some(4),
+        "v" => Some(5),
+        _ => None,
+    }
+}
+
+fn level_code(token: &str) -> Option<Seniority> {
+    let caps = LEVEL_CODE_RE.captures(token)?;
+    let level: u32 = caps[2].parse().ok()?;
+
+    // Each ladder starts at a different number, shift them onto `ic_level`'s 1 = junior scale
+    let seniority = match &caps[1] {
+        "l" | "e" => ic_level(level.saturating_sub(2)),
+        "ic" => ic_level(level.saturating_sub(1)),
+        _ => match level {
+            0 | 1 => Seniority::Manager,
+            2 => Seniority::SeniorManager,
+            3 => Seniority::Director,
+            _ => Seniority::Executive,
+        },
+    };
+
+    Some(seniority)
+}
+
+/// Infers the level of a job from its title, None when the title says nothing about it
+///
+/// Keywords win over level codes, which win over trailing numerals, so "Senior Engineer II" is Senior.
+pub fn classify_seniority(title: &str) -> Option<Seniority> {
+    let normalized: String = title
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let tokens: Vec<&str> = normalized.split_whitespace().collect();
+
+    let has = |word: &str| tokens.contains(&word);
+    let has_phrase =
+        |phrase: &str| format!(" {} ", tokens.join(" ")).contains(&format!(" {} ", phrase));
+    let is_senior = has("senior") || has("sr");
+
+    if has("intern") || has("internship") || has("coop") || has_phrase("co op") || has("apprentice")
+    {
+        return Some(Seniority::Intern);
+    }
+
+    if has("vp") || has("svp") || has("evp") || has("chief") || has_phrase("vice president") {
+        return Some(Seniority::Executive);
+    }
+
+    if has("director") || has_phrase("head of") {
+        return Some(Seniority::Director);
+    }
+
+    let people_manager = tokens.iter().enumerate().any(|(i, token)| {
+        (*token == "manager" || *token == "mgr")
+            && !i
+                .checked_sub(1)
+                .is_some_and(|prev| IC_MANAGER_PREFIXES.contains(&tokens[prev]))
+    });
+
+    if people_manager {
+        return Some(if is_senior || has("principal") || has("staff") {
+            Seniority::SeniorManager
+        } else {
+            Seniority::Manager
+        });
+    }
+
+    if has("distinguished") || has("fellow") {
+        return Some(Seniority::Distinguished);
+    }
+    if has("principal") {
+        return Some(Seniority::Principal);
+    }
+    if has("staff") {
+        return Some(Seniority::Staff);
+    }
+    if is_senior || has("lead") {
+        return Some(Seniority::Senior);
+    }
+    if has("junior")
+        || has("jr")
+        || has("entry")
+        || has_phrase("new grad")
+        || has("graduate")
+        || has("associate")
+    {
+        return Some(Seniority::Junior);
+    }
+    if has("mid") || has("intermediate") {
+        return Some(Seniority::Mid);
+    }
+
+    if let Some(seniority) = tokens.iter().find_map(|t| level_code(t)) {
+        return Some(seniority);
+    }
+
+    // A numeral only counts right after the role ("Engineer II") or at the very end ("Engineer, Backend 3")
+    tokens.iter().enumerate().find_map(|(i, token)| {
+        let level = roman_numeral(token)
+            .or_else(|| token.parse::<u32>().ok().filter(|l| (1..=5).contains(l)))?;
+        let after_role = i
+            .checked_sub(1)
+            .is_some_and(|prev| ROLE_NOUNS.contains(&tokens[prev]));
+
+        (after_role || i == tokens.len() - 1).then(|| ic_level(level))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_classify_keywords() {
+        let cases = [
+            (
+                "Software Engineering Intern, Summer 2025",
+                Some(Seniority::Intern),
+            ),
+            ("Sr. Software Engineer", Some(Seniority::Senior)),
+            (
+                "Senior Staff Engineer, Infrastructure",
+                Some(Seniority::Staff),
+            ),
+            ("Principal Product Manager", Some(Seniority::Principal)),
+            ("Distinguished Engineer", Some(Seniority::Distinguished)),
+            ("Tech Lead, Payments", Some(Seniority::Senior)),
+            ("Junior Data Analyst", Some(Seniority::Junior)),
+            ("Software Engineer, New Grad", Some(Seniority::Junior)),
+            ("Engineering Manager, Ads", Some(Seniority::Manager)),
+            (
+                "Senior Manager, Software Engineering",
+                Some(Seniority::SeniorManager),
+            ),
+            (
+                "Associate Director, Data Science",
+                Some(Seniority::Director),
+            ),
+            ("VP of Engineering", Some(Seniority::Executive)),
+            ("Product Manager", None),
+            ("Internal Tools Engineer", None),
+            ("Software Engineer", None),
+        ];
+
+        for (title, expected) in cases {
+            assert_eq!(classify_seniority(title), expected, "{}", title);
+        }
+    }
+
+    #[test]
+    fn test_classify_levels() {
+        let cases = [
+            ("Software Engineer I", Some(Seniority::Junior)),
+            ("Software Engineer II, Backend", Some(Seniority::Mid)),
+            ("Data Scientist III", Some(Seniority::Senior)),
+            ("Software Engineer IV", Some(Seniority::Staff)),
+            ("Software Engineer 2", Some(Seniority::Mid)),
+            ("Software Engineer (L5)", Some(Seniority::Senior)),
+            ("Production Engineer - E6", Some(Seniority::Staff)),
+            ("iOS Engineer IC4", Some(Seniority::Senior)),
+            ("Engineering Leader M2", Some(Seniority::SeniorManager)),
+            // "I" in the middle of a title is not a level
+            ("Tier I Support Specialist", None),
+            ("Web 3 Engineer", None),
+        ];
+
+        for (title, expected) in cases {
+            assert_eq!(classify_seniority(title), expected, "{}", title);
+        }
+    }
+}
@@ -1,26 +1,43 @@
 use std::{error::Error, thread::sleep, time::Duration};
 
+use chrono::Utc;
 use colored::Colorize;
-use dialoguer::{theme::ColorfulTheme, Confirm, FuzzySelect, Input};
+use dialoguer::{theme::ColorfulTheme, Confirm, FuzzySelect, Input, Select};
 use indicatif::{ProgressBar, ProgressStyle};
+use strum::IntoEnumIterator;
 use jobshell::utils::{clear_console, stall_and_present_countdown};
 use tabled::{settings::Style, Table, Tabled};
 
 use crate::{
+    background_scan::BackgroundScan,
     company_options::ScrapeJobs,
+    config,
     error::AppResult,
     handlers::handlers::{
+        handle_add_job_reminder, handle_agenda, handle_application_status, handle_job_annotations,
         handle_job_selection, handle_manage_connection, handle_manage_smart_criteria,
-        handle_open_job_in_browser, handle_reach_out_to_a_connection,
-        handle_scan_new_jobs_across_network_and_followed_companies, handle_view_new_jobs_reports,
-        prompt_user_for_company_option, prompt_user_for_company_selection_v2,
-        prompt_user_for_job_option, prompt_user_for_main_menu_selection, FormattedJob, JobOption,
-        MainMenuOption, SelectedCompanyOption,
+        handle_open_job_in_browser, handle_reach_out_to_a_connection, handle_switch_profile,
+        handle_view_job_description, handle_view_new_jobs_reports, print_agenda_summary,
+        print_saved_search_matches, prompt_user_for_company_option,
+        prompt_user_for_company_selection_v2, prompt_user_for_discord_webhook,
+        prompt_user_for_job_filter, prompt_user_for_job_option,
+        prompt_user_for_main_menu_selection, prompt_user_for_saved_search_option, FormattedJob,
+        JobOption, MainMenuOption, SavedSearchOption, SelectedCompanyOption,
     },
     models::{
-        data::{Connection, Data},
+        data::{AnalyzeData, Connection, Data},
+        filter::JobFilter,
+        pipeline::ApplicationStatus,
+        reminder::Agenda,
+        run::{
+            company_runs_table, format_duration_ms, runs_table, scraper_stats_table, RunTrigger,
+            ScanRun, ScraperStats,
+        },
+        saved_search::{match_saved_searches, SavedSearch},
         scraper::{Job, JobsPayload},
+        search::{SearchQuery, SEARCH_SYNTAX_HELP},
     },
+    profiles,
 };
 
 pub async fn run() -> AppResult<()> {
@@ -37,23 +54,51 @@ pub async fn run() -> AppResult<()> {
     println!("{logo}");
     sleep(Duration::from_secs(3));
 
+    // INFO: Surface overdue and upcoming follow-ups once on startup
+    let startup_data = Data::get_data();
+    if !Agenda::build(&startup_data.reminders, Utc::now()).is_empty() {
+        clear_console();
+        print_agenda_summary(&startup_data);
+
+        Input::<String>::new()
+            .with_prompt("Press enter to continue")
+            .allow_empty(true)
+            .interact()?;
+    }
+
+    // INFO: Lives across menu iterations so scanning doesn't block the menus
+    let mut background_scan: Option<BackgroundScan> = None;
+
     // INFO: Main App loop
     loop {
         let mut data = Data::get_data();
         clear_console();
 
-        // let counts = data.get_job_counts();
-        //
-        // println!("{:#?}", counts);
-        // sleep(Duration::from_secs(10));
+        let active_profile = profiles::active_profile();
+        if active_profile != profiles::DEFAULT_PROFILE {
+            println!("Profile: {}", active_profile.cyan());
+        }
+
+        if let Some(scan) = &background_scan {
+            scan.merge_into(&mut data);
+            print_background_scan_status(scan);
+        }
 
         match prompt_user_for_main_menu_selection() {
+            MainMenuOption::ApplicationPipeline => handle_view_application_pipeline(&mut data).await?,
+            MainMenuOption::Agenda => handle_agenda(&mut data)?,
+            MainMenuOption::BrowseJobs => handle_browse_jobs(&mut data).await?,
+            MainMenuOption::SearchJobs => handle_search_jobs(&mut data).await?,
+            MainMenuOption::SavedSearches => handle_saved_searches(&mut data).await?,
             MainMenuOption::ViewBookmarkedJobs => {
                 #[derive(Tabled, Debug)]
                 struct DisplayJob {
                     company: String,
                     title: String,
                     location: String,
+                    posted: String,
+                    status: String,
+                    tags: String,
                     link: String,
                 }
 
@@ -74,13 +119,19 @@ pub async fn run() -> AppResult<()> {
                                                 link: j.link.to_string(),
                                                 company: company_name.to_string(),
                                                 location: j.location.to_string(),
+                                                posted: j.metadata.posted_label(),
+                                                status: j.status_label(),
+                                                tags: j.tags_label(),
                                             },
                                             FormattedJob {
                                                 job: j.clone(),
                                                 company: company_name.clone(),
                                                 display_name: format!(
-                                                    "{} | {} | {}",
-                                                    j.title, j.location, company_name
+                                                    "{} | {} | {} {}",
+                                                    j.title,
+                                                    j.location,
+                                                    company_name,
+                                                    j.annotations_label()
                                                 ),
                                             },
                                         )
@@ -322,39 +373,20 @@ pub async fn run() -> AppResult<()> {
                 }
             }
             MainMenuOption::ScanForNewJobsAcrossNetworkAndFollowedCompanies => {
-                match handle_scan_new_jobs_across_network_and_followed_companies(&mut data).await {
-                    Ok(new_jobs) => loop {
-                        let options = new_jobs
-                            .iter()
-                            .map(|fj| fj.display_name.clone())
-                            .chain(vec!["Exit".to_string()])
-                            .collect::<Vec<String>>();
-
-                        let selection = FuzzySelect::new()
-                            .with_prompt("Select a job")
-                            .items(&options)
-                            .interact()
-                            .unwrap();
-
-                        let selected_job = &options[selection];
-
-                        if selected_job == "Exit" {
-                            break;
+                background_scan = match background_scan.take() {
+                    Some(scan) => handle_background_scan(scan, &mut data).await?,
+                    None => match handle_start_background_scan(&data) {
+                        Ok(scan) => Some(scan),
+                        Err(e) => {
+                            stall_and_present_countdown(3, Some(e.to_string()));
+                            None
                         }
-
-                        let selected_job = &new_jobs[selection];
-
-                        data.mark_job_seen(&selected_job.job.id);
-
-                        handle_job_option(&selected_job.job, &mut data, &selected_job.company)
-                            .await?;
                     },
-                    Err(e) => {
-                        stall_and_present_countdown(3, Some(e.to_string()));
-                    }
-                }
+                };
             }
             MainMenuOption::ViewNewJobsReports => handle_view_new_jobs_reports()?,
+            MainMenuOption::JobMarketStats => handle_view_job_market_stats(&data)?,
+            MainMenuOption::ScanHistory => handle_view_scan_history()?,
             MainMenuOption::MyConnections => {
                 clear_console();
                 let all_connections: Vec<&Connection> = data
@@ -384,13 +416,757 @@ pub async fn run() -> AppResult<()> {
                 continue;
             }
             MainMenuOption::ManageSmartCriteria => handle_manage_smart_criteria(),
-            _ => break,
+            MainMenuOption::SwitchProfile => {
+                // INFO: The scan reads and merges into the active profile's data
+                if background_scan.as_ref().is_some_and(|s| !s.is_finished()) {
+                    stall_and_present_countdown(
+                        3,
+                        Some("Wait for the scan to finish or cancel it before switching profiles"),
+                    );
+                    continue;
+                }
+
+                background_scan = None;
+                handle_switch_profile()?
+            }
+            _ => {
+                if background_scan.as_ref().is_some_and(|s| !s.is_finished())
+                    && !Confirm::with_theme(&dialoguer_styles)
+                        .with_prompt("A scan is still running, exit anyway?")
+                        .default(false)
+                        .interact()?
+                {
+                    continue;
+                }
+
+                break;
+            }
         }
     }
 
     Ok(())
 }
 
+fn print_background_scan_status(scan: &BackgroundScan) {
+    let progress = scan.progress();
+
+    if progress.finished {
+        println!(
+            "{} {}",
+            "✅".green(),
+            format!(
+                "{}, choose \"{}\" to see them",
+                progress.status_line(),
+                MainMenuOption::ScanForNewJobsAcrossNetworkAndFollowedCompanies
+            )
+            .green()
+        );
+    } else {
+        println!("⏳ {}", progress.status_line().cyan());
+    }
+}
+
+fn handle_start_background_scan(data: &Data) -> AppResult<BackgroundScan> {
+    let companies = BackgroundScan::network_companies(data);
+
+    if companies.is_empty() {
+        return Err("Looks like you’re not connected with any companies yet or following any. Start building your network by adding connections or following companies you’re interested in!".into());
+    }
+
+    let scan = BackgroundScan::start(companies, data, RunTrigger::Menu);
+    scan.show_progress_in_terminal_title();
+
+    Ok(scan)
+}
+
+// INFO: Shows a running scan's progress, or its new jobs once it is done. Hands the scan back while it is still needed
+async fn handle_background_scan(
+    scan: BackgroundScan,
+    data: &mut Data,
+) -> AppResult<Option<BackgroundScan>> {
+    let dialoguer_styles = ColorfulTheme::default();
+
+    loop {
+        clear_console();
+        scan.merge_into(data);
+
+        // INFO: Copy out what we need, the scan's task can't update while the lock is held
+        let (log, status, finished, new_jobs) = {
+            let progress = scan.progress();
+            (
+                progress.log.clone(),
+                progress.status_line(),
+                progress.finished,
+                progress.results().to_vec(),
+            )
+        };
+
+        for line in log.iter() {
+            println!("{}", line);
+        }
+
+        if !finished {
+            println!("\n⏳ {}\n", status.cyan());
+
+            let options = ["Refresh", "Cancel Scan", "Back"];
+            let selection = Select::with_theme(&dialoguer_styles)
+                .with_prompt("The scan keeps running in the background")
+                .items(&options)
+                .default(0)
+                .interact()?;
+
+            match selection {
+                0 => continue,
+                1 => {
+                    scan.cancel();
+                    continue;
+                }
+                _ => return Ok(Some(scan)),
+            }
+        }
+
+        println!("\n{}\n", status.green());
+
+        let scanned_jobs: Vec<(String, Job)> = new_jobs
+            .iter()
+            .map(|fj| (fj.company.clone(), fj.job.clone()))
+            .collect();
+        print_saved_search_matches(&match_saved_searches(
+            &data.saved_searches,
+            &scanned_jobs,
+            Utc::now(),
+        ));
+
+        if new_jobs.is_empty() {
+            stall_and_present_countdown(
+                3,
+                Some("No new jobs found across your network and followed companies"),
+            );
+            return Ok(None);
+        }
+
+        loop {
+            let options = new_jobs
+                .iter()
+                .map(|fj| fj.display_name.clone())
+                .chain(vec!["Exit".to_string()])
+                .collect::<Vec<String>>();
+
+            let selection = FuzzySelect::new()
+                .with_prompt("Select a job")
+                .items(&options)
+                .interact()
+                .unwrap();
+
+            if selection == new_jobs.len() {
+                return Ok(None);
+            }
+
+            let selected_job = &new_jobs[selection];
+
+            data.mark_job_seen(&selected_job.job.id);
+
+            handle_job_option(&selected_job.job, data, &selected_job.company).await?;
+        }
+    }
+}
+
+// INFO: Jobs across all companies narrowed down by a JobFilter
+async fn handle_browse_jobs(data: &mut Data) -> AppResult<()> {
+    let mut filter = JobFilter::default();
+
+    loop {
+        let Some(next_filter) = prompt_user_for_job_filter(filter.clone(), data)? else {
+            return Ok(());
+        };
+        filter = next_filter;
+
+        browse_filtered_jobs(&filter, data, "Edit Filter").await?;
+    }
+}
+
+// INFO: Lists the jobs matching a filter until the user picks `exit_label` or nothing matches
+async fn browse_filtered_jobs(filter: &JobFilter, data: &mut Data, exit_label: &str) -> AppResult<()> {
+    #[derive(Tabled)]
+    struct DisplayJob {
+        company: String,
+        title: String,
+        location: String,
+        level: String,
+        department: String,
+        #[tabled(rename = "type")]
+        employment_type: String,
+        remote: String,
+        salary: String,
+        posted: String,
+    }
+
+    // INFO: Enough rows to get an overview, the fuzzy select below still lists every match
+    let max_table_rows = config::get().ui.page_size;
+
+    loop {
+        clear_console();
+
+        let formatted_jobs: Vec<FormattedJob> = filter
+            .apply(data, Utc::now())
+            .into_iter()
+            .map(|(company, j)| FormattedJob {
+                job: j.clone(),
+                company: company.clone(),
+                display_name: format!(
+                    "{} | {} | {} {}",
+                    j.title,
+                    j.location,
+                    company,
+                    j.annotations_label()
+                ),
+            })
+            .collect();
+
+        if formatted_jobs.is_empty() {
+            println!("No jobs match this filter.");
+            sleep(Duration::from_secs(2));
+            return Ok(());
+        }
+
+        let display_jobs = formatted_jobs.iter().take(max_table_rows).map(|fj| DisplayJob {
+            company: fj.company.clone(),
+            title: fj.job.title.clone(),
+            location: fj.job.location.clone(),
+            level: fj.job.seniority_label(),
+            department: fj.job.metadata.department.clone().unwrap_or_default(),
+            employment_type: fj.job.metadata.employment_type_label(),
+            remote: fj.job.metadata.remote_label(),
+            salary: fj.job.salary_label(),
+            posted: fj.job.metadata.posted_label(),
+        });
+
+        let mut table = Table::new(display_jobs);
+        table.with(Style::modern());
+        println!("{table}");
+        println!("{} matching jobs\n", formatted_jobs.len().to_string().green());
+
+        let mut titles = formatted_jobs
+            .iter()
+            .map(|job| job.display_name.clone())
+            .collect::<Vec<String>>();
+
+        titles.push(exit_label.to_string());
+
+        let idx = FuzzySelect::with_theme(&ColorfulTheme::default())
+            .items(&titles)
+            .with_prompt("Select a job")
+            .interact()?;
+
+        if idx == formatted_jobs.len() {
+            return Ok(());
+        }
+
+        let selected_formatted_job = &formatted_jobs[idx];
+
+        handle_job_option(
+            &selected_formatted_job.job,
+            data,
+            selected_formatted_job.company.as_str(),
+        )
+        .await?;
+    }
+}
+
+// INFO: Named filters, each one is run over the new jobs of every scan
+async fn handle_saved_searches(data: &mut Data) -> AppResult<()> {
+    const CREATE_SAVED_SEARCH: &str = "Create Saved Search";
+
+    let dialoguer_styles = ColorfulTheme::default();
+
+    loop {
+        clear_console();
+
+        let mut options: Vec<String> = data
+            .saved_searches
+            .iter()
+            .map(|s| s.display_string())
+            .collect();
+        options.push(CREATE_SAVED_SEARCH.to_string());
+        options.push("Back".to_string());
+
+        let idx = Select::with_theme(&dialoguer_styles)
+            .with_prompt("Saved Searches")
+            .items(&options)
+            .interact()?;
+
+        // The saved searches come first, followed by the two fixed options
+        if idx < data.saved_searches.len() {
+            handle_saved_search(data.saved_searches[idx].clone(), data).await?;
+        } else if options[idx] == CREATE_SAVED_SEARCH {
+            let name = Input::<String>::with_theme(&dialoguer_styles)
+                .with_prompt("Name (e.g. Rust backend remote)")
+                .interact_text()?;
+
+            if let Some(filter) = prompt_user_for_job_filter(JobFilter::default(), data)? {
+                data.upsert_saved_search(SavedSearch::new(name.trim().to_string(), filter));
+            }
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+async fn handle_saved_search(mut search: SavedSearch, data: &mut Data) -> AppResult<()> {
+    let dialoguer_styles = ColorfulTheme::default();
+
+    loop {
+        clear_console();
+        println!("{}", search.name.bold());
+        for line in search.filter.describe() {
+            println!("  {}", line.cyan());
+        }
+        if let Some(webhook_url) = &search.discord_webhook_url {
+            println!("  {}", format!("Discord: {}", webhook_url).dimmed());
+        }
+        println!();
+
+        match prompt_user_for_saved_search_option(&search.name)? {
+            SavedSearchOption::ViewMatchingJobs => {
+                browse_filtered_jobs(&search.filter, data, "Back").await?
+            }
+            SavedSearchOption::EditFilter => {
+                if let Some(filter) = prompt_user_for_job_filter(search.filter.clone(), data)? {
+                    search.filter = filter;
+                    data.upsert_saved_search(search.clone());
+                }
+            }
+            SavedSearchOption::Rename => {
+                search.name = Input::<String>::with_theme(&dialoguer_styles)
+                    .with_prompt("Name")
+                    .with_initial_text(search.name.clone())
+                    .interact_text()?
+                    .trim()
+                    .to_string();
+                data.upsert_saved_search(search.clone());
+            }
+            SavedSearchOption::SetDiscordWebhook => {
+                search.discord_webhook_url =
+                    prompt_user_for_discord_webhook(search.discord_webhook_url.clone())?;
+                data.upsert_saved_search(search.clone());
+            }
+            SavedSearchOption::Delete => {
+                let confirmed = Confirm::with_theme(&dialoguer_styles)
+                    .with_prompt(format!("Delete {}?", search.name))
+                    .default(false)
+                    .interact()?;
+
+                if confirmed {
+                    data.delete_saved_search(&search.id);
+                    return Ok(());
+                }
+            }
+            SavedSearchOption::Back => return Ok(()),
+        }
+    }
+}
+
+// INFO: Full-text search over every company's jobs, descriptions, notes and tags
+async fn handle_search_jobs(data: &mut Data) -> AppResult<()> {
+    #[derive(Tabled)]
+    struct DisplayJob {
+        company: String,
+        title: String,
+        location: String,
+        level: String,
+        salary: String,
+        status: String,
+    }
+
+    let max_table_rows = config::get().ui.page_size;
+    const NEW_SEARCH: &str = "New Search";
+
+    let dialoguer_styles = ColorfulTheme::default();
+    let mut last_search = String::new();
+
+    loop {
+        clear_console();
+        println!("{}\n", SEARCH_SYNTAX_HELP.dimmed());
+
+        let input = Input::<String>::with_theme(&dialoguer_styles)
+            .with_prompt("Search (leave empty to go back)")
+            .with_initial_text(last_search.clone())
+            .allow_empty(true)
+            .interact_text()?;
+
+        if input.trim().is_empty() {
+            return Ok(());
+        }
+        last_search = input.clone();
+
+        let query = match SearchQuery::parse(&input) {
+            Ok(query) => query,
+            Err(e) => {
+                println!("{}", e.to_string().red());
+                sleep(Duration::from_secs(3));
+                continue;
+            }
+        };
+
+        loop {
+            clear_console();
+
+            let formatted_jobs: Vec<FormattedJob> = data
+                .search_jobs(&query)
+                .into_iter()
+                .map(|(company, j)| FormattedJob {
+                    display_name: format!(
+                        "{} | {} | {} {}",
+                        j.title,
+                        j.location,
+                        company,
+                        j.annotations_label()
+                    ),
+                    job: j,
+                    company,
+                })
+                .collect();
+
+            if formatted_jobs.is_empty() {
+                println!("No jobs match \"{}\".", input);
+                sleep(Duration::from_secs(2));
+                break;
+            }
+
+            let display_jobs = formatted_jobs.iter().take(max_table_rows).map(|fj| DisplayJob {
+                company: fj.company.clone(),
+                title: fj.job.title.clone(),
+                location: fj.job.location.clone(),
+                level: fj.job.seniority_label(),
+                salary: fj.job.salary_label(),
+                status: fj.job.status_label(),
+            });
+
+            let mut table = Table::new(display_jobs);
+            table.with(Style::modern());
+            println!("{table}");
+            println!(
+                "{} jobs match \"{}\"\n",
+                formatted_jobs.len().to_string().green(),
+                input
+            );
+
+            let mut titles = formatted_jobs
+                .iter()
+                .map(|job| job.display_name.clone())
+                .collect::<Vec<String>>();
+
+            titles.push(NEW_SEARCH.to_string());
+
+            let idx = FuzzySelect::with_theme(&dialoguer_styles)
+                .items(&titles)
+                .with_prompt("Select a job")
+                .interact()?;
+
+            if titles[idx] == NEW_SEARCH {
+                break;
+            }
+
+            let selected_formatted_job = &formatted_jobs[idx];
+
+            handle_job_option(
+                &selected_formatted_job.job,
+                data,
+                selected_formatted_job.company.as_str(),
+            )
+            .await?;
+        }
+    }
+}
+
+// INFO: Every job with application activity, grouped by pipeline stage across all companies
+async fn handle_view_application_pipeline(data: &mut Data) -> AppResult<()> {
+    #[derive(Tabled)]
+    struct DisplayJob {
+        company: String,
+        title: String,
+        location: String,
+        since: String,
+    }
+
+    loop {
+        clear_console();
+
+        let mut pipeline_jobs: Vec<(ApplicationStatus, FormattedJob)> = data
+            .companies
+            .iter()
+            .flat_map(|(company_name, c)| {
+                c.jobs.iter().filter_map(move |j| {
+                    j.application_status().map(|status| {
+                        (
+                            status,
+                            FormattedJob {
+                                job: j.clone(),
+                                company: company_name.clone(),
+                                display_name: format!(
+                                    "{} {} | {} | {} | {}",
+                                    status.emoji(),
+                                    status,
+                                    j.title,
+                                    j.location,
+                                    company_name
+                                ),
+                            },
+                        )
+                    })
+                })
+            })
+            .collect();
+
+        if pipeline_jobs.is_empty() {
+            println!("No jobs in your application pipeline yet.");
+            sleep(Duration::from_secs(3));
+            return Ok(());
+        }
+
+        let closed_count = pipeline_jobs.iter().filter(|(s, _)| s.is_closed()).count();
+
+        println!(
+            "{} active, {} closed\n",
+            (pipeline_jobs.len() - closed_count).to_string().green(),
+            closed_count.to_string().red()
+        );
+
+        let mut formatted_jobs: Vec<FormattedJob> = Vec::new();
+
+        for status in ApplicationStatus::iter() {
+            let (stage_jobs, rest): (Vec<_>, Vec<_>) =
+                pipeline_jobs.into_iter().partition(|(s, _)| *s == status);
+            pipeline_jobs = rest;
+
+            if stage_jobs.is_empty() {
+                continue;
+            }
+
+            println!(
+                "{}",
+                format!("{} {} ({})", status.emoji(), status, stage_jobs.len()).bold()
+            );
+
+            let display_jobs = stage_jobs.iter().map(|(_, fj)| DisplayJob {
+                company: fj.company.clone(),
+                title: fj.job.title.clone(),
+                location: fj.job.location.clone(),
+                since: fj
+                    .job
+                    .status_history
+                    .last()
+                    .map(|c| c.changed_at.format("%Y-%m-%d").to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+            });
+
+            let mut table = Table::new(display_jobs);
+            table.with(Style::modern());
+            println!("{table}\n");
+
+            formatted_jobs.extend(stage_jobs.into_iter().map(|(_, fj)| fj));
+        }
+
+        let mut titles = formatted_jobs
+            .iter()
+            .map(|job| job.display_name.clone())
+            .collect::<Vec<String>>();
+
+        titles.push("Exit".to_string());
+
+        let idx = FuzzySelect::with_theme(&ColorfulTheme::default())
+            .items(&titles)
+            .with_prompt("Select a job")
+            .interact()?;
+
+        if titles[idx] == "Exit" {
+            return Ok(());
+        }
+
+        let selected_formatted_job = &formatted_jobs[idx];
+
+        handle_job_option(
+            &selected_formatted_job.job,
+            data,
+            selected_formatted_job.company.as_str(),
+        )
+        .await?;
+    }
+}
+
+// INFO: Open jobs per seniority level, across all companies or for a single one
+fn handle_view_job_market_stats(data: &Data) -> AppResult<()> {
+    #[derive(Tabled)]
+    struct DisplayCount {
+        level: String,
+        track: String,
+        jobs: usize,
+        share: String,
+    }
+
+    const ALL_COMPANIES: &str = "All Companies";
+
+    let mut companies: Vec<String> = data
+        .companies
+        .iter()
+        .filter(|(_, c)| c.jobs.iter().any(|j| !j.is_closed()))
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    if companies.is_empty() {
+        println!("No jobs scraped yet, select a company or run a scan first.");
+        sleep(Duration::from_secs(3));
+        return Ok(());
+    }
+
+    companies.sort();
+    companies.insert(0, ALL_COMPANIES.to_string());
+
+    let dialoguer_styles = ColorfulTheme::default();
+    let mut scope = 0;
+
+    loop {
+        clear_console();
+
+        let selected_companies = if scope == 0 {
+            Vec::new()
+        } else {
+            vec![companies[scope].clone()]
+        };
+        let counts = data.get_job_counts(&selected_companies);
+
+        let share = |count: usize| format!("{:.1}%", count as f64 * 100.0 / counts.total as f64);
+
+        let mut rows: Vec<DisplayCount> = counts
+            .by_seniority
+            .iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(seniority, count)| DisplayCount {
+                level: seniority.to_string(),
+                track: if seniority.is_management() {
+                    "Management".to_string()
+                } else {
+                    "Individual Contributor".to_string()
+                },
+                jobs: *count,
+                share: share(*count),
+            })
+            .collect();
+
+        rows.push(DisplayCount {
+            level: "Unidentified".to_string(),
+            track: String::new(),
+            jobs: counts.unidentified,
+            share: share(counts.unidentified),
+        });
+
+        println!(
+            "{} | {} open jobs\n",
+            companies[scope].bold(),
+            counts.total.to_string().green()
+        );
+
+        let mut table = Table::new(rows);
+        table.with(Style::modern());
+        println!("{table}\n");
+
+        let options = ["Change Company", "Export CSV", "Back"];
+        let idx = Select::with_theme(&dialoguer_styles)
+            .with_prompt("Select an option")
+            .items(&options)
+            .interact()?;
+
+        match options[idx] {
+            "Change Company" => {
+                scope = FuzzySelect::with_theme(&dialoguer_styles)
+                    .with_prompt("Select a company")
+                    .items(&companies)
+                    .default(scope)
+                    .interact()?;
+            }
+            "Export CSV" => {
+                counts.export_csv()?;
+                println!("Saved to {}", "./job_data.csv".green());
+                sleep(Duration::from_secs(2));
+            }
+            _ => return Ok(()),
+        }
+    }
+}
+
+// INFO: Recent scans, how each company did in one, and which scrapers are slow or failing
+fn handle_view_scan_history() -> AppResult<()> {
+    const RECENT_RUNS: usize = 20;
+
+    let dialoguer_styles = ColorfulTheme::default();
+
+    loop {
+        clear_console();
+
+        let runs = ScanRun::load_all();
+        if runs.is_empty() {
+            println!("No scans recorded yet, run a scan first.");
+            sleep(Duration::from_secs(3));
+            return Ok(());
+        }
+
+        let recent = &runs[..RECENT_RUNS.min(runs.len())];
+        println!("{}\n", runs_table(recent));
+
+        let options = ["View a Scan", "Scraper Stats", "Back"];
+        let idx = Select::with_theme(&dialoguer_styles)
+            .with_prompt("Select an option")
+            .items(&options)
+            .interact()?;
+
+        match options[idx] {
+            "View a Scan" => {
+                let run_names: Vec<String> = recent
+                    .iter()
+                    .map(|r| {
+                        format!(
+                            "{} | {} | {} companies, {} new, {} errors",
+                            r.started_at.format("%Y-%m-%d %H:%M"),
+                            r.trigger,
+                            r.companies.len(),
+                            r.new_jobs(),
+                            r.errors()
+                        )
+                    })
+                    .collect();
+                let selection = Select::with_theme(&dialoguer_styles)
+                    .with_prompt("Select a scan")
+                    .items(&run_names)
+                    .interact()?;
+                let run = &recent[selection];
+
+                clear_console();
+                println!(
+                    "{} | {} | {}\n",
+                    run.started_at.format("%Y-%m-%d %H:%M UTC").to_string().bold(),
+                    run.trigger,
+                    format_duration_ms(run.duration_ms())
+                );
+                println!("{}\n", company_runs_table(run));
+            }
+            "Scraper Stats" => {
+                clear_console();
+                println!(
+                    "{}\n",
+                    format!("Across the last {} scans", runs.len()).bold()
+                );
+                println!("{}\n", scraper_stats_table(&ScraperStats::from_runs(&runs)));
+            }
+            _ => return Ok(()),
+        }
+
+        Select::with_theme(&dialoguer_styles)
+            .items(&["Back"])
+            .default(0)
+            .interact()?;
+    }
+}
+
 async fn handle_job_option(selected_job: &Job, data: &mut Data, company: &str) -> AppResult<()> {
     loop {
         clear_console();
@@ -408,32 +1184,13 @@ async fn handle_job_option(selected_job: &Job, data: &mut Data, company: &str) -
                     &selected_job,
                 )?;
             }
-            // JobOption::GenerateJobDetails => {
-            //     let job_details = match company {
-            //         // "Weedmaps" => get_weedmaps_jod_details(&selected_job).await?,
-            //         "1Password" => default_get_job_details(&selected_job, true, "body").await?,
-            //         "Tarro" => {
-            //             default_get_job_details(&selected_job, true, "._content_ud4nd_71").await?
-            //         }
-            //         "Discord" => default_get_job_details(&selected_job, true, "body").await?,
-            //         "Palantir" => default_get_job_details(&selected_job, true, ".content").await?,
-            //         "Anduril" => default_get_job_details(&selected_job, true, "main").await?,
-            //         "Coinbase" => {
-            //             default_get_job_details(
-            //                 &selected_job,
-            //                 false,
-            //                 ".Flex-sc-9cfb0d13-0.Listing__Container-sc-bcedfe82-0.fXHNQM.dBburU",
-            //             )
-            //             .await?
-            //         }
-            //         _ => default_get_job_details(&selected_job, true, "body").await?,
-            //     };
-            //
-            //     // Print details
-            //     // clear_console();
-            //     job_details.print_job();
-            // }
+            JobOption::ViewDescription => {
+                handle_view_job_description(company, selected_job, data).await?
+            }
             JobOption::Bookmark => data.toggle_job_bookmark(&selected_job.id),
+            JobOption::ApplicationStatus => handle_application_status(&data_job.clone(), data)?,
+            JobOption::Annotations => handle_job_annotations(&selected_job.id, data)?,
+            JobOption::AddReminder => handle_add_job_reminder(&selected_job.id, data)?,
             JobOption::Back => break,
         }
     }
@@ -0,0 +1,653 @@
+use std::path::PathBuf;
+
+use chrono::Utc;
+use colored::Colorize;
+use strum::IntoEnumIterator;
+use tabled::{settings::Style, Table, Tabled};
+use tracing::Instrument;
+use uuid::Uuid;
+
+use crate::{
+    args::{
+        Command, CompaniesCommand, ConfigCommand, ConnectionArgs, ConnectionsCommand, JobsCommand, JobsListArgs,
+        JobsMarkArgs, OutputFormat, ReportsCommand, RunsCommand, ScanArgs,
+    },
+    company_options::{CompanyOption, ScrapeJobs},
+    completions, config, daemon,
+    error::AppResult,
+    handlers::handlers::{print_saved_search_matches, FormattedJob},
+    models::{
+        data::{Connection, Data},
+        description::JobDescription,
+        filter::JobFilter,
+        run::{
+            company_runs_table, runs_table, scraper_stats_table, CompanyTimer, RunTrigger, ScanRun,
+            ScraperStats,
+        },
+        saved_search::match_saved_searches,
+        scraper::Job,
+    },
+    modes::output::{emit, emit_one, print_schema, CompanyRecord, JobRecord, ReportRecord},
+    reports::{create_report, ReportMode},
+};
+
+// INFO: Every command runs once without prompts, so JobShell can be scripted and run from cron
+pub async fn run(command: Command, format: OutputFormat) -> AppResult<()> {
+    match command {
+        Command::Config(command) => return config_command(command, format),
+        Command::Daemon(args) => return daemon::run(args).await,
+        Command::Runs(command) => return runs(command, format),
+        Command::Completions { shell } => return completions::print_registration(shell),
+        Command::Man { out_dir } => return completions::write_man_pages(out_dir),
+        _ => {}
+    }
+
+    let mut data = Data::get_data();
+
+    match command {
+        Command::Scan(args) => scan(args, format, &mut data).await,
+        Command::Jobs(JobsCommand::List(args)) => list_jobs(args, format, &data),
+        Command::Jobs(JobsCommand::Show { id }) => show_job(&id, format, &data),
+        Command::Jobs(JobsCommand::Mark(args)) => mark_job(args, &mut data),
+        Command::Companies(command) => companies(command, format, &mut data),
+        Command::Connections(command) => connections(command, format, &mut data),
+        Command::Reports(command) => reports(command, format),
+        Command::Schema { record } => print_schema(record),
+        Command::Config(_)
+        | Command::Daemon(_)
+        | Command::Runs(_)
+        | Command::Completions { .. }
+        | Command::Man { .. } => unreachable!(),
+    }
+}
+
+fn print_table<T: Tabled>(rows: Vec<T>) {
+    let mut table = Table::new(rows);
+    table.with(Style::modern());
+    println!("{table}");
+}
+
+/// Finds a job by its full id or a unique prefix of it
+fn resolve_job_id(data: &Data, input: &str) -> AppResult<Uuid> {
+    if let Ok(id) = Uuid::parse_str(input) {
+        return data
+            .find_job(&id)
+            .map(|(_, j)| j.id)
+            .ok_or_else(|| format!("No job with id {}", input).into());
+    }
+
+    let input = input.to_lowercase();
+    let matches: Vec<Uuid> = data
+        .companies
+        .values()
+        .flat_map(|c| c.jobs.iter())
+        .filter(|j| j.id.to_string().starts_with(&input))
+        .map(|j| j.id)
+        .collect();
+
+    match matches.as_slice() {
+        [id] => Ok(*id),
+        [] => Err(format!("No job with id {}", input).into()),
+        _ => Err(format!(
+            "{} jobs start with {}, use more of the id",
+            matches.len(),
+            input
+        )
+        .into()),
+    }
+}
+
+async fn scan(args: ScanArgs, format: OutputFormat, data: &mut Data) -> AppResult<()> {
+    let companies_to_scrape: Vec<CompanyOption> = if !args.company.is_empty() {
+        args.company
+            .iter()
+            .map(|name| CompanyOption::from_name(name))
+            .collect::<AppResult<Vec<CompanyOption>>>()?
+    } else if args.all {
+        CompanyOption::iter().collect()
+    } else {
+        CompanyOption::iter()
+            .filter(|c| {
+                let company = &data.companies[&c.to_string()];
+                company.is_following || !company.connections.is_empty()
+            })
+            .collect()
+    };
+
+    if companies_to_scrape.is_empty() {
+        return Err("No companies to scan, follow a company or pass --company or --all".into());
+    }
+
+    let mut new_jobs: Vec<FormattedJob> = Vec::new();
+    let mut run = ScanRun::start(RunTrigger::Cli);
+    let span = run.span();
+
+    // INFO: Progress goes to stderr so stdout only carries the results
+    for company_option in companies_to_scrape {
+        let company_key = company_option.to_string();
+        eprintln!("Scanning {}", company_key);
+
+        let timer = CompanyTimer::start(&company_key);
+        let jobs_payload = match company_option
+            .scrape_jobs(data)
+            .instrument(span.clone())
+            .await
+        {
+            Ok(jobs_payload) => {
+                run.record(timer.finish(Ok(&jobs_payload)));
+                jobs_payload
+            }
+            Err(e) => {
+                run.record(timer.finish(Err(e.to_string())));
+                eprintln!("{}", format!("Error scanning {}: {}", company_key, e).red());
+                continue;
+            }
+        };
+
+        if jobs_payload.diff.is_baseline && !args.notify_on_baseline {
+            eprintln!(
+                "Recorded {} jobs as the baseline for {}",
+                jobs_payload.all_jobs.len(),
+                company_key
+            );
+        }
+
+        for job in jobs_payload.closed_jobs() {
+            eprintln!("Closed: {} @ {}", job.title, company_key);
+        }
+
+        new_jobs.extend(
+            jobs_payload
+                .jobs_to_notify(args.notify_on_baseline)
+                .into_iter()
+                .map(|j| FormattedJob {
+                    display_name: format!("{} | {} | ({})", j.title, j.location, company_key),
+                    job: j,
+                    company: company_key.clone(),
+                }),
+        );
+    }
+
+    if let Err(e) = run.finish() {
+        eprintln!("{}", format!("Could not save the scan history: {}", e).red());
+    }
+
+    if new_jobs.is_empty() {
+        eprintln!("No new jobs found");
+    } else {
+        create_report(&new_jobs, ReportMode::HTML)?;
+    }
+
+    let scanned_jobs: Vec<(String, Job)> = new_jobs
+        .iter()
+        .map(|fj| (fj.company.clone(), fj.job.clone()))
+        .collect();
+    let saved_search_matches =
+        match_saved_searches(&data.saved_searches, &scanned_jobs, Utc::now());
+
+    let records: Vec<JobRecord> = scanned_jobs
+        .iter()
+        .map(|(company, job)| JobRecord {
+            saved_searches: saved_search_matches
+                .iter()
+                .filter(|m| m.jobs.iter().any(|(_, j)| j.id == job.id))
+                .map(|m| m.search.name.clone())
+                .collect(),
+            ..JobRecord::new(company, job)
+        })
+        .collect();
+
+    emit(format, &records, || {
+        if scanned_jobs.is_empty() {
+            return;
+        }
+
+        print_saved_search_matches(&saved_search_matches);
+        print_job_table(
+            scanned_jobs
+                .iter()
+                .map(|(company, job)| (company.as_str(), job))
+                .collect(),
+        );
+    })
+}
+
+fn print_job_table(jobs: Vec<(&str, &Job)>) {
+    #[derive(Tabled)]
+    struct DisplayJob {
+        id: String,
+        company: String,
+        title: String,
+        location: String,
+        level: String,
+        salary: String,
+        status: String,
+    }
+
+    print_table(
+        jobs.into_iter()
+            .map(|(company, j)| DisplayJob {
+                id: j.id.to_string(),
+                company: company.to_string(),
+                title: j.title.clone(),
+                location: j.location.clone(),
+                level: j.seniority_label(),
+                salary: j.salary_label(),
+                status: match j.application_status() {
+                    Some(status) => format!("{} {}", j.status_label(), status),
+                    None => j.status_label(),
+                },
+            })
+            .collect(),
+    );
+}
+
+fn list_jobs(args: JobsListArgs, format: OutputFormat, data: &Data) -> AppResult<()> {
+    let jobs = listed_jobs(&args, data)?;
+
+    let records: Vec<JobRecord> = jobs
+        .iter()
+        .map(|(company, job)| JobRecord::new(company, job))
+        .collect();
+
+    emit(format, &records, || print_job_table(jobs))
+}
+
+/// The jobs `jobs list` shows for `args`, with the company each belongs to
+fn listed_jobs<'a>(args: &JobsListArgs, data: &'a Data) -> AppResult<Vec<(&'a str, &'a Job)>> {
+    let companies = args
+        .company
+        .iter()
+        .map(|name| CompanyOption::from_name(name).map(|c| c.to_string()))
+        .collect::<AppResult<Vec<String>>>()?;
+
+    let filter = JobFilter {
+        keywords: args.keywords.clone(),
+        companies,
+        remote_only: args.remote,
+        include_closed: args.include_closed,
+        ..Default::default()
+    };
+
+    Ok(filter
+        .apply(data, Utc::now())
+        .into_iter()
+        .filter(|(_, j)| !args.bookmarked || j.is_bookmarked)
+        .filter(|(_, j)| !args.applied || j.applied)
+        .filter(|(_, j)| !args.unseen || !j.is_seen)
+        .take(args.limit.unwrap_or(usize::MAX))
+        .map(|(company, j)| (company.as_str(), j))
+        .collect())
+}
+
+fn show_job(id: &str, format: OutputFormat, data: &Data) -> AppResult<()> {
+    let id = resolve_job_id(data, id)?;
+    let (company, job) = data.find_job(&id).unwrap();
+    let description = JobDescription::load(&job.id);
+
+    let record = JobRecord {
+        description: description.as_ref().map(|d| d.markdown.clone()),
+        ..JobRecord::new(company, job)
+    };
+
+    emit_one(format, &record, || {
+        print_job_details(company, job, description)
+    })
+}
+
+fn print_job_details(company: &str, job: &Job, description: Option<JobDescription>) {
+    let mut fields = vec![
+        ("Id", job.id.to_string()),
+        ("Company", company.to_string()),
+        ("Title", job.title.clone()),
+        ("Location", job.all_locations().join(" / ")),
+        ("Link", job.link.clone()),
+        ("Level", job.seniority_label()),
+        ("Salary", job.salary_label()),
+        ("Remote", job.metadata.remote_label()),
+        ("Posted", job.metadata.posted_label()),
+        ("Status", job.status_label()),
+        ("Seen", job.is_seen.to_string()),
+        ("Bookmarked", job.is_bookmarked.to_string()),
+        (
+            "Application",
+            job.application_status()
+                .map(|s| s.to_string())
+                .unwrap_or_default(),
+        ),
+        ("Tags", job.tags.join(", ")),
+    ];
+
+    for (key, value) in job.custom_fields.iter() {
+        fields.push((key.as_str(), value.clone()));
+    }
+
+    for (key, value) in fields.into_iter().filter(|(_, v)| !v.is_empty()) {
+        println!("{}: {}", key.bold(), value);
+    }
+
+    if !job.notes.trim().is_empty() {
+        println!("\n{}\n{}", "Notes".bold(), job.notes);
+    }
+
+    if let Some(description) = description {
+        println!("\n{}\n{}", "Description".bold(), description.markdown);
+    }
+}
+
+fn mark_job(args: JobsMarkArgs, data: &mut Data) -> AppResult<()> {
+    let id = resolve_job_id(data, &args.id)?;
+
+    if args.applied {
+        data.mark_job_applied(&id);
+    }
+    if args.bookmark || args.unbookmark {
+        data.set_job_bookmarked(&id, args.bookmark);
+    }
+    if args.seen {
+        data.mark_job_seen(&id);
+    }
+
+    let (company, job) = data.find_job(&id).unwrap();
+    eprintln!("Updated {} @ {}", job.title, company);
+
+    Ok(())
+}
+
+fn companies(command: CompaniesCommand, format: OutputFormat, data: &mut Data) -> AppResult<()> {
+    #[derive(Tabled)]
+    struct DisplayCompany {
+        company: String,
+        following: bool,
+        connections: usize,
+        open_jobs: usize,
+    }
+
+    match command {
+        CompaniesCommand::List => {
+            let keys = CompanyOption::keys();
+            let records: Vec<CompanyRecord> = keys
+                .iter()
+                .map(|key| CompanyRecord {
+                    name: key,
+                    company: &data.companies[key],
+                })
+                .collect();
+
+            emit(format, &records, || {
+                print_table(
+                    records
+                        .iter()
+                        .map(|r| DisplayCompany {
+                            company: r.name.to_string(),
+                            following: r.company.is_following,
+                            connections: r.company.connections.len(),
+                            open_jobs: r.company.jobs.iter().filter(|j| !j.is_closed()).count(),
+                        })
+                        .collect(),
+                )
+            })?;
+        }
+        CompaniesCommand::Follow { name } => {
+            let company = CompanyOption::from_name(&name)?.to_string();
+            data.set_company_following(&company, true);
+            eprintln!("Following {}", company);
+        }
+        CompaniesCommand::Unfollow { name } => {
+            let company = CompanyOption::from_name(&name)?.to_string();
+            data.set_company_following(&company, false);
+            eprintln!("No longer following {}", company);
+        }
+    }
+
+    Ok(())
+}
+
+fn connections(
+    command: ConnectionsCommand,
+    format: OutputFormat,
+    data: &mut Data,
+) -> AppResult<()> {
+    match command {
+        ConnectionsCommand::List { company } => {
+            let company = company
+                .map(|name| CompanyOption::from_name(&name).map(|c| c.to_string()))
+                .transpose()?;
+
+            let mut connections: Vec<Connection> = data
+                .companies
+                .iter()
+                .filter(|(key, _)| company.as_ref().is_none_or(|c| c == *key))
+                .flat_map(|(_, c)| c.connections.iter().cloned())
+                .collect();
+            connections.sort_by(|a, b| {
+                (&a.company, &a.last_name, &a.first_name).cmp(&(
+                    &b.company,
+                    &b.last_name,
+                    &b.first_name,
+                ))
+            });
+
+            emit(format, &connections, || print_table(connections.clone()))?;
+        }
+        ConnectionsCommand::Add(ConnectionArgs {
+            company,
+            first_name,
+            last_name,
+            role,
+            email,
+            linkedin,
+            former_employee,
+        }) => {
+            let company = CompanyOption::from_name(&company)?.to_string();
+
+            data.add_connection(Connection {
+                first_name,
+                last_name,
+                company,
+                current_employee: !former_employee,
+                role,
+                email,
+                linkedin,
+            })?;
+            eprintln!("Connection added");
+        }
+        ConnectionsCommand::Remove {
+            company,
+            first_name,
+            last_name,
+        } => {
+            let company = CompanyOption::from_name(&company)?.to_string();
+
+            if !data.remove_connection(&company, &first_name, &last_name) {
+                return Err(format!(
+                    "{} {} is not a connection at {}",
+                    first_name, last_name, company
+                )
+                .into());
+            }
+            eprintln!("Connection removed");
+        }
+    }
+
+    Ok(())
+}
+
+fn report_path(name: &str) -> PathBuf {
+    Data::get_data_dir()
+        .join("reports")
+        .join(format!("{}.html", name))
+}
+
+fn reports(command: ReportsCommand, format: OutputFormat) -> AppResult<()> {
+    // INFO: The reports dir only exists once a scan found new jobs
+    let reports = Data::get_new_jobs_report_files().unwrap_or_default();
+
+    match command {
+        ReportsCommand::List => {
+            let records: Vec<ReportRecord> = reports
+                .iter()
+                .map(|name| ReportRecord {
+                    name: name.clone(),
+                    path: report_path(name).display().to_string(),
+                })
+                .collect();
+
+            emit(format, &records, || {
+                for report in reports.iter() {
+                    println!("{}", report);
+                }
+            })?;
+        }
+        ReportsCommand::Open { name } => {
+            let report = if name == "latest" {
+                reports.first()
+            } else {
+                reports.iter().find(|r| **r == name)
+            }
+            .ok_or_else(|| format!("No report named {}", name))?;
+
+            webbrowser::open(&format!("file://{}", report_path(report).display()))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn runs(command: RunsCommand, format: OutputFormat) -> AppResult<()> {
+    let runs = ScanRun::load_all();
+
+    match command {
+        RunsCommand::List { limit } => {
+            let runs = &runs[..limit.min(runs.len())];
+            emit(format, runs, || {
+                if runs.is_empty() {
+                    eprintln!("No scans recorded yet");
+                } else {
+                    println!("{}", runs_table(runs));
+                }
+            })?;
+        }
+        RunsCommand::Show { id } => {
+            let id = id.to_lowercase();
+            let matches: Vec<&ScanRun> = runs
+                .iter()
+                .filter(|r| r.id.to_string().starts_with(&id))
+                .collect();
+
+            let run = match matches.as_slice() {
+                [run] => *run,
+                [] => return Err(format!("No scan with id {}", id).into()),
+                _ => {
+                    return Err(format!(
+                        "{} scans start with {}, use more of the id",
+                        matches.len(),
+                        id
+                    )
+                    .into())
+                }
+            };
+
+            emit_one(format, run, || {
+                println!("{}", runs_table(std::slice::from_ref(run)));
+                println!("{}", company_runs_table(run));
+            })?;
+        }
+        RunsCommand::Stats => {
+            let stats = ScraperStats::from_runs(&runs);
+            emit(format, &stats, || {
+                if stats.is_empty() {
+                    eprintln!("No scans recorded yet");
+                } else {
+                    println!("{}", scraper_stats_table(&stats));
+                }
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+fn config_command(command: ConfigCommand, format: OutputFormat) -> AppResult<()> {
+    let path = config::path();
+
+    match command {
+        ConfigCommand::Show => {
+            let config = config::resolve()?.redacted();
+
+            match format {
+                OutputFormat::Table => {
+                    eprintln!("# {}", path.display());
+                    print!("{}", toml::to_string(&config)?);
+                }
+                _ => emit_one(format, &config, || {})?,
+            }
+        }
+        ConfigCommand::Set { key, value } => {
+            let mut table = config::read_file(&path)?;
+            config::set_value(&mut table, &key, &value)?;
+            config::Config::from_table(&table)?.validate()?;
+            config::write_setting(&path, &key, &table)?;
+
+            println!("Set {} in {}", key, path.display());
+        }
+        ConfigCommand::Validate => {
+            config::resolve()?;
+            println!("{} {} is valid", "✓".green(), path.display());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::{pipeline::ApplicationStatus, scraper::ScrapedJob};
+
+    #[test]
+    fn test_list_applied_jobs() {
+        let mut data = Data {
+            detached: true,
+            ..Data::default()
+        };
+        let job = |title: &str, status: ApplicationStatus| {
+            let mut job = Job::from_scraped(
+                ScrapedJob {
+                    title: title.to_string(),
+                    location: "Remote".to_string(),
+                    link: format!("https://example.com/{}", title),
+                    ..Default::default()
+                },
+                Utc::now(),
+            );
+            job.set_application_status(status, Utc::now());
+            job
+        };
+        let jobs = vec![
+            job("Interested", ApplicationStatus::Interested),
+            job("Applied", ApplicationStatus::Applied),
+            job("Recruiter Screen", ApplicationStatus::RecruiterScreen),
+        ];
+        data.companies.get_mut("Stripe").unwrap().jobs = jobs;
+
+        let args = JobsListArgs {
+            company: vec![],
+            keywords: None,
+            remote: false,
+            bookmarked: false,
+            applied: true,
+            unseen: false,
+            include_closed: false,
+            limit: None,
+        };
+        let mut titles: Vec<&str> = listed_jobs(&args, &data)
+            .unwrap()
+            .into_iter()
+            .map(|(_, job)| job.title.as_str())
+            .collect();
+        titles.sort();
+
+        assert_eq!(titles, ["Applied", "Recruiter Screen"]);
+    }
+}
@@ -2,11 +2,83 @@ use std::error::Error;
 
 use dialoguer::{theme::ColorfulTheme, Confirm, Input};
 
-use crate::{discord::initialize_discord_mode, error::AppResult};
+use crate::{
+    config,
+    discord::{initialize_discord_mode, ScanSchedule},
+    error::AppResult,
+    models::data::Data,
+};
 pub async fn run() -> AppResult<()> {
     let dialoguer_styles = ColorfulTheme::default();
+    let mut data = Data::get_data();
+    let config::Config {
+        notifications,
+        schedule,
+        ..
+    } = config::get();
 
-    let webhook_url = Input::<String>::with_theme(&dialoguer_styles)
+    // INFO: Only what the config leaves unset is prompted for. A webhook from the config applies to
+    // every profile, so only a prompted one is saved with the profile, see `discord::webhook_url`
+    let webhook_url = match &notifications.discord_webhook_url {
+        Some(webhook_url) => webhook_url.clone(),
+        None => {
+            let webhook_url = prompt_webhook_url(&dialoguer_styles, &data)?;
+            data.set_discord_webhook_url(webhook_url.clone());
+            webhook_url
+        }
+    };
+
+    let cron = match (&schedule.cron, schedule.interval_hours) {
+        (Some(cron), _) => cron.clone(),
+        (None, Some(interval)) => format!("every {} hours", interval),
+        (None, None) => {
+            let interval = Input::<u64>::with_theme(&dialoguer_styles)
+                .with_prompt("Enter scan interval (hours)")
+                .validate_with(|input: &u64| -> Result<(), &str> {
+                    if *input >= 1 && *input <= 12 {
+                        Ok(())
+                    } else {
+                        Err("Interval must be between 1 and 12 hours")
+                    }
+                })
+                .default(4)
+                .interact()?;
+
+            format!("every {} hours", interval)
+        }
+    };
+
+    let scan_all_companies = match schedule.scan_all_companies {
+        Some(scan_all_companies) => scan_all_companies,
+        None => Confirm::with_theme(&dialoguer_styles)
+            .with_prompt("Scan all companies? (otherwise only followed companies or companies where you have at least 1 connection)")
+            .default(false)
+            .interact()?,
+    };
+
+    let notify_on_baseline = match schedule.notify_on_baseline {
+        Some(notify_on_baseline) => notify_on_baseline,
+        None => Confirm::with_theme(&dialoguer_styles)
+            .with_prompt("Notify about every job the first time a company is scanned? (otherwise the first scan only records a baseline)")
+            .default(false)
+            .interact()?,
+    };
+
+    let schedules = ScanSchedule::from_config(schedule, cron, scan_all_companies);
+    initialize_discord_mode(webhook_url, schedules, notify_on_baseline).await?;
+
+    return Ok(());
+}
+
+fn prompt_webhook_url(dialoguer_styles: &ColorfulTheme, data: &Data) -> AppResult<String> {
+    let mut webhook_input = Input::<String>::with_theme(dialoguer_styles);
+
+    // INFO: Offer the webhook last used with this profile
+    if let Some(saved_webhook_url) = data.discord_webhook_url.clone() {
+        webhook_input = webhook_input.default(saved_webhook_url);
+    }
+
+    let webhook_url = webhook_input
         .with_prompt("Enter Discord webhook URL")
         .validate_with(|input: &String| -> Result<(), &str> {
             if input.starts_with("https://discord.com/api/webhooks/") {
@@ -17,24 +89,5 @@ pub async fn run() -> AppResult<()> {
         })
         .interact()?;
 
-    let interval = Input::<u64>::with_theme(&dialoguer_styles)
-        .with_prompt("Enter scan interval (hours)")
-        .validate_with(|input: &u64| -> Result<(), &str> {
-            if *input >= 1 && *input <= 12 {
-                Ok(())
-            } else {
-                Err("Interval must be between 1 and 12 hours")
-            }
-        })
-        .default(4)
-        .interact()?;
-
-    let scan_all_companies = Confirm::with_theme(&dialoguer_styles)
-            .with_prompt("Scan all companies? (otherwise only followed companies or companies where you have at least 1 connection)")
-            .default(false)
-            .interact()?;
-
-    initialize_discord_mode(webhook_url, interval, scan_all_companies).await?;
-
-    return Ok(());
+    Ok(webhook_url)
 }
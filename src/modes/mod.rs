@@ -1,2 +1,5 @@
 pub mod cli;
+pub mod commands;
 pub mod discord;
+pub mod output;
+pub mod tui;
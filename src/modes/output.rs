@@ -0,0 +1,205 @@
+use schemars::{schema_for, JsonSchema};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    args::{OutputFormat, SchemaRecord},
+    error::AppResult,
+    models::{
+        data::{Company, Connection},
+        scraper::Job,
+    },
+    reports::csv_field,
+};
+
+/// A job as emitted by `--format json|ndjson|csv`, the stored `Job` plus the company it belongs to
+#[derive(Serialize, JsonSchema)]
+pub struct JobRecord<'a> {
+    pub company: &'a str,
+    #[serde(flatten)]
+    pub job: &'a Job,
+    /// Markdown description, only included by `jobs show` once it has been fetched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Names of the saved searches that matched the job, only included by `scan`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub saved_searches: Vec<String>,
+}
+
+impl<'a> JobRecord<'a> {
+    pub fn new(company: &'a str, job: &'a Job) -> Self {
+        JobRecord {
+            company,
+            job,
+            description: None,
+            saved_searches: Vec::new(),
+        }
+    }
+}
+
+/// A company as emitted by `--format json|ndjson|csv`, the stored `Company` plus its name
+#[derive(Serialize, JsonSchema)]
+pub struct CompanyRecord<'a> {
+    pub name: &'a str,
+    #[serde(flatten)]
+    pub company: &'a Company,
+}
+
+#[derive(Serialize)]
+pub struct ReportRecord {
+    pub name: String,
+    pub path: String,
+}
+
+pub fn print_schema(record: SchemaRecord) -> AppResult<()> {
+    let schema = match record {
+        SchemaRecord::Job => schema_for!(JobRecord),
+        SchemaRecord::Connection => schema_for!(Connection),
+        SchemaRecord::Company => schema_for!(CompanyRecord),
+    };
+
+    println!("{}", serde_json::to_string_pretty(&schema)?);
+
+    Ok(())
+}
+
+/// Prints a listing in the requested format, `print_table` renders the table format
+pub fn emit<T: Serialize>(
+    format: OutputFormat,
+    records: &[T],
+    print_table: impl FnOnce(),
+) -> AppResult<()> {
+    match format {
+        OutputFormat::Table => print_table(),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(records)?),
+        OutputFormat::Ndjson => {
+            for record in records {
+                println!("{}", serde_json::to_string(record)?);
+            }
+        }
+        OutputFormat::Csv => print!("{}", to_csv(records)?),
+    }
+
+    Ok(())
+}
+
+/// Like `emit` for a single record, json prints the object itself instead of an array
+pub fn emit_one<T: Serialize>(
+    format: OutputFormat,
+    record: &T,
+    print_table: impl FnOnce(),
+) -> AppResult<()> {
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(record)?),
+        _ => emit(format, std::slice::from_ref(record), print_table)?,
+    }
+
+    Ok(())
+}
+
+// INFO: Nested objects become dotted columns (metadata.department), arrays stay JSON and nulls are empty
+fn flatten(prefix: &str, value: &Value, row: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(&key, value, row);
+            }
+        }
+        Value::Null => row.push((prefix.to_string(), String::new())),
+        Value::String(s) => row.push((prefix.to_string(), s.clone())),
+        _ => row.push((prefix.to_string(), value.to_string())),
+    }
+}
+
+fn to_csv<T: Serialize>(records: &[T]) -> AppResult<String> {
+    let mut columns: Vec<String> = Vec::new();
+    let mut rows: Vec<Vec<(String, String)>> = Vec::new();
+
+    for record in records {
+        let mut row = Vec::new();
+        flatten("", &serde_json::to_value(record)?, &mut row);
+
+        for (column, _) in row.iter() {
+            if !columns.contains(column) {
+                columns.push(column.clone());
+            }
+        }
+        rows.push(row);
+    }
+
+    let mut csv = String::new();
+    if columns.is_empty() {
+        return Ok(csv);
+    }
+
+    let header: Vec<String> = columns.iter().map(|c| csv_field(c)).collect();
+    csv.push_str(&format!("{}\n", header.join(",")));
+
+    for row in rows {
+        let fields: Vec<String> = columns
+            .iter()
+            .map(|column| {
+                row.iter()
+                    .find(|(c, _)| c == column)
+                    .map(|(_, v)| csv_field(v))
+                    .unwrap_or_default()
+            })
+            .collect();
+        csv.push_str(&format!("{}\n", fields.join(",")));
+    }
+
+    Ok(csv)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_job_record_fields() {
+        let schema = serde_json::to_value(schema_for!(JobRecord)).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+
+        // Scripts depend on these names, renaming one is a breaking change
+        for field in [
+            "company",
+            "id",
+            "title",
+            "location",
+            "link",
+            "is_seen",
+            "is_bookmarked",
+            "metadata",
+            "salary",
+            "seniority",
+            "tags",
+            "notes",
+            "description",
+            "saved_searches",
+        ] {
+            assert!(properties.contains_key(field), "missing {}", field);
+        }
+
+        let job = Job {
+            title: "Rust Engineer, Payments".to_string(),
+            location: "Remote".to_string(),
+            tags: vec!["rust".to_string()],
+            ..Default::default()
+        };
+        let csv = to_csv(&[JobRecord::new("Stripe", &job)]).unwrap();
+        let mut lines = csv.lines();
+        let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+
+        assert!(header.contains(&"company"));
+        assert!(header.contains(&"metadata.remote"));
+        assert!(lines
+            .next()
+            .unwrap()
+            .contains(r#""Rust Engineer, Payments""#));
+    }
+}
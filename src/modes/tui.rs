@@ -0,0 +1,871 @@
+use std::{cmp::Reverse, io, sync::mpsc as std_mpsc, thread, time::Duration};
+
+use colored::Colorize;
+use console::{pad_str, Alignment, Key, Term};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use crate::{
+    background_scan::BackgroundScan,
+    company_options::CompanyOption,
+    error::AppResult,
+    models::{
+        data::Data, description::JobDescription, run::RunTrigger, scraper::Job,
+        search::SearchQuery,
+    },
+    profiles,
+};
+
+const SIDEBAR_WIDTH: usize = 28;
+const SPINNER: [&str; 4] = ["◐", "◓", "◑", "◒"];
+
+#[derive(Display, EnumIter, Clone, Copy, PartialEq)]
+enum JobSort {
+    #[strum(to_string = "Newest")]
+    Newest,
+    #[strum(to_string = "Title")]
+    Title,
+    #[strum(to_string = "Company")]
+    Company,
+    #[strum(to_string = "Level")]
+    Level,
+    #[strum(to_string = "Salary")]
+    Salary,
+}
+
+impl JobSort {
+    fn next(self) -> Self {
+        let sorts: Vec<JobSort> = JobSort::iter().collect();
+        let index = sorts.iter().position(|s| *s == self).unwrap();
+
+        sorts[(index + 1) % sorts.len()]
+    }
+
+    fn apply(self, jobs: &mut [(String, Job)]) {
+        match self {
+            JobSort::Newest => jobs.sort_by(|(_, a), (_, b)| {
+                let a_posted = a.metadata.posted_at.or(a.first_seen_at);
+                let b_posted = b.metadata.posted_at.or(b.first_seen_at);
+
+                b_posted.cmp(&a_posted)
+            }),
+            JobSort::Title => jobs.sort_by_key(|(_, j)| j.title.to_lowercase()),
+            JobSort::Company => jobs.sort_by(|(a_company, a), (b_company, b)| {
+                (a_company, &a.title).cmp(&(b_company, &b.title))
+            }),
+            JobSort::Level => jobs.sort_by_key(|(_, j)| Reverse(j.seniority)),
+            JobSort::Salary => {
+                jobs.sort_by_key(|(_, j)| Reverse(j.salary.as_ref().map(|s| s.annual_max())))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Focus {
+    Companies,
+    Jobs,
+}
+
+struct App {
+    data: Data,
+    companies: Vec<String>,
+    focus: Focus,
+    // INFO: 0 is "All companies", the companies follow
+    company_cursor: usize,
+    company_scroll: usize,
+    jobs: Vec<(String, Job)>,
+    job_cursor: usize,
+    job_scroll: usize,
+    detail_scroll: usize,
+    description: Option<(Uuid, Option<String>)>,
+    sort: JobSort,
+    filter: String,
+    filter_error: Option<String>,
+    editing_filter: bool,
+    status: String,
+    scan: Option<BackgroundScan>,
+    tick: usize,
+}
+
+impl App {
+    fn new(data: Data) -> Self {
+        let mut app = App {
+            data,
+            companies: CompanyOption::keys(),
+            focus: Focus::Jobs,
+            company_cursor: 0,
+            company_scroll: 0,
+            jobs: Vec::new(),
+            job_cursor: 0,
+            job_scroll: 0,
+            detail_scroll: 0,
+            description: None,
+            sort: JobSort::Newest,
+            filter: String::new(),
+            filter_error: None,
+            editing_filter: false,
+            status: String::new(),
+            scan: None,
+            tick: 0,
+        };
+        app.refresh_jobs();
+
+        app
+    }
+
+    fn selected_company(&self) -> Option<&String> {
+        self.company_cursor
+            .checked_sub(1)
+            .and_then(|i| self.companies.get(i))
+    }
+
+    fn selected_job(&self) -> Option<&(String, Job)> {
+        self.jobs.get(self.job_cursor)
+    }
+
+    /// Rebuilds the job list from the data, keeping the selected job selected when it is still listed
+    fn refresh_jobs(&mut self) {
+        let selected_id = self.selected_job().map(|(_, j)| j.id);
+
+        let mut jobs = if self.filter.trim().is_empty() {
+            self.filter_error = None;
+            self.data
+                .companies
+                .iter()
+                .flat_map(|(company, c)| c.jobs.iter().map(move |j| (company, j)))
+                .filter(|(_, j)| !j.is_closed())
+                .map(|(company, j)| (company.clone(), j.clone()))
+                .collect()
+        } else {
+            // INFO: Half typed queries are common while filtering, keep the last good results
+            match SearchQuery::parse(&self.filter) {
+                Ok(query) => {
+                    self.filter_error = None;
+                    self.data.search_jobs(&query)
+                }
+                Err(e) => {
+                    self.filter_error = Some(e.to_string());
+                    return;
+                }
+            }
+        };
+
+        if let Some(company) = self.selected_company() {
+            jobs.retain(|(c, _)| c == company);
+        }
+        self.sort.apply(&mut jobs);
+        self.jobs = jobs;
+
+        self.job_cursor = selected_id
+            .and_then(|id| self.jobs.iter().position(|(_, j)| j.id == id))
+            .unwrap_or(self.job_cursor)
+            .min(self.jobs.len().saturating_sub(1));
+    }
+
+    fn move_cursor(&mut self, delta: isize) {
+        match self.focus {
+            Focus::Companies => {
+                self.company_cursor = self
+                    .company_cursor
+                    .saturating_add_signed(delta)
+                    .min(self.companies.len());
+                self.job_cursor = 0;
+                self.refresh_jobs();
+            }
+            Focus::Jobs => {
+                self.job_cursor = self
+                    .job_cursor
+                    .saturating_add_signed(delta)
+                    .min(self.jobs.len().saturating_sub(1));
+            }
+        }
+        self.detail_scroll = 0;
+    }
+
+    /// Applies `update` to the selected job and refreshes the list, `update` returns the status to
+    /// show, or None when nothing changed
+    fn update_selected_job(&mut self, update: impl FnOnce(&mut Data, &Job) -> Option<String>) {
+        let Some((_, job)) = self.selected_job().cloned() else {
+            self.status = "No job selected".to_string();
+            return;
+        };
+
+        if let Some(status) = update(&mut self.data, &job) {
+            self.status = status;
+        }
+        self.refresh_jobs();
+    }
+
+    fn start_scan(&mut self) {
+        if self.scan.is_some() {
+            self.status = "A scan is already running".to_string();
+            return;
+        }
+
+        let companies: Vec<CompanyOption> = match self.selected_company() {
+            Some(company) => vec![CompanyOption::from_name(company).unwrap()],
+            None => BackgroundScan::network_companies(&self.data),
+        };
+
+        if companies.is_empty() {
+            self.status =
+                "Nothing to scan, follow a company with f or select one in the sidebar".to_string();
+            return;
+        }
+
+        self.scan = Some(BackgroundScan::start(companies, &self.data, RunTrigger::Tui));
+    }
+
+    fn handle_scan_update(&mut self) {
+        let Some(scan) = self.scan.take() else {
+            return;
+        };
+
+        if scan.merge_into(&mut self.data) {
+            self.refresh_jobs();
+        }
+
+        if scan.is_finished() {
+            self.status = scan.progress().status_line();
+        } else {
+            self.scan = Some(scan);
+        }
+    }
+
+    /// Returns false when the user quits
+    fn handle_key(&mut self, key: Key) -> bool {
+        if self.editing_filter {
+            match key {
+                Key::Enter => self.editing_filter = false,
+                Key::Escape => {
+                    self.editing_filter = false;
+                    self.filter.clear();
+                    self.refresh_jobs();
+                }
+                Key::Backspace => {
+                    self.filter.pop();
+                    self.refresh_jobs();
+                }
+                Key::Char(c) if !c.is_control() => {
+                    self.filter.push(c);
+                    self.job_cursor = 0;
+                    self.refresh_jobs();
+                }
+                _ => {}
+            }
+            return true;
+        }
+
+        match key {
+            Key::Char('q') | Key::CtrlC => return false,
+            Key::Tab | Key::BackTab => {
+                self.focus = match self.focus {
+                    Focus::Companies => Focus::Jobs,
+                    Focus::Jobs => Focus::Companies,
+                }
+            }
+            Key::ArrowLeft | Key::Char('h') => self.focus = Focus::Companies,
+            Key::ArrowRight | Key::Char('l') => self.focus = Focus::Jobs,
+            Key::ArrowUp | Key::Char('k') => self.move_cursor(-1),
+            Key::ArrowDown | Key::Char('j') => self.move_cursor(1),
+            Key::Home | Key::Char('g') => self.move_cursor(isize::MIN),
+            Key::End | Key::Char('G') => self.move_cursor(isize::MAX),
+            Key::PageDown | Key::Char(' ') => self.detail_scroll += 10,
+            Key::PageUp => self.detail_scroll = self.detail_scroll.saturating_sub(10),
+            Key::Char('/') => {
+                self.editing_filter = true;
+                self.focus = Focus::Jobs;
+            }
+            Key::Escape if !self.filter.is_empty() => {
+                self.filter.clear();
+                self.refresh_jobs();
+            }
+            Key::Char('t') => {
+                self.sort = self.sort.next();
+                self.refresh_jobs();
+                self.status = format!("Sorted by {}", self.sort);
+            }
+            Key::Char('r') => self.start_scan(),
+            Key::Char('x') => match &self.scan {
+                Some(scan) => scan.cancel(),
+                None => self.status = "No scan is running".to_string(),
+            },
+            Key::Char('f') => match self.selected_company().cloned() {
+                Some(company) => {
+                    self.data.toggle_company_follow(&company);
+                    self.status = if self.data.companies[&company].is_following {
+                        format!("Following {}", company)
+                    } else {
+                        format!("No longer following {}", company)
+                    };
+                }
+                None => self.status = "Select a company in the sidebar to follow it".to_string(),
+            },
+            Key::Enter if self.focus == Focus::Companies => self.focus = Focus::Jobs,
+            Key::Char('o') | Key::Enter => self.update_selected_job(|data, job| {
+                data.mark_job_seen(&job.id);
+                Some(match webbrowser::open(&job.link) {
+                    Ok(_) => format!("Opened {}", job.title),
+                    Err(e) => format!("Could not open the browser: {}", e),
+                })
+            }),
+            Key::Char('b') => self.update_selected_job(|data, job| {
+                data.toggle_job_bookmark(&job.id);
+                Some(if job.is_bookmarked {
+                    format!("Removed bookmark from {}", job.title)
+                } else {
+                    format!("Bookmarked {}", job.title)
+                })
+            }),
+            Key::Char('a') => self.update_selected_job(|data, job| {
+                data.mark_job_applied(&job.id)
+                    .then(|| format!("Marked {} as applied", job.title))
+            }),
+            Key::Char('s') => self.update_selected_job(|data, job| {
+                data.mark_job_seen(&job.id);
+                Some(format!("Marked {} as seen", job.title))
+            }),
+            _ => {}
+        }
+
+        true
+    }
+
+    fn sidebar_lines(&mut self, height: usize) -> Vec<String> {
+        self.company_scroll = scroll_to(self.company_cursor, self.company_scroll, height);
+
+        let open_jobs = |company: &str| {
+            self.data.companies[company]
+                .jobs
+                .iter()
+                .filter(|j| !j.is_closed())
+                .count()
+        };
+        let total: usize = self.companies.iter().map(|c| open_jobs(c)).sum();
+
+        let rows = std::iter::once(("All companies".to_string(), total)).chain(
+            self.companies.iter().map(|c| {
+                let marker = if self.data.companies[c].is_following {
+                    "★"
+                } else {
+                    " "
+                };
+                (format!("{} {}", marker, c), open_jobs(c))
+            }),
+        );
+
+        rows.enumerate()
+            .skip(self.company_scroll)
+            .take(height)
+            .map(|(i, (label, count))| {
+                let line = format!(
+                    " {}{:>5} ",
+                    pad_str(&label, SIDEBAR_WIDTH - 7, Alignment::Left, Some("…")),
+                    count
+                );
+                highlight(
+                    line,
+                    i == self.company_cursor,
+                    self.focus == Focus::Companies,
+                )
+            })
+            .collect()
+    }
+
+    fn job_lines(&mut self, width: usize, height: usize) -> Vec<String> {
+        if self.jobs.is_empty() {
+            return vec![format!(" {}", "No jobs, press r to scan".dimmed())];
+        }
+
+        self.job_scroll = scroll_to(self.job_cursor, self.job_scroll, height);
+        let show_company = self.selected_company().is_none();
+
+        self.jobs
+            .iter()
+            .enumerate()
+            .skip(self.job_scroll)
+            .take(height)
+            .map(|(i, (company, job))| {
+                let marks = format!(
+                    "{}{}{}",
+                    if job.is_seen { " " } else { "●" },
+                    if job.is_bookmarked { "★" } else { " " },
+                    if job.applied { "✓" } else { " " }
+                );
+                let context = if show_company { company } else { &job.location };
+                let line = format!(
+                    " {} {}",
+                    marks,
+                    pad_str(
+                        &format!("{} · {}", job.title, context),
+                        width.saturating_sub(6),
+                        Alignment::Left,
+                        Some("…")
+                    )
+                );
+
+                highlight(line, i == self.job_cursor, self.focus == Focus::Jobs)
+            })
+            .collect()
+    }
+
+    fn detail_lines(&mut self, width: usize, height: usize) -> Vec<String> {
+        let Some((company, job)) = self.selected_job().cloned() else {
+            return Vec::new();
+        };
+        let width = width.saturating_sub(2);
+
+        // INFO: Descriptions live in their own files, only read them when the selection changes
+        if self.description.as_ref().map(|(id, _)| *id) != Some(job.id) {
+            self.description = Some((job.id, JobDescription::load(&job.id).map(|d| d.markdown)));
+        }
+
+        let mut lines: Vec<String> = wrap(&job.title, width)
+            .into_iter()
+            .map(|l| l.bold().to_string())
+            .collect();
+        lines.push(company.cyan().to_string());
+        lines.push(String::new());
+
+        let mut fields = vec![
+            ("Location", job.all_locations().join(" / ")),
+            ("Level", job.seniority_label()),
+            ("Salary", job.salary_label()),
+            ("Remote", job.metadata.remote_label()),
+            ("Posted", job.metadata.posted_label()),
+            ("Status", job.status_label()),
+            (
+                "Application",
+                job.application_status()
+                    .map(|s| s.to_string())
+                    .unwrap_or_default(),
+            ),
+            ("Tags", job.tags_label()),
+            ("Link", job.link.clone()),
+        ];
+        fields.retain(|(_, value)| !value.is_empty());
+
+        for (key, value) in fields {
+            for (i, line) in wrap(&format!("{}: {}", key, value), width)
+                .into_iter()
+                .enumerate()
+            {
+                lines.push(match i {
+                    0 => format!("{}{}", key.dimmed(), &line[key.len()..]),
+                    _ => line,
+                });
+            }
+        }
+
+        let sections = [
+            (
+                "Notes",
+                Some(job.notes.clone()).filter(|n| !n.trim().is_empty()),
+            ),
+            (
+                "Description",
+                self.description.as_ref().and_then(|(_, d)| d.clone()),
+            ),
+        ];
+        for (title, text) in sections {
+            if let Some(text) = text {
+                lines.push(String::new());
+                lines.push(title.bold().to_string());
+                lines.extend(text.lines().flat_map(|l| wrap(l, width)));
+            }
+        }
+
+        self.detail_scroll = self.detail_scroll.min(lines.len().saturating_sub(height));
+
+        lines
+            .into_iter()
+            .skip(self.detail_scroll)
+            .take(height)
+            .map(|l| format!(" {}", l))
+            .collect()
+    }
+
+    fn header_line(&self) -> String {
+        let mut header = " JobShell".to_string();
+
+        let profile = profiles::active_profile();
+        if profile != profiles::DEFAULT_PROFILE {
+            header += &format!(" · {}", profile);
+        }
+        header += &format!(" · {} jobs · sort: {}", self.jobs.len(), self.sort);
+        if !self.filter.is_empty() {
+            header += &format!(" · filter: {}", self.filter);
+        }
+
+        header
+    }
+
+    fn footer_lines(&self) -> (String, String) {
+        let prompt = if self.editing_filter {
+            match &self.filter_error {
+                Some(e) => format!(" / {}▏ {}", self.filter, e.red()),
+                None => format!(
+                    " / {}▏ {}",
+                    self.filter,
+                    "enter to keep, esc to clear".dimmed()
+                ),
+            }
+        } else {
+            " ↑↓ move  ←→ pane  / filter  t sort  o open  b bookmark  a applied  s seen  f follow  r scan  x cancel scan  q quit"
+                .dimmed()
+                .to_string()
+        };
+
+        let status = match &self.scan {
+            Some(scan) => format!(
+                " {} {}",
+                SPINNER[self.tick % SPINNER.len()],
+                scan.progress().status_line()
+            ),
+            None => format!(" {}", self.status),
+        };
+
+        (prompt, status)
+    }
+
+    fn render(&mut self, term: &Term) -> io::Result<()> {
+        let (rows, cols) = term.size();
+        let (height, width) = (rows as usize, cols as usize);
+
+        let mut frame = String::new();
+        let mut put_line = |row: usize, line: &str| {
+            // INFO: Lines are placed explicitly, a newline doesn't return the cursor while the terminal is raw
+            frame += &format!("\x1b[{};1H{}\x1b[K", row + 1, line);
+        };
+
+        let Some((list_width, detail_width)) = pane_widths(width, height) else {
+            put_line(0, "Make the terminal larger to use the TUI");
+            return term.write_str(&frame);
+        };
+        let body_height = height - 3;
+
+        let sidebar = self.sidebar_lines(body_height);
+        let jobs = self.job_lines(list_width, body_height);
+        let detail = self.detail_lines(detail_width, body_height);
+        let (prompt, status) = self.footer_lines();
+
+        put_line(
+            0,
+            &pad_str(&self.header_line(), width, Alignment::Left, Some("…"))
+                .reversed()
+                .to_string(),
+        );
+
+        let blank = String::new();
+        for row in 0..body_height {
+            let line = format!(
+                "{}{}{}{}{}",
+                pad_str(
+                    sidebar.get(row).unwrap_or(&blank),
+                    SIDEBAR_WIDTH,
+                    Alignment::Left,
+                    None
+                ),
+                "│".dimmed(),
+                pad_str(
+                    jobs.get(row).unwrap_or(&blank),
+                    list_width,
+                    Alignment::Left,
+                    None
+                ),
+                "│".dimmed(),
+                pad_str(
+                    detail.get(row).unwrap_or(&blank),
+                    detail_width,
+                    Alignment::Left,
+                    Some("…")
+                ),
+            );
+            put_line(row + 1, &line);
+        }
+
+        put_line(
+            height - 2,
+            &pad_str(&prompt, width, Alignment::Left, Some("…")),
+        );
+        put_line(
+            height - 1,
+            &pad_str(&status, width, Alignment::Left, Some("…"))
+                .on_bright_black()
+                .to_string(),
+        );
+
+        term.write_str(&frame)
+    }
+}
+
+/// The widths of the job list and the detail pane next to the sidebar and the two borders, None when
+/// the terminal is too small for them
+fn pane_widths(width: usize, height: usize) -> Option<(usize, usize)> {
+    if width < SIDEBAR_WIDTH + 40 || height < 8 {
+        return None;
+    }
+
+    let list_width = (width - SIDEBAR_WIDTH - 2) * 11 / 20;
+    Some((list_width, width - SIDEBAR_WIDTH - list_width - 2))
+}
+
+fn highlight(line: String, selected: bool, focused: bool) -> String {
+    match (selected, focused) {
+        (true, true) => line.reversed().to_string(),
+        (true, false) => line.bold().to_string(),
+        _ => line,
+    }
+}
+
+// INFO: Keeps the cursor inside the visible window, scrolling as little as possible
+fn scroll_to(cursor: usize, scroll: usize, height: usize) -> usize {
+    if cursor < scroll {
+        cursor
+    } else if cursor >= scroll + height {
+        cursor + 1 - height
+    } else {
+        scroll
+    }
+}
+
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+// INFO: Restores the terminal however the TUI exits
+struct Screen(Term);
+
+impl Screen {
+    fn enter(term: Term) -> io::Result<Self> {
+        term.write_str("\x1b[?1049h")?;
+        term.hide_cursor()?;
+
+        Ok(Screen(term))
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = self.0.show_cursor();
+        let _ = self.0.write_str("\x1b[?1049l");
+    }
+}
+
+pub async fn run() -> AppResult<()> {
+    let term = Term::stdout();
+    if !term.is_term() {
+        return Err("The TUI needs an interactive terminal".into());
+    }
+
+    // INFO: read_key blocks, so keys are read on their own thread. The thread waits for the go ahead
+    // before reading the next key so it never leaves the terminal in raw mode when we quit
+    let (key_sender, mut keys) = mpsc::unbounded_channel();
+    let (next_key, wait_for_next_key) = std_mpsc::channel::<()>();
+    let key_term = term.clone();
+    thread::spawn(move || {
+        while let Ok(key) = key_term.read_key() {
+            if key_sender.send(key).is_err() || wait_for_next_key.recv().is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut ticker = tokio::time::interval(Duration::from_millis(150));
+
+    let screen = Screen::enter(term)?;
+    let mut app = App::new(Data::get_data());
+
+    loop {
+        app.render(&screen.0)?;
+        let scan_updated = app.scan.as_ref().map(|scan| scan.updated());
+
+        tokio::select! {
+            key = keys.recv() => {
+                let Some(key) = key else { break };
+                if !app.handle_key(key) {
+                    break;
+                }
+                next_key.send(())?;
+            }
+            _ = async { scan_updated.unwrap().await }, if scan_updated.is_some() => {
+                app.handle_scan_update()
+            }
+            _ = ticker.tick(), if app.scan.is_some() => app.tick += 1,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+    use console::strip_ansi_codes;
+
+    use super::*;
+    use crate::models::{pipeline::ApplicationStatus, scraper::ScrapedJob};
+
+    fn app(titles: &[&str]) -> App {
+        let mut data = Data {
+            detached: true,
+            ..Data::default()
+        };
+        data.companies.get_mut("Stripe").unwrap().jobs = titles
+            .iter()
+            .map(|title| {
+                Job::from_scraped(
+                    ScrapedJob {
+                        title: title.to_string(),
+                        location: "Remote".to_string(),
+                        link: format!("https://example.com/{}", title),
+                        ..Default::default()
+                    },
+                    Utc::now(),
+                )
+            })
+            .collect();
+
+        let mut app = App::new(data);
+        app.sort = JobSort::Title;
+        app.refresh_jobs();
+        app.job_cursor = 0;
+        app
+    }
+
+    fn app_with_long_title() -> App {
+        app(&["Principal Software Engineer, Developer Platform and Infrastructure"])
+    }
+
+    fn plain(lines: Vec<String>) -> Vec<String> {
+        lines
+            .iter()
+            .map(|l| strip_ansi_codes(l).trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_wrap_and_scroll() {
+        assert_eq!(
+            wrap("Senior Software Engineer, Payments Infrastructure", 20),
+            vec!["Senior Software", "Engineer, Payments", "Infrastructure"]
+        );
+        assert_eq!(wrap("", 20), vec![""]);
+        assert_eq!(wrap("  spaced   out  ", 20), vec!["spaced out"]);
+        // Exactly the width still fits, a word longer than the line gets a line of its own
+        assert_eq!(wrap("abcde fghij", 11), vec!["abcde fghij"]);
+        assert_eq!(
+            wrap("see https://example.com/a/very/long/link now", 10),
+            vec!["see", "https://example.com/a/very/long/link", "now"]
+        );
+
+        assert_eq!(scroll_to(0, 0, 10), 0);
+        assert_eq!(scroll_to(12, 0, 10), 3);
+        assert_eq!(scroll_to(4, 6, 10), 4);
+        assert_eq!(scroll_to(8, 3, 10), 3);
+    }
+
+    #[test]
+    fn test_pane_widths() {
+        assert_eq!(pane_widths(SIDEBAR_WIDTH + 39, 24), None);
+        assert_eq!(pane_widths(120, 7), None);
+
+        for width in [SIDEBAR_WIDTH + 40, 80, 120, 241] {
+            let (list, detail) = pane_widths(width, 24).unwrap();
+            assert_eq!(SIDEBAR_WIDTH + 1 + list + 1 + detail, width);
+            assert!(list >= 20 && detail >= 18);
+        }
+    }
+
+    #[test]
+    fn test_job_list_scrolling() {
+        let titles: Vec<String> = (0..30).map(|i| format!("Job {:02}", i)).collect();
+        let mut app = app(&titles.iter().map(|t| t.as_str()).collect::<Vec<_>>());
+
+        let lines = plain(app.job_lines(40, 10));
+        assert_eq!(lines.len(), 10);
+        assert!(lines[0].ends_with("Job 00 · Stripe"));
+
+        // The cursor drags the window along once it leaves it
+        app.move_cursor(15);
+        let lines = plain(app.job_lines(40, 10));
+        assert_eq!(app.job_scroll, 6);
+        assert!(lines[0].ends_with("Job 06 · Stripe"));
+        assert!(lines[9].ends_with("Job 15 · Stripe"));
+
+        app.move_cursor(isize::MAX);
+        assert_eq!(app.job_cursor, 29);
+        assert_eq!(plain(app.job_lines(40, 10)).len(), 10);
+        assert_eq!(app.job_scroll, 20);
+
+        app.move_cursor(isize::MIN);
+        app.job_lines(40, 10);
+        assert_eq!((app.job_cursor, app.job_scroll), (0, 0));
+
+        // Long titles are cut to the pane
+        let mut app = app_with_long_title();
+        assert!(plain(app.job_lines(30, 10))[0].chars().count() <= 30);
+    }
+
+    #[test]
+    fn test_detail_scrolling() {
+        let mut app = app_with_long_title();
+        let id = app.jobs[0].1.id;
+        app.description = Some((id, Some("word ".repeat(200))));
+
+        let lines = plain(app.detail_lines(30, 10));
+        assert_eq!(lines.len(), 10);
+        assert!(lines.iter().all(|l| l.chars().count() <= 30));
+        assert_eq!(lines[0], " Principal Software Engineer,");
+
+        // Scrolling stops with the last line at the bottom of the pane
+        app.detail_scroll = 1000;
+        let lines = plain(app.detail_lines(30, 10));
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[9], " word word word word word");
+        let bottom = app.detail_scroll;
+        app.handle_key(Key::PageDown);
+        app.detail_lines(30, 10);
+        assert_eq!(app.detail_scroll, bottom);
+    }
+
+    #[test]
+    fn test_applied_marker() {
+        let mut app = app(&["Applied", "Interested"]);
+        app.data
+            .set_job_application_status(&app.jobs[1].1.id, ApplicationStatus::Interested);
+        app.refresh_jobs();
+
+        let lines = plain(app.job_lines(40, 10));
+        assert!(!lines[1].contains('✓'));
+
+        app.handle_key(Key::Char('a'));
+        assert_eq!(app.status, "Marked Applied as applied");
+        assert!(plain(app.job_lines(40, 10))[0].contains('✓'));
+
+        // Nothing changes the second time, so there is nothing to report
+        app.status.clear();
+        app.handle_key(Key::Char('a'));
+        assert_eq!(app.status, "");
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+use crate::{error::AppResult, models::data::Data};
+
+pub const DEFAULT_PROFILE: &str = "default";
+
+// INFO: The profile every data read/write resolves against, set once from --profile and
+// switchable from the main menu. `None` means the default profile.
+static ACTIVE_PROFILE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Profile names become directory names, so keep them to a safe character set
+pub fn validate_profile_name(name: &str) -> Result<(), &'static str> {
+    if name.is_empty() {
+        return Err("Profile name cannot be empty");
+    }
+
+    if name.len() > 32 {
+        return Err("Profile name must be 32 characters or fewer");
+    }
+
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("Profile name may only contain letters, numbers, '-' and '_'");
+    }
+
+    Ok(())
+}
+
+pub fn set_active_profile(name: &str) -> AppResult<()> {
+    validate_profile_name(name)?;
+
+    let mut active = ACTIVE_PROFILE.write().unwrap();
+
+    *active = if name == DEFAULT_PROFILE {
+        None
+    } else {
+        Some(name.to_string())
+    };
+
+    Ok(())
+}
+
+pub fn active_profile() -> String {
+    ACTIVE_PROFILE
+        .read()
+        .unwrap()
+        .clone()
+        .unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+}
+
+/// The default profile keeps living in the base data dir so existing installs carry over as is
+pub fn profile_dir(base_dir: &Path, name: &str) -> PathBuf {
+    if name == DEFAULT_PROFILE {
+        base_dir.to_path_buf()
+    } else {
+        base_dir.join("profiles").join(name)
+    }
+}
+
+/// Every profile that has a data store on disk, always starting with the default one
+pub fn list_profiles() -> Vec<String> {
+    let mut profiles: Vec<String> = fs::read_dir(Data::get_base_data_dir().join("profiles"))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().to_str().map(String::from))
+                .filter(|name| validate_profile_name(name).is_ok() && name != DEFAULT_PROFILE)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    profiles.sort();
+    profiles.insert(0, DEFAULT_PROFILE.to_string());
+
+    profiles
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_profile_name() {
+        assert!(validate_profile_name("passive-watch").is_ok());
+        assert!(validate_profile_name("alex_2").is_ok());
+        assert!(validate_profile_name("").is_err());
+        assert!(validate_profile_name("../escape").is_err());
+        assert!(validate_profile_name("has space").is_err());
+    }
+
+    #[test]
+    fn test_profile_dir() {
+        let base = Path::new("/data/jobshell");
+
+        assert_eq!(profile_dir(base, DEFAULT_PROFILE), base);
+        assert_eq!(
+            profile_dir(base, "partner"),
+            Path::new("/data/jobshell/profiles/partner")
+        );
+    }
+}
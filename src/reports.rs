@@ -16,6 +16,52 @@ pub enum ReportMode {
     CSV,
 }
 
+// INFO: Quote a CSV field when it contains a delimiter, quote or newline
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// INFO: Escape text before it goes into the report, notes and custom fields are free text
+pub fn html_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn html_row(fj: &FormattedJob) -> String {
+    format!(
+        r#"<tr><td>{}</td> <td>{}</td> <td>{}</td> <td>{}</td> <td>{}</td> <td>{}</td> <td>{}</td> <td>{}</td> <td>{}</td> <td>{}</td> <td>{}</td> <td>{}</td> <td><a href="{}">Apply</a></td></tr>"#,
+        html_escape(&fj.company),
+        html_escape(&fj.job.title),
+        html_escape(&fj.job.location),
+        html_escape(fj.job.metadata.department.as_deref().unwrap_or_default()),
+        html_escape(&fj.job.metadata.employment_type_label()),
+        html_escape(&fj.job.metadata.remote_label()),
+        html_escape(&fj.job.salary_label()),
+        html_escape(&fj.job.metadata.posted_label()),
+        html_escape(&fj.job.status_label()),
+        html_escape(&fj.job.tags_label()),
+        html_escape(&fj.job.custom_fields_label()),
+        html_escape(&fj.job.notes).replace('\n', "<br>"),
+        html_escape(&fj.job.link)
+    )
+}
+
 fn append_jobs_to_html(jobs: &Vec<FormattedJob>, html: String) -> String {
     // let document = scraper::Html::parse_document(html);
     // let tbody_selector = scraper::Selector::parse("tbody").unwrap();
@@ -29,10 +75,7 @@ fn append_jobs_to_html(jobs: &Vec<FormattedJob>, html: String) -> String {
         // Create the new rows HTML
         let mut new_rows = String::new();
         for fj in jobs {
-            new_rows.push_str(&format!(
-                r#"<tr><td>{}</td> <td>{}</td> <td>{}</td> <td><a href="{}">{}</a></td></tr>"#,
-                fj.company, fj.job.title, fj.job.location, fj.job.link, "Apply"
-            ));
+            new_rows.push_str(&html_row(fj));
         }
 
         // Insert the new rows after <tbody>
@@ -61,15 +104,24 @@ pub fn create_report(new_jobs: &Vec<FormattedJob>, mode: ReportMode) -> AppResul
     }
     match mode {
         ReportMode::CSV => {
-            let names_row = "Company,Title,Location,Link\n";
+            let names_row = "Company,Title,Location,Department,Employment Type,Remote,Salary,Posted,Status,Tags,Custom Fields,Notes,Link\n";
             let entries = new_jobs
                 .iter()
                 .map(|j| {
                     format!(
-                        "{},{},{},{}\n",
+                        "{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
                         j.company,
                         j.job.title,
                         j.job.location.replace(",", ""),
+                        csv_field(j.job.metadata.department.as_deref().unwrap_or_default()),
+                        j.job.metadata.employment_type_label(),
+                        j.job.metadata.remote_label(),
+                        csv_field(&j.job.salary_label()),
+                        j.job.metadata.posted_label(),
+                        j.job.status_label(),
+                        j.job.tags_label(),
+                        csv_field(&j.job.custom_fields_label()),
+                        csv_field(&j.job.notes),
                         j.job.link
                     )
                 })
@@ -112,6 +164,15 @@ pub fn create_report(new_jobs: &Vec<FormattedJob>, mode: ReportMode) -> AppResul
           <th>Company</th>
           <th>Title</th>
           <th>Location</th>
+          <th>Department</th>
+          <th>Employment Type</th>
+          <th>Remote</th>
+          <th>Salary</th>
+          <th>Posted</th>
+          <th>Status</th>
+          <th>Tags</th>
+          <th>Custom Fields</th>
+          <th>Notes</th>
           <th>Link</th>
         </tr>
       </thead>
@@ -124,15 +185,7 @@ pub fn create_report(new_jobs: &Vec<FormattedJob>, mode: ReportMode) -> AppResul
 </html>
 "#,
                 format!("New Jobs: {}", today),
-                new_jobs
-                    .iter()
-                    .map(|fj| {
-                        format!(
-                            r#"<tr><td>{}</td> <td>{}</td> <td>{}</td> <td><a href="{}">Apply</a></td></tr>"#,
-                            fj.company, fj.job.title, fj.job.location, fj.job.link
-                        )
-                    })
-                    .collect::<String>()
+                new_jobs.iter().map(html_row).collect::<String>()
             );
 
                 fs::write(&path, html)?;
@@ -164,6 +217,7 @@ mod test {
                     is_seen: false,
                     applied: false,
                     is_bookmarked: false,
+                    ..Default::default()
                 },
             }],
             ReportMode::HTML,
@@ -174,4 +228,39 @@ mod test {
         }
         assert_eq!(v.is_ok(), true);
     }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("referral"), "referral");
+        assert_eq!(csv_field("met at meetup, follow up"), "\"met at meetup, follow up\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_html_row_escapes() {
+        let mut job = Job {
+            title: "C++ & Rust <Engineer>".to_string(),
+            link: "https://example.com/apply?a=1&b=\"2\"".to_string(),
+            notes: "<script>alert(1)</script>\nsee </td>".to_string(),
+            tags: vec!["<b>".to_string()],
+            ..Default::default()
+        };
+        job.custom_fields
+            .insert("referrer".to_string(), "<i>Sam</i>".to_string());
+
+        let row = html_row(&FormattedJob {
+            display_name: String::new(),
+            company: "Disney".to_string(),
+            job,
+        });
+
+        assert!(!row.contains("<script>"));
+        assert!(!row.contains("<b>"));
+        assert!(!row.contains("<i>"));
+        assert!(row.contains("C++ &amp; Rust &lt;Engineer&gt;"));
+        assert!(row.contains("&lt;script&gt;alert(1)&lt;/script&gt;<br>see &lt;/td&gt;"));
+        assert!(row.contains("&lt;i&gt;Sam&lt;/i&gt;"));
+        assert!(row.contains(r#"href="https://example.com/apply?a=1&amp;b=&quot;2&quot;""#));
+        assert_eq!(row.matches("<td>").count(), 13);
+    }
 }
@@ -1,15 +1,20 @@
 use std::error::Error;
 
-use reqwest::Client;
 use serde_json::Value;
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::http_client,
+    error::AppResult,
+    models::{
+        data::Data,
+        identity::external_id_from_value,
+        metadata::{string_from_value, EmploymentType, JobMetadata},
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_atlassian(data: &mut Data) -> AppResult<JobsPayload> {
-    let client = Client::new();
+    let client = http_client();
     let json: Value = client
     .get("https://www.atlassian.com/endpoint/careers/listings")
     .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36")
@@ -52,6 +57,13 @@ pub async fn scrape_atlassian(data: &mut Data) -> AppResult<JobsPayload> {
                 title,
                 location: formatted_location,
                 link,
+                external_id: external_id_from_value(&job["id"]),
+                locations: Vec::new(),
+                metadata: JobMetadata {
+                    department: string_from_value(&job["category"]),
+                    employment_type: job["type"].as_str().and_then(EmploymentType::parse),
+                    ..Default::default()
+                },
             };
 
             scraped_jobs.push(scraped_job);
@@ -2,18 +2,21 @@ use std::error::Error;
 
 use headless_chrome::{Browser, LaunchOptions};
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::launch_options,
+    error::AppResult,
+    models::{
+        data::Data,
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_blizzard(data: &mut Data) -> AppResult<JobsPayload> {
     let launch_options = LaunchOptions {
-        headless: true,
         window_size: Some((1920, 1080)),
         enable_logging: true,
 
-        ..LaunchOptions::default()
+        ..launch_options(true)
     };
     let browser = Browser::new(launch_options)?;
 
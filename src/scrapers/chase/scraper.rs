@@ -1,12 +1,19 @@
 use std::error::Error;
 
-use reqwest::Client;
 use serde_json::Value;
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::http_client,
+    error::AppResult,
+    models::{
+        data::Data,
+        identity::external_id_from_value,
+        metadata::{
+            parse_posted_date, remote_from_text, string_from_value, EmploymentType, JobMetadata,
+        },
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_chase(data: &mut Data) -> AppResult<JobsPayload> {
     let mut offset = 0;
@@ -15,7 +22,7 @@ pub async fn scrape_chase(data: &mut Data) -> AppResult<JobsPayload> {
     loop {
         let mut url  = format!("https://jpmc.fa.oraclecloud.com/hcmRestApi/resources/latest/recruitingCEJobRequisitions?onlyData=true&expand=requisitionList.secondaryLocations,flexFieldsFacet.values,requisitionList.requisitionFlexFields&finder=findReqs;siteNumber=CX_1002,facetsList=LOCATIONS%3BWORK_LOCATIONS%3BWORKPLACE_TYPES%3BTITLES%3BCATEGORIES%3BORGANIZATIONS%3BPOSTING_DATES%3BFLEX_FIELDS,limit=200,lastSelectedFacet=CATEGORIES,selectedCategoriesFacet=300000086152753,sortBy=POSTING_DATES_DESC,offset={}", offset);
 
-        let json = Client::new().get(url).send().await?.json::<Value>().await?;
+        let json = http_client().get(url).send().await?.json::<Value>().await?;
 
         let jobs = json["items"][0]["requisitionList"].as_array().unwrap();
 
@@ -26,8 +33,16 @@ pub async fn scrape_chase(data: &mut Data) -> AppResult<JobsPayload> {
         let scraped_jobs_subset: Vec<ScrapedJob> = jobs.iter().map(|v| ScrapedJob {
             title: v["Title"].as_str().unwrap().trim().to_string(),
             location: v["PrimaryLocation"].as_str().unwrap().trim().split(",").take(2).collect::<Vec<&str>>().join(","),
-            link: format!("https://jpmc.fa.oraclecloud.com/hcmUI/CandidateExperience/en/sites/CX_1002/job/{}", v["Id"].as_str().unwrap())
-
+            link: format!("https://jpmc.fa.oraclecloud.com/hcmUI/CandidateExperience/en/sites/CX_1002/job/{}", v["Id"].as_str().unwrap()),
+            external_id: external_id_from_value(&v["Id"]),
+            locations: Vec::new(),
+            metadata: JobMetadata {
+                posted_at: parse_posted_date(&v["PostedDate"]),
+                department: string_from_value(&v["JobFamily"]),
+                employment_type: v["JobSchedule"].as_str().and_then(EmploymentType::parse),
+                remote: v["WorkplaceType"].as_str().and_then(remote_from_text),
+                ..Default::default()
+            },
         }).collect();
 
         scraped_jobs.extend(scraped_jobs_subset);
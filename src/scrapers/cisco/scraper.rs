@@ -1,13 +1,17 @@
 use core::panic;
 use std::error::Error;
 
-use reqwest::Client;
 use scraper::{Html, Selector};
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::http_client,
+    error::AppResult,
+    models::{
+        data::Data,
+        metadata::JobMetadata,
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_cisco(data: &mut Data) -> AppResult<JobsPayload> {
     // Fetch the html
@@ -21,7 +25,7 @@ pub async fn scrape_cisco(data: &mut Data) -> AppResult<JobsPayload> {
     let mut scraped_jobs: Vec<ScrapedJob> = Vec::new();
     loop {
         let url = format!("https://jobs.cisco.com/jobs/SearchJobs/?21181=%5B187%5D&21181_format=6023&listFilterMode=1&projectOffset={offset}");
-        let html_string = Client::new().get(&url).send().await?.text().await?;
+        let html_string = http_client().get(&url).send().await?.text().await?;
 
         let document = Html::parse_document(&html_string);
 
@@ -59,6 +63,9 @@ pub async fn scrape_cisco(data: &mut Data) -> AppResult<JobsPayload> {
                         title,
                         location,
                         link,
+                        external_id: None,
+                        locations: Vec::new(),
+                        metadata: JobMetadata::default(),
                     };
 
                     scraped_jobs.push(job);
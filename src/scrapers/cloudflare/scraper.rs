@@ -1,17 +1,22 @@
 use std::error::Error;
 
-use reqwest::Client;
 use serde_json::Value;
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::http_client,
+    error::AppResult,
+    models::{
+        data::Data,
+        identity::external_id_from_value,
+        metadata::{greenhouse_metadata, string_from_value},
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_cloudflare(data: &mut Data) -> AppResult<JobsPayload> {
     let mut scraped_jobs: Vec<ScrapedJob> = Vec::new();
 
-    let mut json: Value = Client::new()
+    let mut json: Value = http_client()
         .get("https://boards-api.greenhouse.io/v1/boards/cloudflare/departments/?render_as=tree")
         .send()
         .await?
@@ -47,6 +52,9 @@ pub async fn scrape_cloudflare(data: &mut Data) -> AppResult<JobsPayload> {
                     title: title.to_string(),
                     location: location.to_string(),
                     link: link.to_string(),
+                    external_id: external_id_from_value(&job["id"]),
+                    locations: Vec::new(),
+                    metadata: greenhouse_metadata(job, string_from_value(&dep["name"])),
                 });
             }
         }
@@ -1,8 +1,10 @@
 use std::error::Error;
 
 use headless_chrome::{Browser, LaunchOptions};
+use tracing::debug;
 
 use crate::{
+    config::launch_options,
     error::AppResult,
     models::{
         data::Data,
@@ -12,11 +14,10 @@ use crate::{
 
 pub async fn scrape_coinbase(data: &mut Data) -> AppResult<JobsPayload> {
     let launch_options = LaunchOptions {
-        headless: false,
         window_size: Some((1920, 1080)),
         enable_logging: false,
 
-        ..LaunchOptions::default()
+        ..launch_options(false)
     };
     let browser = Browser::new(launch_options)?;
 
@@ -35,7 +36,7 @@ pub async fn scrape_coinbase(data: &mut Data) -> AppResult<JobsPayload> {
 
         if department_title.contains("Engineering") {
             p.click()?;
-            println!("{} clicked", department_title);
+            debug!(department = %department_title, "Expanded department");
         }
     }
 
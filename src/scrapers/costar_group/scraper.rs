@@ -1,12 +1,17 @@
 use std::error::Error;
 
-use reqwest::Client;
 use serde_json::Value;
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::http_client,
+    error::AppResult,
+    models::{
+        data::Data,
+        identity::external_id_from_value,
+        metadata::eightfold_metadata,
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_costar_group(data: &mut Data) -> AppResult<JobsPayload> {
     let mut start = 0;
@@ -14,7 +19,7 @@ pub async fn scrape_costar_group(data: &mut Data) -> AppResult<JobsPayload> {
 
     loop {
         let url = format!("https://careers.costargroup.com/api/apply/v2/jobs?domain=costar.com&start={start}&num=10&exclude_pid=446702351152&pid=446702351152&business_unit=Software%20Engineering&domain=costar.com&sort_by=relevance");
-        let data: Value = Client::new().get(&url).send().await?.json().await?;
+        let data: Value = http_client().get(&url).send().await?.json().await?;
 
         let positions = data["positions"].as_array().unwrap();
 
@@ -37,6 +42,9 @@ pub async fn scrape_costar_group(data: &mut Data) -> AppResult<JobsPayload> {
                     title,
                     link,
                     location,
+                    external_id: external_id_from_value(&v["id"]),
+                    locations: Vec::new(),
+                    metadata: eightfold_metadata(v),
                 }
             })
             .collect();
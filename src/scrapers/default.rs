@@ -1,6 +1,7 @@
 use headless_chrome::{Browser, LaunchOptions};
 
 use crate::{
+    config::launch_options,
     error::AppResult,
     handlers::scrape_options::DefaultJobScraperOptions,
     models::{
@@ -14,11 +15,10 @@ pub async fn default_scrape_jobs_handler(
     options: DefaultJobScraperOptions,
 ) -> AppResult<JobsPayload> {
     let launch_options = LaunchOptions {
-        headless: options.headless,
         window_size: Some((1920, 1080)),
         enable_logging: true,
 
-        ..LaunchOptions::default()
+        ..launch_options(options.headless)
     };
     let browser = Browser::new(launch_options)?;
 
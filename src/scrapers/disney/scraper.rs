@@ -2,18 +2,21 @@ use std::error::Error;
 
 use headless_chrome::{Browser, LaunchOptions};
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::launch_options,
+    error::AppResult,
+    models::{
+        data::Data,
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_disney(data: &mut Data) -> AppResult<JobsPayload> {
     let launch_options = LaunchOptions {
-        headless: true,
         window_size: Some((1920, 1080)),
         enable_logging: true,
 
-        ..LaunchOptions::default()
+        ..launch_options(true)
     };
     let browser = Browser::new(launch_options)?;
 
@@ -110,7 +113,7 @@ let statesOfAmerica = [
 
 let location = item.querySelector("span.job-location").textContent.trim();
 
-let locations = location.split("/");
+let locations = location.split("/").map(loc => loc.trim()).filter(loc => loc.length > 0);
 
 const states = locations.map(loc => {
     const v = loc.split(",")
@@ -129,6 +132,7 @@ for(const state of states) {
 return ({
         title: item.querySelector("h2").textContent.trim(),
         location,
+        locations,
         link: item.querySelector("a").href.trim()
     })}))
 
@@ -1,10 +1,12 @@
-use reqwest::Client;
 use serde_json::Value;
 
 use crate::{
+    config::http_client,
     error::AppResult,
     models::{
         data::Data,
+        identity::external_id_from_value,
+        metadata::{parse_posted_date, string_from_value, EmploymentType, JobMetadata},
         scraper::{JobsPayload, ScrapedJob},
     },
 };
@@ -18,7 +20,7 @@ pub async fn scrape_experian(data: &mut Data) -> AppResult<JobsPayload> {
             "https://api.smartrecruiters.com/v1/companies/experian/postings?offset={}",
             offset
         );
-        let json: Value = Client::new().get(&url).send().await?.json().await?;
+        let json: Value = http_client().get(&url).send().await?.json().await?;
 
         let mut content = json["content"].as_array().unwrap().clone();
 
@@ -56,6 +58,17 @@ pub async fn scrape_experian(data: &mut Data) -> AppResult<JobsPayload> {
                     title,
                     location,
                     link,
+                    external_id: external_id_from_value(&v["id"]),
+                    locations: Vec::new(),
+                    metadata: JobMetadata {
+                        posted_at: parse_posted_date(&v["releasedDate"]),
+                        department: string_from_value(&v["department"]["label"]),
+                        employment_type: v["typeOfEmployment"]["label"]
+                            .as_str()
+                            .and_then(EmploymentType::parse),
+                        remote: v["location"]["remote"].as_bool(),
+                        ..Default::default()
+                    },
                 }
             })
             .collect();
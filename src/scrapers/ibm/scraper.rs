@@ -1,14 +1,18 @@
-
 use reqwest::{
     header::{HeaderMap, HeaderValue, ACCEPT, ACCEPT_LANGUAGE, CONTENT_TYPE, REFERER},
     Client, ClientBuilder,
 };
 use serde_json::{json, Value};
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    error::AppResult,
+    models::{
+        data::Data,
+        identity::external_id_from_value,
+        metadata::{string_from_value, JobMetadata},
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_ibm(data: &mut Data) -> AppResult<JobsPayload> {
     let mut from = 0;
@@ -77,6 +81,12 @@ pub async fn scrape_ibm(data: &mut Data) -> AppResult<JobsPayload> {
                     title,
                     location,
                     link,
+                    external_id: external_id_from_value(&v["_id"]),
+                    locations: Vec::new(),
+                    metadata: JobMetadata {
+                        department: string_from_value(&v["_source"]["field_keyword_08"]),
+                        ..Default::default()
+                    },
                 }
             })
             .collect();
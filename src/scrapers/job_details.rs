@@ -0,0 +1,283 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use headless_chrome::{Browser, LaunchOptions};
+use serde_json::Value;
+use tracing::{debug, warn};
+
+use crate::{
+    config::{http_client, launch_options},
+    error::AppResult,
+    models::{
+        description::{html_to_markdown, unescape_html, JobDescription},
+        scraper::Job,
+    },
+};
+
+// INFO: Set from --fetch-descriptions, fetches the description of every new job after a scrape
+static BACKGROUND_FETCH: AtomicBool = AtomicBool::new(false);
+
+pub fn enable_background_fetch() {
+    BACKGROUND_FETCH.store(true, Ordering::Relaxed);
+}
+
+/// Where a job's description can be read from, the ATS API when we know it, the page otherwise
+enum DetailsSource {
+    Greenhouse {
+        board: String,
+        id: String,
+    },
+    Eightfold {
+        host: &'static str,
+        domain: &'static str,
+        id: String,
+    },
+    SmartRecruiters {
+        company: &'static str,
+        id: String,
+    },
+    Lever {
+        org: String,
+        id: String,
+    },
+    Browser,
+}
+
+fn details_source(company: &str, job: &Job) -> DetailsSource {
+    if let Some(id) = job.external_id.clone() {
+        match company {
+            "Cloudflare" | "Robinhood" => {
+                return DetailsSource::Greenhouse {
+                    board: company.to_lowercase(),
+                    id,
+                }
+            }
+            "Netflix" => {
+                return DetailsSource::Eightfold {
+                    host: "explore.jobs.netflix.net",
+                    domain: "netflix.com",
+                    id,
+                }
+            }
+            "PayPal" => {
+                return DetailsSource::Eightfold {
+                    host: "paypal.eightfold.ai",
+                    domain: "paypal.com",
+                    id,
+                }
+            }
+            "Costar Group" => {
+                return DetailsSource::Eightfold {
+                    host: "careers.costargroup.com",
+                    domain: "costar.com",
+                    id,
+                }
+            }
+            "Experian" => {
+                return DetailsSource::SmartRecruiters {
+                    company: "experian",
+                    id,
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Boards hosted directly on an ATS carry everything we need in the link
+    let Ok(url) = reqwest::Url::parse(&job.link) else {
+        return DetailsSource::Browser;
+    };
+    let segments: Vec<&str> = url
+        .path_segments()
+        .map(|s| s.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+
+    match (url.host_str(), segments.as_slice()) {
+        (Some("boards.greenhouse.io" | "job-boards.greenhouse.io"), [board, "jobs", id, ..]) => {
+            DetailsSource::Greenhouse {
+                board: board.to_string(),
+                id: id.to_string(),
+            }
+        }
+        (Some("jobs.lever.co"), [org, id, ..]) => DetailsSource::Lever {
+            org: org.to_string(),
+            id: id.to_string(),
+        },
+        _ => DetailsSource::Browser,
+    }
+}
+
+async fn get_json(url: &str) -> AppResult<Value> {
+    Ok(http_client()
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<Value>()
+        .await?)
+}
+
+fn section_markdown(title: Option<&str>, html: &str) -> String {
+    let body = html_to_markdown(html);
+
+    match title {
+        Some(title) if !title.trim().is_empty() && !body.is_empty() => {
+            format!("## {}\n\n{}", title.trim(), body)
+        }
+        _ => body,
+    }
+}
+
+fn fetch_with_browser(link: &str) -> AppResult<String> {
+    let launch_options = LaunchOptions {
+        window_size: Some((1920, 1080)),
+        ..launch_options(true)
+    };
+    let browser = Browser::new(launch_options)?;
+    let tab = browser.new_tab()?;
+
+    tab.navigate_to(link)?;
+    tab.wait_for_element("body")?;
+
+    // INFO: Embedded Greenhouse boards (Weedmaps and others) render the posting in an iframe
+    if let Ok(iframe) = tab.find_element("#grnhse_iframe") {
+        if let Some(src) = iframe.get_attribute_value("src")? {
+            tab.navigate_to(&src)?;
+            tab.wait_for_element("body")?;
+        }
+    }
+
+    let content = tab.evaluate(
+        r##"(["#content", "main", "article", "[role=main]", "body"]
+            .map(s => document.querySelector(s))
+            .find(el => el && el.innerText.trim().length > 200) || document.body).innerHTML"##,
+        false,
+    )?;
+
+    let html = content
+        .value
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .ok_or("No content found on the job page")?;
+
+    Ok(html_to_markdown(&html))
+}
+
+/// Fetches a job's description as Markdown, without storing it
+pub async fn fetch_job_description(company: &str, job: &Job) -> AppResult<String> {
+    let markdown = match details_source(company, job) {
+        DetailsSource::Greenhouse { board, id } => {
+            let json = get_json(&format!(
+                "https://boards-api.greenhouse.io/v1/boards/{}/jobs/{}",
+                board, id
+            ))
+            .await?;
+
+            html_to_markdown(&unescape_html(json["content"].as_str().unwrap_or_default()))
+        }
+        DetailsSource::Eightfold { host, domain, id } => {
+            let json = get_json(&format!(
+                "https://{}/api/apply/v2/jobs/{}?domain={}",
+                host, id, domain
+            ))
+            .await?;
+
+            html_to_markdown(json["job_description"].as_str().unwrap_or_default())
+        }
+        DetailsSource::SmartRecruiters { company, id } => {
+            let json = get_json(&format!(
+                "https://api.smartrecruiters.com/v1/companies/{}/postings/{}",
+                company, id
+            ))
+            .await?;
+
+            ["jobDescription", "qualifications", "additionalInformation"]
+                .iter()
+                .map(|key| &json["jobAd"]["sections"][key])
+                .map(|section| {
+                    section_markdown(
+                        section["title"].as_str(),
+                        section["text"].as_str().unwrap_or_default(),
+                    )
+                })
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<String>>()
+                .join("\n\n")
+        }
+        DetailsSource::Lever { org, id } => {
+            let json =
+                get_json(&format!("https://api.lever.co/v0/postings/{}/{}", org, id)).await?;
+
+            let mut sections = vec![section_markdown(
+                None,
+                json["description"].as_str().unwrap_or_default(),
+            )];
+
+            for list in json["lists"].as_array().into_iter().flatten() {
+                sections.push(section_markdown(
+                    list["text"].as_str(),
+                    &format!("<ul>{}</ul>", list["content"].as_str().unwrap_or_default()),
+                ));
+            }
+
+            sections.push(section_markdown(
+                None,
+                json["additional"].as_str().unwrap_or_default(),
+            ));
+
+            sections
+                .into_iter()
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<String>>()
+                .join("\n\n")
+        }
+        DetailsSource::Browser => {
+            // INFO: headless_chrome blocks, so it gets a thread of its own instead of a runtime worker
+            let link = job.link.clone();
+            tokio::task::spawn_blocking(move || fetch_with_browser(&link)).await??
+        }
+    };
+
+    if markdown.trim().is_empty() {
+        return Err(format!("No description found for {}", job.title).into());
+    }
+
+    Ok(markdown)
+}
+
+/// Fetches and stores a job's description, returning what was stored
+pub async fn fetch_and_store_job_description(
+    company: &str,
+    job: &Job,
+) -> AppResult<JobDescription> {
+    let markdown = fetch_job_description(company, job).await?;
+    JobDescription::save(&job.id, &markdown)?;
+
+    Ok(JobDescription::load(&job.id).ok_or("Description could not be stored")?)
+}
+
+/// Fetches the descriptions of new jobs in a background task when --fetch-descriptions is set
+///
+/// Failures are only logged, the description can still be fetched from the job menu. Pay found in
+/// a description is read when it is viewed there, see `Data::refresh_job_salary`.
+pub fn queue_background_fetch(company: &str, jobs: &[Job]) {
+    if !BACKGROUND_FETCH.load(Ordering::Relaxed) || jobs.is_empty() {
+        return;
+    }
+
+    let company = company.to_string();
+    let jobs: Vec<Job> = jobs
+        .iter()
+        .filter(|j| !JobDescription::exists(&j.id))
+        .cloned()
+        .collect();
+
+    tokio::spawn(async move {
+        for job in jobs {
+            match fetch_and_store_job_description(&company, &job).await {
+                Ok(_) => debug!(company, job = %job.id, "Stored job description"),
+                Err(e) => {
+                    warn!(company, job = %job.id, error = %e, "Could not fetch job description")
+                }
+            }
+        }
+    });
+}
@@ -3,9 +3,12 @@ use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
 use serde_json::Value;
 
 use crate::{
+    config::http_client,
     error::AppResult,
     models::{
         data::Data,
+        identity::external_id_from_value,
+        metadata::{string_from_value, JobMetadata},
         scraper::{JobsPayload, ScrapedJob},
     },
 };
@@ -49,7 +52,7 @@ pub async fn scrape_meta(data: &mut Data) -> AppResult<JobsPayload> {
         ),
     );
 
-    let client = reqwest::Client::new();
+    let client = http_client();
     let response = client.post("https://www.metacareers.com/graphql")
         .headers(headers)
         .body(r#"av=0&__user=0&__a=1&__req=2&__hs=20054.BP%3ADEFAULT.2.0..0.0&dpr=3&__ccg=GOOD&__rev=1018509888&__s=2ppi3o%3Abbrg3o%3Aerkk4k&__hsi=7442044011372323185&__dyn=7xeUmwkHg7ebwKBAg5S1Dxu13wqovzEdEc8uxa1twKzobo1nEhwem0nCq1ewcG0RU2Cwooa81VohwnU14E9k2C0sy0H82NxCawcK1iwmE2ewnE2Lw5XwSyES4E3PwbS1Lwqo3cwbq0x8qw53wtU5K0zU5a&__csr=&lsd=AVq53ZVpBuM&jazoest=2916&__spin_r=1018509888&__spin_b=trunk&__spin_t=1732735896&__jssesw=1&fb_api_caller_class=RelayModern&fb_api_req_friendly_name=CareersJobSearchResultsQuery&variables=%7B%22search_input%22%3A%7B%22q%22%3Anull%2C%22divisions%22%3A%5B%5D%2C%22offices%22%3A%5B%5D%2C%22roles%22%3A%5B%5D%2C%22leadership_levels%22%3A%5B%5D%2C%22saved_jobs%22%3A%5B%5D%2C%22saved_searches%22%3A%5B%5D%2C%22sub_teams%22%3A%5B%5D%2C%22teams%22%3A%5B%22Software%20Engineering%22%2C%22Artificial%20Intelligence%22%5D%2C%22is_leadership%22%3Afalse%2C%22is_remote_only%22%3Afalse%2C%22sort_by_new%22%3Afalse%2C%22results_per_page%22%3Anull%7D%7D&server_timestamps=true&doc_id=9114524511922157"#)
@@ -62,23 +65,33 @@ pub async fn scrape_meta(data: &mut Data) -> AppResult<JobsPayload> {
         .as_array()
         .unwrap()
         .iter()
-        .flat_map(|v| {
+        .map(|v| {
             let title = v["title"].as_str().unwrap().trim().to_string();
             let link = format!(
                 "https://www.metacareers.com/jobs/{}",
                 v["id"].as_str().unwrap().trim()
             );
 
-            // Create a ScrapedJob for each location
-            v["locations"]
+            // One ScrapedJob per posting, carrying every location it is open in
+            let locations: Vec<String> = v["locations"]
                 .as_array()
                 .unwrap()
                 .iter()
-                .map(move |l| ScrapedJob {
-                    title: title.clone(),
-                    location: l.as_str().unwrap().trim().to_string(),
-                    link: link.clone(),
-                })
+                .filter_map(|l| l.as_str())
+                .map(|l| l.trim().to_string())
+                .collect();
+
+            ScrapedJob {
+                title,
+                location: locations.join(" | "),
+                link,
+                external_id: external_id_from_value(&v["id"]),
+                locations,
+                metadata: JobMetadata {
+                    department: string_from_value(&v["teams"][0]),
+                    ..Default::default()
+                },
+            }
         })
         .collect();
 
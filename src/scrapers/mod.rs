@@ -11,77 +11,78 @@ pub mod gen {
     pub mod scraper;
 }
 pub mod disney {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod netflix {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod meta {
-	pub mod scraper;
+    pub mod scraper;
 }
 
 pub mod chase {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod square {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod ibm {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod cisco {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod costar_group {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod experian {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod salesforce {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod stripe {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod airbnb {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod servicenow {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod cloudflare {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod robinhood {
-	pub mod scraper;
+    pub mod scraper;
 }
 
 pub mod nike {
-	pub mod scraper;
+    pub mod scraper;
 }
 
 pub mod toast {
-	pub mod scraper;
+    pub mod scraper;
 }
 
 pub mod uber {
-	pub mod scraper;
+    pub mod scraper;
 }
 
 pub mod doordash {
-	pub mod scraper;
+    pub mod scraper;
 }
 
 pub mod paypal {
-	pub mod scraper;
+    pub mod scraper;
 }
 
 pub mod atlassian {
-	pub mod scraper;
+    pub mod scraper;
 }
 
 pub mod panasonic {
-	pub mod scraper;
+    pub mod scraper;
 }
 pub mod default;
+pub mod job_details;
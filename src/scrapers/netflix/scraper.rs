@@ -1,13 +1,18 @@
 use std::error::Error;
 
 use headless_chrome::{Browser, LaunchOptions};
-use reqwest::Client;
 use serde_json::Value;
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{Job, JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::http_client,
+    error::AppResult,
+    models::{
+        data::Data,
+        identity::external_id_from_value,
+        metadata::eightfold_metadata,
+        scraper::{Job, JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_netflix(data: &mut Data) -> AppResult<JobsPayload> {
     let mut i = 0;
@@ -17,7 +22,7 @@ pub async fn scrape_netflix(data: &mut Data) -> AppResult<JobsPayload> {
             "https://explore.jobs.netflix.net/api/apply/v2/jobs?&start={}&num=10&Teams=Engineering&Teams=Engineering%20Operations&Teams=Data%20Science%20%26%20Analytics",
             i
         );
-        let json = Client::new().get(url).send().await?.json::<Value>().await?;
+        let json = http_client().get(url).send().await?.json::<Value>().await?;
 
         if json["positions"].as_array().unwrap().is_empty() {
             break;
@@ -30,7 +35,14 @@ pub async fn scrape_netflix(data: &mut Data) -> AppResult<JobsPayload> {
             .map(|v| ScrapedJob {
                 title: v["name"].as_str().unwrap().trim().to_string(),
                 location: v["location"].as_str().unwrap().trim().to_string(),
-                link: v["canonicalPositionUrl"].as_str().unwrap().trim().to_string(),
+                link: v["canonicalPositionUrl"]
+                    .as_str()
+                    .unwrap()
+                    .trim()
+                    .to_string(),
+                external_id: external_id_from_value(&v["id"]),
+                locations: Vec::new(),
+                metadata: eightfold_metadata(v),
             })
             .collect();
 
@@ -2,17 +2,23 @@ use std::error::Error;
 
 use serde_json::Value;
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::http_client,
+    error::AppResult,
+    models::{
+        data::Data,
+        identity::external_id_from_value,
+        metadata::{parse_posted_date, JobMetadata},
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_nike(data: &mut Data) -> AppResult<JobsPayload> {
     let mut offset = 0;
     let mut scraped_jobs: Vec<ScrapedJob> = Vec::new();
     loop {
         let url = format!("https://jobs.nike.com/cms/api/v1/nike/search/jobs/?offset={}&limit=100&sort_key=posting_start_date&lang=en&categories=Technology&sort_dir=DESC", offset);
-        let mut json: Value = reqwest::get(&url).await?.json().await?;
+        let mut json: Value = http_client().get(&url).send().await?.json().await?;
 
         let jobs = json["jobs"].as_array_mut().unwrap();
 
@@ -68,6 +74,12 @@ pub async fn scrape_nike(data: &mut Data) -> AppResult<JobsPayload> {
                 title,
                 location,
                 link,
+                external_id: external_id_from_value(&job["id"]),
+                locations: Vec::new(),
+                metadata: JobMetadata {
+                    posted_at: parse_posted_date(&job["postingStartDate"]),
+                    ..Default::default()
+                },
             });
         }
         offset += 100;
@@ -79,4 +91,3 @@ pub async fn scrape_nike(data: &mut Data) -> AppResult<JobsPayload> {
     // Return JobsPayload
     Ok(jobs_payload)
 }
-
@@ -3,9 +3,14 @@ use std::error::Error;
 use serde_json::Value;
 
 use crate::{
+    config::http_client,
     error::AppResult,
     models::{
         data::Data,
+        identity::external_id_from_value,
+        metadata::{
+            parse_posted_date, remote_from_text, string_from_value, EmploymentType, JobMetadata,
+        },
         scraper::{JobsPayload, ScrapedJob},
     },
 };
@@ -18,7 +23,7 @@ pub async fn scrape_panasonic(data: &mut Data) -> AppResult<JobsPayload> {
         let api_url =
             format!("https://careers.na.panasonic.com/api/jobs?page={page}&categories=Engineering");
 
-        let json: Value = reqwest::get(&api_url).await?.json().await?;
+        let json: Value = http_client().get(&api_url).send().await?.json().await?;
 
         let jobs: Vec<Value> = json["jobs"].as_array().cloned().unwrap();
 
@@ -39,6 +44,19 @@ pub async fn scrape_panasonic(data: &mut Data) -> AppResult<JobsPayload> {
                     .as_str()
                     .unwrap()
                     .to_string(),
+                external_id: external_id_from_value(&j["data"]["req_id"]),
+                locations: Vec::new(),
+                metadata: JobMetadata {
+                    posted_at: parse_posted_date(&j["data"]["posted_date"]),
+                    department: string_from_value(&j["data"]["category"][0]),
+                    employment_type: j["data"]["employment_type"]
+                        .as_str()
+                        .and_then(EmploymentType::parse),
+                    remote: j["data"]["location_type"]
+                        .as_str()
+                        .and_then(remote_from_text),
+                    ..Default::default()
+                },
             })
             .collect();
 
@@ -51,4 +69,3 @@ pub async fn scrape_panasonic(data: &mut Data) -> AppResult<JobsPayload> {
     // Return JobsPayload
     Ok(jobs_payload)
 }
-
@@ -2,16 +2,22 @@ use std::error::Error;
 
 use serde_json::Value;
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::http_client,
+    error::AppResult,
+    models::{
+        data::Data,
+        identity::external_id_from_value,
+        metadata::eightfold_metadata,
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_paypal(data: &mut Data) -> AppResult<JobsPayload> {
     let mut start = 0;
     let mut scraped_jobs: Vec<ScrapedJob> = Vec::new();
     loop {
-        let json: Value = reqwest::get(&format!("https://paypal.eightfold.ai/api/apply/v2/jobs?domain=paypal.com&start={}&num=10&exclude_pid=274904231921&pid=274904231921&Job%20Category=Software%20Development&Job%20Category=Machine%20Learning&Job%20Category=Data%20Science&domain=paypal.com&sort_by=relevance", start)).await?.json().await?;
+        let json: Value = http_client().get(format!("https://paypal.eightfold.ai/api/apply/v2/jobs?domain=paypal.com&start={}&num=10&exclude_pid=274904231921&pid=274904231921&Job%20Category=Software%20Development&Job%20Category=Machine%20Learning&Job%20Category=Data%20Science&domain=paypal.com&sort_by=relevance", start)).send().await?.json().await?;
 
         let positions = json["positions"].as_array().unwrap();
 
@@ -31,6 +37,9 @@ pub async fn scrape_paypal(data: &mut Data) -> AppResult<JobsPayload> {
                     title,
                     link,
                     location: location.as_str().unwrap().to_string(),
+                    external_id: external_id_from_value(&position["id"]),
+                    locations: Vec::new(),
+                    metadata: eightfold_metadata(position),
                 });
             }
         }
@@ -43,4 +52,3 @@ pub async fn scrape_paypal(data: &mut Data) -> AppResult<JobsPayload> {
     // Return JobsPayload
     Ok(jobs_payload)
 }
-
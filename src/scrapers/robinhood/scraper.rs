@@ -2,13 +2,21 @@ use std::error::Error;
 
 use serde_json::Value;
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::http_client,
+    error::AppResult,
+    models::{
+        data::Data,
+        identity::external_id_from_value,
+        metadata::{greenhouse_metadata, string_from_value},
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_robinhood(data: &mut Data) -> AppResult<JobsPayload> {
-    let json: Value = reqwest::get("https://api.greenhouse.io/v1/boards/robinhood/jobs")
+    let json: Value = http_client()
+        .get("https://api.greenhouse.io/v1/boards/robinhood/jobs?content=true")
+        .send()
         .await?
         .json()
         .await?;
@@ -31,6 +39,9 @@ pub async fn scrape_robinhood(data: &mut Data) -> AppResult<JobsPayload> {
                 title: job["title"].as_str().unwrap().trim().to_string(),
                 location: loction.trim().to_string(),
                 link: job["absolute_url"].as_str().unwrap().to_string(),
+                external_id: external_id_from_value(&job["id"]),
+                locations: Vec::new(),
+                metadata: greenhouse_metadata(job, string_from_value(&job["metadata"][0]["value"])),
             });
         }
     }
@@ -1,12 +1,17 @@
 use std::error::Error;
 
-use reqwest::Client;
 use serde_json::Value;
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::http_client,
+    error::AppResult,
+    models::{
+        data::Data,
+        identity::external_id_from_value,
+        metadata::JobMetadata,
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_square(data: &mut Data) -> AppResult<JobsPayload> {
     let mut page = 1;
@@ -15,7 +20,7 @@ pub async fn scrape_square(data: &mut Data) -> AppResult<JobsPayload> {
     loop {
         let url = format!("https://block.xyz/api/careers/jobs?businessUnits[]=square&page={}&pageLimit=10&teams[]=Software%20Engineering", page);
 
-        let json = Client::new().get(url).send().await?.json::<Value>().await?;
+        let json = http_client().get(url).send().await?.json::<Value>().await?;
 
         if let Some(jobs) = json["currentPage"].as_array() {
             if jobs.is_empty() {
@@ -31,6 +36,9 @@ pub async fn scrape_square(data: &mut Data) -> AppResult<JobsPayload> {
                         "https://block.xyz/careers/jobs/{}",
                         v["id"].as_i64().unwrap()
                     ),
+                    external_id: external_id_from_value(&v["id"]),
+                    locations: Vec::new(),
+                    metadata: JobMetadata::default(),
                 })
                 .collect();
 
@@ -1,12 +1,19 @@
 use std::{error::Error, thread::sleep, time::Duration};
 
-use reqwest::Client;
 use serde_json::{json, Value};
 
-use crate::{error::AppResult, models::{
-    data::Data,
-    scraper::{JobsPayload, ScrapedJob},
-}};
+use crate::{
+    config::http_client,
+    error::AppResult,
+    models::{
+        data::Data,
+        metadata::{
+            parse_posted_date, salary_from_description, string_from_value, EmploymentType,
+            JobMetadata,
+        },
+        scraper::{JobsPayload, ScrapedJob},
+    },
+};
 
 pub async fn scrape_uber(data: &mut Data) -> AppResult<JobsPayload> {
     let mut page = 0;
@@ -23,7 +30,7 @@ pub async fn scrape_uber(data: &mut Data) -> AppResult<JobsPayload> {
 
         let url = "https://www.uber.com/api/loadSearchJobsResults?localeCode=en";
 
-        let json: Value = Client::new()
+        let json: Value = http_client()
             .post(url)
             .body(body.to_string())
             .header("Content-Type", "application/json")
@@ -37,6 +44,9 @@ pub async fn scrape_uber(data: &mut Data) -> AppResult<JobsPayload> {
             for job in jobs {
                 let title = job["title"].as_str().unwrap();
                 let id = job["id"].as_number().unwrap().as_i64().unwrap();
+                let salary_range = job["description"]
+                    .as_str()
+                    .and_then(salary_from_description);
 
                 for loc in job["allLocations"].as_array().unwrap() {
                     let link = format!("https://www.uber.com/global/en/careers/list/{}/", id);
@@ -53,6 +63,17 @@ pub async fn scrape_uber(data: &mut Data) -> AppResult<JobsPayload> {
                         title: title.to_string(),
                         location,
                         link,
+                        external_id: Some(id.to_string()),
+                        locations: Vec::new(),
+                        metadata: JobMetadata {
+                            posted_at: parse_posted_date(&job["creationDate"]),
+                            department: string_from_value(&job["department"]),
+                            employment_type: job["timeType"]
+                                .as_str()
+                                .and_then(EmploymentType::parse),
+                            salary_range: salary_range.clone(),
+                            ..Default::default()
+                        },
                     };
 
                     scraped_jobs.push(scraped_job);
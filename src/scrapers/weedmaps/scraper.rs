@@ -4,6 +4,7 @@ use std::{collections::HashSet, error::Error, fs};
 use crate::models::data::Data;
 use crate::utils::stringify_js;
 use crate::{
+    config::launch_options,
     models::scraper::{Job, JobsPayload},
     utils::stringify_js::strinfify_js,
 };
@@ -20,11 +21,10 @@ pub async fn scrape_weedmaps(data: &mut Data) -> Result<JobsPayload, Box<dyn Err
     let js = strinfify_js(file_path)?;
 
     let options = LaunchOptions {
-        headless: false,
         window_size: Some((1920, 1080)),
         enable_logging: true,
 
-        ..LaunchOptions::default()
+        ..launch_options(false)
     };
 
     let browser = Browser::new(options)?;